@@ -6829,6 +6829,7 @@ pub mod js_protocol {
         impl SearchMatch {
             pub const IDENTIFIER: &'static str = "Debugger.SearchMatch";
         }
+        #[doc = "[BreakLocation](https://chromedevtools.github.io/devtools-protocol/tot/Debugger/#type-BreakLocation)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct BreakLocation {
             #[doc = "Script identifier as reported in the `Debugger.scriptParsed`."]
@@ -6936,6 +6937,7 @@ pub mod js_protocol {
         impl BreakLocation {
             pub const IDENTIFIER: &'static str = "Debugger.BreakLocation";
         }
+        #[doc = "[WasmDisassemblyChunk](https://chromedevtools.github.io/devtools-protocol/tot/Debugger/#type-WasmDisassemblyChunk)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct WasmDisassemblyChunk {
             #[doc = "The next chunk of disassembled lines."]
@@ -7015,7 +7017,7 @@ pub mod js_protocol {
         impl WasmDisassemblyChunk {
             pub const IDENTIFIER: &'static str = "Debugger.WasmDisassemblyChunk";
         }
-        #[doc = "Enum of possible script languages."]
+        #[doc = "Enum of possible script languages.\n[ScriptLanguage](https://chromedevtools.github.io/devtools-protocol/tot/Debugger/#type-ScriptLanguage)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ScriptLanguage {
             #[serde(rename = "JavaScript")]
@@ -7773,6 +7775,7 @@ pub mod js_protocol {
         impl chromiumoxide_types::Command for GetScriptSourceParams {
             type Response = GetScriptSourceReturns;
         }
+        #[doc = "[disassembleWasmModule](https://chromedevtools.github.io/devtools-protocol/tot/Debugger/#method-disassembleWasmModule)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct DisassembleWasmModuleParams {
             #[doc = "Id of the script to disassemble"]
@@ -7824,6 +7827,7 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[disassembleWasmModule](https://chromedevtools.github.io/devtools-protocol/tot/Debugger/#method-disassembleWasmModule)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct DisassembleWasmModuleReturns {
             #[doc = "For large modules, return a stream from which additional chunks of\ndisassembly can be read successively."]
@@ -10555,6 +10559,7 @@ pub mod js_protocol {
         impl chromiumoxide_types::Command for AddInspectedHeapObjectParams {
             type Response = AddInspectedHeapObjectReturns;
         }
+        #[doc = "[collectGarbage](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-collectGarbage)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct CollectGarbageParams {}
         impl CollectGarbageParams {
@@ -10573,11 +10578,13 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[collectGarbage](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-collectGarbage)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct CollectGarbageReturns {}
         impl chromiumoxide_types::Command for CollectGarbageParams {
             type Response = CollectGarbageReturns;
         }
+        #[doc = "[disable](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-disable)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct DisableParams {}
         impl DisableParams {
@@ -10596,11 +10603,13 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[disable](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-disable)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct DisableReturns {}
         impl chromiumoxide_types::Command for DisableParams {
             type Response = DisableReturns;
         }
+        #[doc = "[enable](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-enable)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct EnableParams {}
         impl EnableParams {
@@ -10619,11 +10628,13 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[enable](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-enable)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct EnableReturns {}
         impl chromiumoxide_types::Command for EnableParams {
             type Response = EnableReturns;
         }
+        #[doc = "[getHeapObjectId](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-getHeapObjectId)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct GetHeapObjectIdParams {
             #[doc = "Identifier of the object to get heap object id for."]
@@ -10678,6 +10689,7 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[getHeapObjectId](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-getHeapObjectId)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct GetHeapObjectIdReturns {
             #[doc = "Id of the heap snapshot object corresponding to the passed remote object id."]
@@ -10722,6 +10734,7 @@ pub mod js_protocol {
         impl chromiumoxide_types::Command for GetHeapObjectIdParams {
             type Response = GetHeapObjectIdReturns;
         }
+        #[doc = "[getObjectByHeapObjectId](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-getObjectByHeapObjectId)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct GetObjectByHeapObjectIdParams {
             #[serde(rename = "objectId")]
@@ -10783,6 +10796,7 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[getObjectByHeapObjectId](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-getObjectByHeapObjectId)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct GetObjectByHeapObjectIdReturns {
             #[doc = "Evaluation result."]
@@ -10821,6 +10835,7 @@ pub mod js_protocol {
         impl chromiumoxide_types::Command for GetObjectByHeapObjectIdParams {
             type Response = GetObjectByHeapObjectIdReturns;
         }
+        #[doc = "[getSamplingProfile](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-getSamplingProfile)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct GetSamplingProfileParams {}
         impl GetSamplingProfileParams {
@@ -10839,6 +10854,7 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[getSamplingProfile](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-getSamplingProfile)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct GetSamplingProfileReturns {
             #[doc = "Return the sampling profile being collected."]
@@ -10877,6 +10893,7 @@ pub mod js_protocol {
         impl chromiumoxide_types::Command for GetSamplingProfileParams {
             type Response = GetSamplingProfileReturns;
         }
+        #[doc = "[startSampling](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-startSampling)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct StartSamplingParams {
             #[doc = "Average sample interval in bytes. Poisson distribution is used for the intervals. The\ndefault value is 32768 bytes."]
@@ -10920,11 +10937,13 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[startSampling](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-startSampling)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct StartSamplingReturns {}
         impl chromiumoxide_types::Command for StartSamplingParams {
             type Response = StartSamplingReturns;
         }
+        #[doc = "[startTrackingHeapObjects](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-startTrackingHeapObjects)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct StartTrackingHeapObjectsParams {
             #[serde(rename = "trackAllocations")]
@@ -10967,11 +10986,13 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[startTrackingHeapObjects](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-startTrackingHeapObjects)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct StartTrackingHeapObjectsReturns {}
         impl chromiumoxide_types::Command for StartTrackingHeapObjectsParams {
             type Response = StartTrackingHeapObjectsReturns;
         }
+        #[doc = "[stopSampling](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-stopSampling)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct StopSamplingParams {}
         impl StopSamplingParams {
@@ -10990,6 +11011,7 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[stopSampling](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-stopSampling)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct StopSamplingReturns {
             #[doc = "Recorded sampling heap profile."]
@@ -11028,6 +11050,7 @@ pub mod js_protocol {
         impl chromiumoxide_types::Command for StopSamplingParams {
             type Response = StopSamplingReturns;
         }
+        #[doc = "[stopTrackingHeapObjects](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-stopTrackingHeapObjects)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct StopTrackingHeapObjectsParams {
             #[doc = "If true 'reportHeapSnapshotProgress' events will be generated while snapshot is being taken\nwhen the tracking is stopped."]
@@ -11091,11 +11114,13 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[stopTrackingHeapObjects](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-stopTrackingHeapObjects)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct StopTrackingHeapObjectsReturns {}
         impl chromiumoxide_types::Command for StopTrackingHeapObjectsParams {
             type Response = StopTrackingHeapObjectsReturns;
         }
+        #[doc = "[takeHeapSnapshot](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-takeHeapSnapshot)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct TakeHeapSnapshotParams {
             #[doc = "If true 'reportHeapSnapshotProgress' events will be generated while snapshot is being taken."]
@@ -11159,11 +11184,13 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[takeHeapSnapshot](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#method-takeHeapSnapshot)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct TakeHeapSnapshotReturns {}
         impl chromiumoxide_types::Command for TakeHeapSnapshotParams {
             type Response = TakeHeapSnapshotReturns;
         }
+        #[doc = "[addHeapSnapshotChunk](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#event-addHeapSnapshotChunk)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventAddHeapSnapshotChunk {
             #[serde(rename = "chunk")]
@@ -11233,6 +11260,7 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[reportHeapSnapshotProgress](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#event-reportHeapSnapshotProgress)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventReportHeapSnapshotProgress {
             #[serde(rename = "done")]
@@ -11259,6 +11287,7 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[resetProfiles](https://chromedevtools.github.io/devtools-protocol/tot/HeapProfiler/#event-resetProfiles)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct EventResetProfiles {}
         impl EventResetProfiles {
@@ -11995,6 +12024,7 @@ pub mod js_protocol {
         impl ScriptTypeProfile {
             pub const IDENTIFIER: &'static str = "Profiler.ScriptTypeProfile";
         }
+        #[doc = "[disable](https://chromedevtools.github.io/devtools-protocol/tot/Profiler/#method-disable)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct DisableParams {}
         impl DisableParams {
@@ -12013,11 +12043,13 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[disable](https://chromedevtools.github.io/devtools-protocol/tot/Profiler/#method-disable)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct DisableReturns {}
         impl chromiumoxide_types::Command for DisableParams {
             type Response = DisableReturns;
         }
+        #[doc = "[enable](https://chromedevtools.github.io/devtools-protocol/tot/Profiler/#method-enable)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct EnableParams {}
         impl EnableParams {
@@ -12036,6 +12068,7 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[enable](https://chromedevtools.github.io/devtools-protocol/tot/Profiler/#method-enable)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct EnableReturns {}
         impl chromiumoxide_types::Command for EnableParams {
@@ -12168,6 +12201,7 @@ pub mod js_protocol {
         impl chromiumoxide_types::Command for SetSamplingIntervalParams {
             type Response = SetSamplingIntervalReturns;
         }
+        #[doc = "[start](https://chromedevtools.github.io/devtools-protocol/tot/Profiler/#method-start)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct StartParams {}
         impl StartParams {
@@ -12186,6 +12220,7 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[start](https://chromedevtools.github.io/devtools-protocol/tot/Profiler/#method-start)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct StartReturns {}
         impl chromiumoxide_types::Command for StartParams {
@@ -12322,6 +12357,7 @@ pub mod js_protocol {
         impl chromiumoxide_types::Command for StartTypeProfileParams {
             type Response = StartTypeProfileReturns;
         }
+        #[doc = "[stop](https://chromedevtools.github.io/devtools-protocol/tot/Profiler/#method-stop)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct StopParams {}
         impl StopParams {
@@ -12340,6 +12376,7 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[stop](https://chromedevtools.github.io/devtools-protocol/tot/Profiler/#method-stop)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct StopReturns {
             #[doc = "Recorded profile."]
@@ -12580,6 +12617,7 @@ pub mod js_protocol {
         impl chromiumoxide_types::Command for TakeTypeProfileParams {
             type Response = TakeTypeProfileReturns;
         }
+        #[doc = "[consoleProfileFinished](https://chromedevtools.github.io/devtools-protocol/tot/Profiler/#event-consoleProfileFinished)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventConsoleProfileFinished {
             #[serde(rename = "id")]
@@ -13227,6 +13265,7 @@ pub mod js_protocol {
         impl RemoteObject {
             pub const IDENTIFIER: &'static str = "Runtime.RemoteObject";
         }
+        #[doc = "[CustomPreview](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#type-CustomPreview)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct CustomPreview {
             #[doc = "The JSON-stringified result of formatter.header(object, config) call.\nIt contains json ML array that represents RemoteObject."]
@@ -13555,6 +13594,7 @@ pub mod js_protocol {
         impl ObjectPreview {
             pub const IDENTIFIER: &'static str = "Runtime.ObjectPreview";
         }
+        #[doc = "[PropertyPreview](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#type-PropertyPreview)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct PropertyPreview {
             #[doc = "Property name."]
@@ -13791,6 +13831,7 @@ pub mod js_protocol {
         impl PropertyPreview {
             pub const IDENTIFIER: &'static str = "Runtime.PropertyPreview";
         }
+        #[doc = "[EntryPreview](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#type-EntryPreview)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EntryPreview {
             #[doc = "Preview of the key. Specified for map-like collection entries."]
@@ -16063,6 +16104,7 @@ pub mod js_protocol {
         impl chromiumoxide_types::Command for GlobalLexicalScopeNamesParams {
             type Response = GlobalLexicalScopeNamesReturns;
         }
+        #[doc = "[queryObjects](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#method-queryObjects)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct QueryObjectsParams {
             #[doc = "Identifier of the prototype to return objects for."]
@@ -16131,6 +16173,7 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[queryObjects](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#method-queryObjects)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct QueryObjectsReturns {
             #[doc = "Array with objects."]
@@ -16561,6 +16604,7 @@ pub mod js_protocol {
         impl chromiumoxide_types::Command for SetAsyncCallStackDepthParams {
             type Response = SetAsyncCallStackDepthReturns;
         }
+        #[doc = "[setCustomObjectFormatterEnabled](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#method-setCustomObjectFormatterEnabled)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct SetCustomObjectFormatterEnabledParams {
             #[serde(rename = "enabled")]
@@ -16611,11 +16655,13 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[setCustomObjectFormatterEnabled](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#method-setCustomObjectFormatterEnabled)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SetCustomObjectFormatterEnabledReturns {}
         impl chromiumoxide_types::Command for SetCustomObjectFormatterEnabledParams {
             type Response = SetCustomObjectFormatterEnabledReturns;
         }
+        #[doc = "[setMaxCallStackSizeToCapture](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#method-setMaxCallStackSizeToCapture)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct SetMaxCallStackSizeToCaptureParams {
             #[serde(rename = "size")]
@@ -16664,6 +16710,7 @@ pub mod js_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[setMaxCallStackSizeToCapture](https://chromedevtools.github.io/devtools-protocol/tot/Runtime/#method-setMaxCallStackSizeToCapture)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SetMaxCallStackSizeToCaptureReturns {}
         impl chromiumoxide_types::Command for SetMaxCallStackSizeToCaptureParams {
@@ -17266,7 +17313,7 @@ pub mod browser_protocol {
         impl AxNodeId {
             pub const IDENTIFIER: &'static str = "Accessibility.AXNodeId";
         }
-        #[doc = "Enum of possible property types."]
+        #[doc = "Enum of possible property types.\n[AXValueType](https://chromedevtools.github.io/devtools-protocol/tot/Accessibility/#type-AXValueType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum AxValueType {
             #[serde(rename = "boolean")]
@@ -17360,7 +17407,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Enum of possible property sources."]
+        #[doc = "Enum of possible property sources.\n[AXValueSourceType](https://chromedevtools.github.io/devtools-protocol/tot/Accessibility/#type-AXValueSourceType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum AxValueSourceType {
             #[serde(rename = "attribute")]
@@ -17404,7 +17451,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Enum of possible native property sources (as a subtype of a particular AXValueSourceType)."]
+        #[doc = "Enum of possible native property sources (as a subtype of a particular AXValueSourceType).\n[AXValueNativeSourceType](https://chromedevtools.github.io/devtools-protocol/tot/Accessibility/#type-AXValueNativeSourceType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum AxValueNativeSourceType {
             #[serde(rename = "description")]
@@ -17597,6 +17644,7 @@ pub mod browser_protocol {
         impl AxValueSource {
             pub const IDENTIFIER: &'static str = "Accessibility.AXValueSource";
         }
+        #[doc = "[AXRelatedNode](https://chromedevtools.github.io/devtools-protocol/tot/Accessibility/#type-AXRelatedNode)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct AxRelatedNode {
             #[doc = "The BackendNodeId of the related DOM node."]
@@ -17663,6 +17711,7 @@ pub mod browser_protocol {
         impl AxRelatedNode {
             pub const IDENTIFIER: &'static str = "Accessibility.AXRelatedNode";
         }
+        #[doc = "[AXProperty](https://chromedevtools.github.io/devtools-protocol/tot/Accessibility/#type-AXProperty)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct AxProperty {
             #[doc = "The name of this property."]
@@ -17811,7 +17860,7 @@ pub mod browser_protocol {
         impl AxValue {
             pub const IDENTIFIER: &'static str = "Accessibility.AXValue";
         }
-        #[doc = "Values of AXProperty name:\n- from 'busy' to 'roledescription': states which apply to every AX node\n- from 'live' to 'root': attributes which apply to nodes in live regions\n- from 'autocomplete' to 'valuetext': attributes which apply to widgets\n- from 'checked' to 'selected': states which apply to widgets\n- from 'activedescendant' to 'owns' - relationships between elements other than parent/child/sibling."]
+        #[doc = "Values of AXProperty name:\n- from 'busy' to 'roledescription': states which apply to every AX node\n- from 'live' to 'root': attributes which apply to nodes in live regions\n- from 'autocomplete' to 'valuetext': attributes which apply to widgets\n- from 'checked' to 'selected': states which apply to widgets\n- from 'activedescendant' to 'owns' - relationships between elements other than parent/child/sibling.\n[AXPropertyName](https://chromedevtools.github.io/devtools-protocol/tot/Accessibility/#type-AXPropertyName)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum AxPropertyName {
             #[serde(rename = "busy")]
@@ -20296,6 +20345,7 @@ pub mod browser_protocol {
         impl AffectedFrame {
             pub const IDENTIFIER: &'static str = "Audits.AffectedFrame";
         }
+        #[doc = "[CookieExclusionReason](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-CookieExclusionReason)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CookieExclusionReason {
             #[serde(rename = "ExcludeSameSiteUnspecifiedTreatedAsLax")]
@@ -20362,6 +20412,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[CookieWarningReason](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-CookieWarningReason)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CookieWarningReason {
             #[serde(rename = "WarnSameSiteUnspecifiedCrossSiteContext")]
@@ -20461,6 +20512,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[CookieOperation](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-CookieOperation)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CookieOperation {
             #[serde(rename = "SetCookie")]
@@ -20641,6 +20693,7 @@ pub mod browser_protocol {
         impl CookieIssueDetails {
             pub const IDENTIFIER: &'static str = "Audits.CookieIssueDetails";
         }
+        #[doc = "[MixedContentResolutionStatus](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-MixedContentResolutionStatus)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum MixedContentResolutionStatus {
             #[serde(rename = "MixedContentBlocked")]
@@ -20678,6 +20731,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[MixedContentResourceType](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-MixedContentResourceType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum MixedContentResourceType {
             #[serde(rename = "AttributionSrc")]
@@ -20813,6 +20867,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[MixedContentIssueDetails](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-MixedContentIssueDetails)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct MixedContentIssueDetails {
             #[doc = "The type of resource causing the mixed content issue (css, js, iframe,\nform,...). Marked as optional because it is mapped to from\nblink::mojom::RequestContextType, which will be replaced\nby network::mojom::RequestDestination"]
@@ -20927,7 +20982,7 @@ pub mod browser_protocol {
         impl MixedContentIssueDetails {
             pub const IDENTIFIER: &'static str = "Audits.MixedContentIssueDetails";
         }
-        #[doc = "Enum indicating the reason a response has been blocked. These reasons are\nrefinements of the net error BLOCKED_BY_RESPONSE."]
+        #[doc = "Enum indicating the reason a response has been blocked. These reasons are\nrefinements of the net error BLOCKED_BY_RESPONSE.\n[BlockedByResponseReason](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-BlockedByResponseReason)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum BlockedByResponseReason {
             #[serde(rename = "CoepFrameResourceNeedsCoepHeader")]
@@ -21056,6 +21111,7 @@ pub mod browser_protocol {
         impl BlockedByResponseIssueDetails {
             pub const IDENTIFIER: &'static str = "Audits.BlockedByResponseIssueDetails";
         }
+        #[doc = "[HeavyAdResolutionStatus](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-HeavyAdResolutionStatus)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum HeavyAdResolutionStatus {
             #[serde(rename = "HeavyAdBlocked")]
@@ -21085,6 +21141,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[HeavyAdReason](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-HeavyAdReason)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum HeavyAdReason {
             #[serde(rename = "NetworkTotalLimit")]
@@ -21116,6 +21173,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[HeavyAdIssueDetails](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-HeavyAdIssueDetails)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct HeavyAdIssueDetails {
             #[doc = "The resolution status, either blocking the content or warning."]
@@ -21184,6 +21242,7 @@ pub mod browser_protocol {
         impl HeavyAdIssueDetails {
             pub const IDENTIFIER: &'static str = "Audits.HeavyAdIssueDetails";
         }
+        #[doc = "[ContentSecurityPolicyViolationType](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-ContentSecurityPolicyViolationType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ContentSecurityPolicyViolationType {
             #[serde(rename = "kInlineViolation")]
@@ -21245,6 +21304,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[SourceCodeLocation](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-SourceCodeLocation)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct SourceCodeLocation {
             #[serde(rename = "scriptId")]
@@ -21321,6 +21381,7 @@ pub mod browser_protocol {
         impl SourceCodeLocation {
             pub const IDENTIFIER: &'static str = "Audits.SourceCodeLocation";
         }
+        #[doc = "[ContentSecurityPolicyIssueDetails](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-ContentSecurityPolicyIssueDetails)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct ContentSecurityPolicyIssueDetails {
             #[doc = "The url not included in allowed sources."]
@@ -21446,6 +21507,7 @@ pub mod browser_protocol {
         impl ContentSecurityPolicyIssueDetails {
             pub const IDENTIFIER: &'static str = "Audits.ContentSecurityPolicyIssueDetails";
         }
+        #[doc = "[SharedArrayBufferIssueType](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-SharedArrayBufferIssueType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum SharedArrayBufferIssueType {
             #[serde(rename = "TransferIssue")]
@@ -21546,6 +21608,7 @@ pub mod browser_protocol {
         impl SharedArrayBufferIssueDetails {
             pub const IDENTIFIER: &'static str = "Audits.SharedArrayBufferIssueDetails";
         }
+        #[doc = "[TwaQualityEnforcementViolationType](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-TwaQualityEnforcementViolationType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum TwaQualityEnforcementViolationType {
             #[serde(rename = "kHttpError")]
@@ -21583,6 +21646,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[TrustedWebActivityIssueDetails](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-TrustedWebActivityIssueDetails)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct TrustedWebActivityIssueDetails {
             #[doc = "The url that triggers the violation."]
@@ -21671,6 +21735,7 @@ pub mod browser_protocol {
         impl TrustedWebActivityIssueDetails {
             pub const IDENTIFIER: &'static str = "Audits.TrustedWebActivityIssueDetails";
         }
+        #[doc = "[LowTextContrastIssueDetails](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-LowTextContrastIssueDetails)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct LowTextContrastIssueDetails {
             #[serde(rename = "violatingNodeId")]
@@ -21891,6 +21956,7 @@ pub mod browser_protocol {
         impl CorsIssueDetails {
             pub const IDENTIFIER: &'static str = "Audits.CorsIssueDetails";
         }
+        #[doc = "[AttributionReportingIssueType](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-AttributionReportingIssueType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum AttributionReportingIssueType {
             #[serde(rename = "PermissionPolicyDisabled")]
@@ -22146,6 +22212,7 @@ pub mod browser_protocol {
         impl QuirksModeIssueDetails {
             pub const IDENTIFIER: &'static str = "Audits.QuirksModeIssueDetails";
         }
+        #[doc = "[NavigatorUserAgentIssueDetails](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-NavigatorUserAgentIssueDetails)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct NavigatorUserAgentIssueDetails {
             #[serde(rename = "url")]
@@ -22198,6 +22265,7 @@ pub mod browser_protocol {
         impl NavigatorUserAgentIssueDetails {
             pub const IDENTIFIER: &'static str = "Audits.NavigatorUserAgentIssueDetails";
         }
+        #[doc = "[GenericIssueErrorType](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-GenericIssueErrorType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum GenericIssueErrorType {
             #[serde(rename = "CrossOriginPortalPostMessageError")]
@@ -22273,6 +22341,7 @@ pub mod browser_protocol {
         impl GenericIssueDetails {
             pub const IDENTIFIER: &'static str = "Audits.GenericIssueDetails";
         }
+        #[doc = "[DeprecationIssueType](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-DeprecationIssueType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum DeprecationIssueType {
             #[serde(rename = "AuthorizationCoveredByWildcard")]
@@ -22468,6 +22537,7 @@ pub mod browser_protocol {
         impl DeprecationIssueDetails {
             pub const IDENTIFIER: &'static str = "Audits.DeprecationIssueDetails";
         }
+        #[doc = "[ClientHintIssueReason](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-ClientHintIssueReason)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ClientHintIssueReason {
             #[doc = "Items in the accept-ch meta tag allow list must be valid origins.\nNo special values (e.g. self, none, and *) are permitted."]
@@ -22501,6 +22571,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[FederatedAuthRequestIssueDetails](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-FederatedAuthRequestIssueDetails)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct FederatedAuthRequestIssueDetails {
             #[serde(rename = "federatedAuthRequestIssueReason")]
@@ -22550,7 +22621,7 @@ pub mod browser_protocol {
         impl FederatedAuthRequestIssueDetails {
             pub const IDENTIFIER: &'static str = "Audits.FederatedAuthRequestIssueDetails";
         }
-        #[doc = "Represents the failure reason when a federated authentication reason fails.\nShould be updated alongside RequestIdTokenStatus in\nthird_party/blink/public/mojom/devtools/inspector_issue.mojom to include\nall cases except for success."]
+        #[doc = "Represents the failure reason when a federated authentication reason fails.\nShould be updated alongside RequestIdTokenStatus in\nthird_party/blink/public/mojom/devtools/inspector_issue.mojom to include\nall cases except for success.\n[FederatedAuthRequestIssueReason](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-FederatedAuthRequestIssueReason)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum FederatedAuthRequestIssueReason {
             #[serde(rename = "ShouldEmbargo")]
@@ -22811,7 +22882,7 @@ pub mod browser_protocol {
         impl ClientHintIssueDetails {
             pub const IDENTIFIER: &'static str = "Audits.ClientHintIssueDetails";
         }
-        #[doc = "A unique identifier for the type of issue. Each type may use one of the\noptional fields in InspectorIssueDetails to convey more specific\ninformation about the kind of issue."]
+        #[doc = "A unique identifier for the type of issue. Each type may use one of the\noptional fields in InspectorIssueDetails to convey more specific\ninformation about the kind of issue.\n[InspectorIssueCode](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#type-InspectorIssueCode)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum InspectorIssueCode {
             #[serde(rename = "CookieIssue")]
@@ -23517,6 +23588,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for CheckContrastParams {
             type Response = CheckContrastReturns;
         }
+        #[doc = "[issueAdded](https://chromedevtools.github.io/devtools-protocol/tot/Audits/#event-issueAdded)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventIssueAdded {
             #[serde(rename = "issue")]
@@ -23542,7 +23614,7 @@ pub mod browser_protocol {
     #[doc = "Defines events for background web platform features."]
     pub mod background_service {
         use serde::{Deserialize, Serialize};
-        #[doc = "The Background Service that will be associated with the commands/events.\nEvery Background Service operates independently, but they share the same\nAPI."]
+        #[doc = "The Background Service that will be associated with the commands/events.\nEvery Background Service operates independently, but they share the same\nAPI.\n[ServiceName](https://chromedevtools.github.io/devtools-protocol/tot/BackgroundService/#type-ServiceName)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ServiceName {
             #[serde(rename = "backgroundFetch")]
@@ -23643,6 +23715,7 @@ pub mod browser_protocol {
         impl EventMetadata {
             pub const IDENTIFIER: &'static str = "BackgroundService.EventMetadata";
         }
+        #[doc = "[BackgroundServiceEvent](https://chromedevtools.github.io/devtools-protocol/tot/BackgroundService/#type-BackgroundServiceEvent)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct BackgroundServiceEvent {
             #[doc = "Timestamp of the event (in seconds)."]
@@ -24059,6 +24132,7 @@ pub mod browser_protocol {
     #[doc = "The Browser domain defines methods and events for browser managing."]
     pub mod browser {
         use serde::{Deserialize, Serialize};
+        #[doc = "[BrowserContextID](https://chromedevtools.github.io/devtools-protocol/tot/Browser/#type-BrowserContextID)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, Eq, Hash)]
         pub struct BrowserContextId(String);
         impl BrowserContextId {
@@ -24092,6 +24166,7 @@ pub mod browser_protocol {
         impl BrowserContextId {
             pub const IDENTIFIER: &'static str = "Browser.BrowserContextID";
         }
+        #[doc = "[WindowID](https://chromedevtools.github.io/devtools-protocol/tot/Browser/#type-WindowID)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, Eq, Copy, Hash)]
         pub struct WindowId(i64);
         impl WindowId {
@@ -24105,7 +24180,7 @@ pub mod browser_protocol {
         impl WindowId {
             pub const IDENTIFIER: &'static str = "Browser.WindowID";
         }
-        #[doc = "The state of the browser window."]
+        #[doc = "The state of the browser window.\n[WindowState](https://chromedevtools.github.io/devtools-protocol/tot/Browser/#type-WindowState)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum WindowState {
             #[serde(rename = "normal")]
@@ -24212,6 +24287,7 @@ pub mod browser_protocol {
         impl Bounds {
             pub const IDENTIFIER: &'static str = "Browser.Bounds";
         }
+        #[doc = "[PermissionType](https://chromedevtools.github.io/devtools-protocol/tot/Browser/#type-PermissionType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum PermissionType {
             #[serde(rename = "accessibilityEvents")]
@@ -24353,6 +24429,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[PermissionSetting](https://chromedevtools.github.io/devtools-protocol/tot/Browser/#type-PermissionSetting)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum PermissionSetting {
             #[serde(rename = "granted")]
@@ -24473,7 +24550,7 @@ pub mod browser_protocol {
         impl PermissionDescriptor {
             pub const IDENTIFIER: &'static str = "Browser.PermissionDescriptor";
         }
-        #[doc = "Browser command ids used by executeBrowserCommand."]
+        #[doc = "Browser command ids used by executeBrowserCommand.\n[BrowserCommandId](https://chromedevtools.github.io/devtools-protocol/tot/Browser/#type-BrowserCommandId)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum BrowserCommandId {
             #[serde(rename = "openTabSearch")]
@@ -26041,6 +26118,7 @@ pub mod browser_protocol {
     #[doc = "This domain exposes CSS read/write operations. All CSS objects (stylesheets, rules, and styles)\nhave an associated `id` used in subsequent operations on the related object. Each object type has\na specific `id` structure, and those are not interchangeable between objects of different kinds.\nCSS objects can be loaded using the `get*ForNode()` calls (which accept a DOM node id). A client\ncan also keep track of stylesheets via the `styleSheetAdded`/`styleSheetRemoved` events and\nsubsequently load the required stylesheet contents using the `getStyleSheet[Text]()` methods."]
     pub mod css {
         use serde::{Deserialize, Serialize};
+        #[doc = "[StyleSheetId](https://chromedevtools.github.io/devtools-protocol/tot/CSS/#type-StyleSheetId)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, Eq, Hash)]
         pub struct StyleSheetId(String);
         impl StyleSheetId {
@@ -26074,7 +26152,7 @@ pub mod browser_protocol {
         impl StyleSheetId {
             pub const IDENTIFIER: &'static str = "CSS.StyleSheetId";
         }
-        #[doc = "Stylesheet type: \"injected\" for stylesheets injected via extension, \"user-agent\" for user-agent\nstylesheets, \"inspector\" for stylesheets created by the inspector (i.e. those holding the \"via\ninspector\" rules), \"regular\" for regular stylesheets."]
+        #[doc = "Stylesheet type: \"injected\" for stylesheets injected via extension, \"user-agent\" for user-agent\nstylesheets, \"inspector\" for stylesheets created by the inspector (i.e. those holding the \"via\ninspector\" rules), \"regular\" for regular stylesheets.\n[StyleSheetOrigin](https://chromedevtools.github.io/devtools-protocol/tot/CSS/#type-StyleSheetOrigin)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum StyleSheetOrigin {
             #[serde(rename = "injected")]
@@ -27055,6 +27133,7 @@ pub mod browser_protocol {
         impl SourceRange {
             pub const IDENTIFIER: &'static str = "CSS.SourceRange";
         }
+        #[doc = "[ShorthandEntry](https://chromedevtools.github.io/devtools-protocol/tot/CSS/#type-ShorthandEntry)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct ShorthandEntry {
             #[doc = "Shorthand name."]
@@ -27116,6 +27195,7 @@ pub mod browser_protocol {
         impl ShorthandEntry {
             pub const IDENTIFIER: &'static str = "CSS.ShorthandEntry";
         }
+        #[doc = "[CSSComputedStyleProperty](https://chromedevtools.github.io/devtools-protocol/tot/CSS/#type-CSSComputedStyleProperty)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct CssComputedStyleProperty {
             #[doc = "Computed style property name."]
@@ -29029,6 +29109,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for ForcePseudoStateParams {
             type Response = ForcePseudoStateReturns;
         }
+        #[doc = "[getBackgroundColors](https://chromedevtools.github.io/devtools-protocol/tot/CSS/#method-getBackgroundColors)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct GetBackgroundColorsParams {
             #[doc = "Id of the node to get background colors for."]
@@ -29080,6 +29161,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[getBackgroundColors](https://chromedevtools.github.io/devtools-protocol/tot/CSS/#method-getBackgroundColors)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct GetBackgroundColorsReturns {
             #[doc = "The range of background colors behind this element, if it contains any visible text. If no\nvisible text is present, this will be undefined. In the case of a flat background color,\nthis will consist of simply that color. In the case of a gradient, this will consist of each\nof the color stops. For anything more complicated, this will be an empty array. Images will\nbe ignored (as if the image had failed to load)."]
@@ -31427,7 +31509,7 @@ pub mod browser_protocol {
         impl CacheId {
             pub const IDENTIFIER: &'static str = "CacheStorage.CacheId";
         }
-        #[doc = "type of HTTP response cached"]
+        #[doc = "type of HTTP response cached\n[CachedResponseType](https://chromedevtools.github.io/devtools-protocol/tot/CacheStorage/#type-CachedResponseType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CachedResponseType {
             #[serde(rename = "basic")]
@@ -31682,6 +31764,7 @@ pub mod browser_protocol {
         impl Cache {
             pub const IDENTIFIER: &'static str = "CacheStorage.Cache";
         }
+        #[doc = "[Header](https://chromedevtools.github.io/devtools-protocol/tot/CacheStorage/#type-Header)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct Header {
             #[serde(rename = "name")]
@@ -32290,6 +32373,7 @@ pub mod browser_protocol {
     #[doc = "A domain for interacting with Cast, Presentation API, and Remote Playback API\nfunctionalities."]
     pub mod cast {
         use serde::{Deserialize, Serialize};
+        #[doc = "[Sink](https://chromedevtools.github.io/devtools-protocol/tot/Cast/#type-Sink)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct Sink {
             #[serde(rename = "name")]
@@ -32814,7 +32898,7 @@ pub mod browser_protocol {
         impl BackendNode {
             pub const IDENTIFIER: &'static str = "DOM.BackendNode";
         }
-        #[doc = "Pseudo element type."]
+        #[doc = "Pseudo element type.\n[PseudoType](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#type-PseudoType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum PseudoType {
             #[serde(rename = "first-line")]
@@ -32944,7 +33028,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Shadow root type."]
+        #[doc = "Shadow root type.\n[ShadowRootType](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#type-ShadowRootType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ShadowRootType {
             #[serde(rename = "user-agent")]
@@ -32974,7 +33058,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Document compatibility mode."]
+        #[doc = "Document compatibility mode.\n[CompatibilityMode](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#type-CompatibilityMode)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CompatibilityMode {
             #[serde(rename = "QuirksMode")]
@@ -33758,6 +33842,7 @@ pub mod browser_protocol {
         impl Rect {
             pub const IDENTIFIER: &'static str = "DOM.Rect";
         }
+        #[doc = "[CSSComputedStyleProperty](https://chromedevtools.github.io/devtools-protocol/tot/DOM/#type-CSSComputedStyleProperty)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct CssComputedStyleProperty {
             #[doc = "Computed style property name."]
@@ -38339,7 +38424,7 @@ pub mod browser_protocol {
     #[doc = "DOM debugging allows setting breakpoints on particular DOM operations and events. JavaScript\nexecution will stop on these operations as if there was a regular breakpoint set."]
     pub mod dom_debugger {
         use serde::{Deserialize, Serialize};
-        #[doc = "DOM breakpoint type."]
+        #[doc = "DOM breakpoint type.\n[DOMBreakpointType](https://chromedevtools.github.io/devtools-protocol/tot/DOMDebugger/#type-DOMBreakpointType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum DomBreakpointType {
             #[serde(rename = "subtree-modified")]
@@ -38373,7 +38458,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "CSP Violation type."]
+        #[doc = "CSP Violation type.\n[CSPViolationType](https://chromedevtools.github.io/devtools-protocol/tot/DOMDebugger/#type-CSPViolationType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CspViolationType {
             #[serde(rename = "trustedtype-sink-violation")]
@@ -40218,6 +40303,7 @@ pub mod browser_protocol {
         impl RareStringData {
             pub const IDENTIFIER: &'static str = "DOMSnapshot.RareStringData";
         }
+        #[doc = "[RareBooleanData](https://chromedevtools.github.io/devtools-protocol/tot/DOMSnapshot/#type-RareBooleanData)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct RareBooleanData {
             #[serde(rename = "index")]
@@ -40266,6 +40352,7 @@ pub mod browser_protocol {
         impl RareBooleanData {
             pub const IDENTIFIER: &'static str = "DOMSnapshot.RareBooleanData";
         }
+        #[doc = "[RareIntegerData](https://chromedevtools.github.io/devtools-protocol/tot/DOMSnapshot/#type-RareIntegerData)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct RareIntegerData {
             #[serde(rename = "index")]
@@ -40337,6 +40424,7 @@ pub mod browser_protocol {
         impl RareIntegerData {
             pub const IDENTIFIER: &'static str = "DOMSnapshot.RareIntegerData";
         }
+        #[doc = "[Rectangle](https://chromedevtools.github.io/devtools-protocol/tot/DOMSnapshot/#type-Rectangle)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct Rectangle(Vec<f64>);
         impl Rectangle {
@@ -41455,6 +41543,7 @@ pub mod browser_protocol {
     #[doc = "Query and modify DOM storage."]
     pub mod dom_storage {
         use serde::{Deserialize, Serialize};
+        #[doc = "[SerializedStorageKey](https://chromedevtools.github.io/devtools-protocol/tot/DOMStorage/#type-SerializedStorageKey)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, Eq, Hash)]
         pub struct SerializedStorageKey(String);
         impl SerializedStorageKey {
@@ -41561,6 +41650,7 @@ pub mod browser_protocol {
         impl Item {
             pub const IDENTIFIER: &'static str = "DOMStorage.Item";
         }
+        #[doc = "[clear](https://chromedevtools.github.io/devtools-protocol/tot/DOMStorage/#method-clear)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct ClearParams {
             #[serde(rename = "storageId")]
@@ -41611,6 +41701,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[clear](https://chromedevtools.github.io/devtools-protocol/tot/DOMStorage/#method-clear)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct ClearReturns {}
         impl chromiumoxide_types::Command for ClearParams {
@@ -41666,6 +41757,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for EnableParams {
             type Response = EnableReturns;
         }
+        #[doc = "[getDOMStorageItems](https://chromedevtools.github.io/devtools-protocol/tot/DOMStorage/#method-getDOMStorageItems)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct GetDomStorageItemsParams {
             #[serde(rename = "storageId")]
@@ -41716,6 +41808,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[getDOMStorageItems](https://chromedevtools.github.io/devtools-protocol/tot/DOMStorage/#method-getDOMStorageItems)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct GetDomStorageItemsReturns {
             #[serde(rename = "entries")]
@@ -41764,6 +41857,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for GetDomStorageItemsParams {
             type Response = GetDomStorageItemsReturns;
         }
+        #[doc = "[removeDOMStorageItem](https://chromedevtools.github.io/devtools-protocol/tot/DOMStorage/#method-removeDOMStorageItem)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct RemoveDomStorageItemParams {
             #[serde(rename = "storageId")]
@@ -41825,11 +41919,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[removeDOMStorageItem](https://chromedevtools.github.io/devtools-protocol/tot/DOMStorage/#method-removeDOMStorageItem)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct RemoveDomStorageItemReturns {}
         impl chromiumoxide_types::Command for RemoveDomStorageItemParams {
             type Response = RemoveDomStorageItemReturns;
         }
+        #[doc = "[setDOMStorageItem](https://chromedevtools.github.io/devtools-protocol/tot/DOMStorage/#method-setDOMStorageItem)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct SetDomStorageItemParams {
             #[serde(rename = "storageId")]
@@ -41906,11 +42002,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[setDOMStorageItem](https://chromedevtools.github.io/devtools-protocol/tot/DOMStorage/#method-setDOMStorageItem)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SetDomStorageItemReturns {}
         impl chromiumoxide_types::Command for SetDomStorageItemParams {
             type Response = SetDomStorageItemReturns;
         }
+        #[doc = "[domStorageItemAdded](https://chromedevtools.github.io/devtools-protocol/tot/DOMStorage/#event-domStorageItemAdded)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventDomStorageItemAdded {
             #[serde(rename = "storageId")]
@@ -41936,6 +42034,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[domStorageItemRemoved](https://chromedevtools.github.io/devtools-protocol/tot/DOMStorage/#event-domStorageItemRemoved)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventDomStorageItemRemoved {
             #[serde(rename = "storageId")]
@@ -41959,6 +42058,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[domStorageItemUpdated](https://chromedevtools.github.io/devtools-protocol/tot/DOMStorage/#event-domStorageItemUpdated)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventDomStorageItemUpdated {
             #[serde(rename = "storageId")]
@@ -41986,6 +42086,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[domStorageItemsCleared](https://chromedevtools.github.io/devtools-protocol/tot/DOMStorage/#event-domStorageItemsCleared)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventDomStorageItemsCleared {
             #[serde(rename = "storageId")]
@@ -42225,6 +42326,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for EnableParams {
             type Response = EnableReturns;
         }
+        #[doc = "[executeSQL](https://chromedevtools.github.io/devtools-protocol/tot/Database/#method-executeSQL)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct ExecuteSqlParams {
             #[serde(rename = "databaseId")]
@@ -42286,6 +42388,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[executeSQL](https://chromedevtools.github.io/devtools-protocol/tot/Database/#method-executeSQL)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct ExecuteSqlReturns {
             #[serde(rename = "columnNames")]
@@ -42357,6 +42460,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for ExecuteSqlParams {
             type Response = ExecuteSqlReturns;
         }
+        #[doc = "[getDatabaseTableNames](https://chromedevtools.github.io/devtools-protocol/tot/Database/#method-getDatabaseTableNames)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct GetDatabaseTableNamesParams {
             #[serde(rename = "databaseId")]
@@ -42407,6 +42511,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[getDatabaseTableNames](https://chromedevtools.github.io/devtools-protocol/tot/Database/#method-getDatabaseTableNames)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct GetDatabaseTableNamesReturns {
             #[serde(rename = "tableNames")]
@@ -42455,6 +42560,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for GetDatabaseTableNamesParams {
             type Response = GetDatabaseTableNamesReturns;
         }
+        #[doc = "[addDatabase](https://chromedevtools.github.io/devtools-protocol/tot/Database/#event-addDatabase)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventAddDatabase {
             #[serde(rename = "database")]
@@ -42684,6 +42790,7 @@ pub mod browser_protocol {
         impl ScreenOrientation {
             pub const IDENTIFIER: &'static str = "Emulation.ScreenOrientation";
         }
+        #[doc = "[DisplayFeature](https://chromedevtools.github.io/devtools-protocol/tot/Emulation/#type-DisplayFeature)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct DisplayFeature {
             #[doc = "Orientation of a display feature in relation to screen"]
@@ -42780,6 +42887,7 @@ pub mod browser_protocol {
         impl DisplayFeature {
             pub const IDENTIFIER: &'static str = "Emulation.DisplayFeature";
         }
+        #[doc = "[MediaFeature](https://chromedevtools.github.io/devtools-protocol/tot/Emulation/#type-MediaFeature)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct MediaFeature {
             #[serde(rename = "name")]
@@ -42828,7 +42936,7 @@ pub mod browser_protocol {
         impl MediaFeature {
             pub const IDENTIFIER: &'static str = "Emulation.MediaFeature";
         }
-        #[doc = "advance: If the scheduler runs out of immediate work, the virtual time base may fast forward to\nallow the next delayed task (if any) to run; pause: The virtual time base may not advance;\npauseIfNetworkFetchesPending: The virtual time base may not advance if there are any pending\nresource fetches."]
+        #[doc = "advance: If the scheduler runs out of immediate work, the virtual time base may fast forward to\nallow the next delayed task (if any) to run; pause: The virtual time base may not advance;\npauseIfNetworkFetchesPending: The virtual time base may not advance if there are any pending\nresource fetches.\n[VirtualTimePolicy](https://chromedevtools.github.io/devtools-protocol/tot/Emulation/#type-VirtualTimePolicy)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum VirtualTimePolicy {
             #[serde(rename = "advance")]
@@ -43050,7 +43158,7 @@ pub mod browser_protocol {
         impl UserAgentMetadata {
             pub const IDENTIFIER: &'static str = "Emulation.UserAgentMetadata";
         }
-        #[doc = "Enum of image types that can be disabled."]
+        #[doc = "Enum of image types that can be disabled.\n[DisabledImageType](https://chromedevtools.github.io/devtools-protocol/tot/Emulation/#type-DisabledImageType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum DisabledImageType {
             #[serde(rename = "avif")]
@@ -43629,6 +43737,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for SetDeviceMetricsOverrideParams {
             type Response = SetDeviceMetricsOverrideReturns;
         }
+        #[doc = "[setScrollbarsHidden](https://chromedevtools.github.io/devtools-protocol/tot/Emulation/#method-setScrollbarsHidden)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct SetScrollbarsHiddenParams {
             #[doc = "Whether scrollbars should be always hidden."]
@@ -43680,11 +43789,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[setScrollbarsHidden](https://chromedevtools.github.io/devtools-protocol/tot/Emulation/#method-setScrollbarsHidden)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SetScrollbarsHiddenReturns {}
         impl chromiumoxide_types::Command for SetScrollbarsHiddenParams {
             type Response = SetScrollbarsHiddenReturns;
         }
+        #[doc = "[setDocumentCookieDisabled](https://chromedevtools.github.io/devtools-protocol/tot/Emulation/#method-setDocumentCookieDisabled)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct SetDocumentCookieDisabledParams {
             #[doc = "Whether document.coookie API should be disabled."]
@@ -43736,11 +43847,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[setDocumentCookieDisabled](https://chromedevtools.github.io/devtools-protocol/tot/Emulation/#method-setDocumentCookieDisabled)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SetDocumentCookieDisabledReturns {}
         impl chromiumoxide_types::Command for SetDocumentCookieDisabledParams {
             type Response = SetDocumentCookieDisabledReturns;
         }
+        #[doc = "[setEmitTouchEventsForMouse](https://chromedevtools.github.io/devtools-protocol/tot/Emulation/#method-setEmitTouchEventsForMouse)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct SetEmitTouchEventsForMouseParams {
             #[doc = "Whether touch emulation based on mouse input should be enabled."]
@@ -43834,6 +43947,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[setEmitTouchEventsForMouse](https://chromedevtools.github.io/devtools-protocol/tot/Emulation/#method-setEmitTouchEventsForMouse)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SetEmitTouchEventsForMouseReturns {}
         impl chromiumoxide_types::Command for SetEmitTouchEventsForMouseParams {
@@ -44628,6 +44742,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for SetTimezoneOverrideParams {
             type Response = SetTimezoneOverrideReturns;
         }
+        #[doc = "[setDisabledImageTypes](https://chromedevtools.github.io/devtools-protocol/tot/Emulation/#method-setDisabledImageTypes)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct SetDisabledImageTypesParams {
             #[doc = "Image types to disable."]
@@ -44690,11 +44805,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[setDisabledImageTypes](https://chromedevtools.github.io/devtools-protocol/tot/Emulation/#method-setDisabledImageTypes)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SetDisabledImageTypesReturns {}
         impl chromiumoxide_types::Command for SetDisabledImageTypesParams {
             type Response = SetDisabledImageTypesReturns;
         }
+        #[doc = "[setHardwareConcurrencyOverride](https://chromedevtools.github.io/devtools-protocol/tot/Emulation/#method-setHardwareConcurrencyOverride)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct SetHardwareConcurrencyOverrideParams {
             #[doc = "Hardware concurrency to report"]
@@ -44749,6 +44866,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[setHardwareConcurrencyOverride](https://chromedevtools.github.io/devtools-protocol/tot/Emulation/#method-setHardwareConcurrencyOverride)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SetHardwareConcurrencyOverrideReturns {}
         impl chromiumoxide_types::Command for SetHardwareConcurrencyOverrideParams {
@@ -47061,6 +47179,7 @@ pub mod browser_protocol {
     }
     pub mod input {
         use serde::{Deserialize, Serialize};
+        #[doc = "[TouchPoint](https://chromedevtools.github.io/devtools-protocol/tot/Input/#type-TouchPoint)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct TouchPoint {
             #[doc = "X coordinate of the event relative to the main frame's viewport in CSS pixels."]
@@ -47210,6 +47329,7 @@ pub mod browser_protocol {
         impl TouchPoint {
             pub const IDENTIFIER: &'static str = "Input.TouchPoint";
         }
+        #[doc = "[GestureSourceType](https://chromedevtools.github.io/devtools-protocol/tot/Input/#type-GestureSourceType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum GestureSourceType {
             #[serde(rename = "default")]
@@ -47239,6 +47359,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[MouseButton](https://chromedevtools.github.io/devtools-protocol/tot/Input/#type-MouseButton)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum MouseButton {
             #[serde(rename = "none")]
@@ -47294,6 +47415,7 @@ pub mod browser_protocol {
         impl TimeSinceEpoch {
             pub const IDENTIFIER: &'static str = "Input.TimeSinceEpoch";
         }
+        #[doc = "[DragDataItem](https://chromedevtools.github.io/devtools-protocol/tot/Input/#type-DragDataItem)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct DragDataItem {
             #[doc = "Mime type of the dragged data."]
@@ -47366,6 +47488,7 @@ pub mod browser_protocol {
         impl DragDataItem {
             pub const IDENTIFIER: &'static str = "Input.DragDataItem";
         }
+        #[doc = "[DragData](https://chromedevtools.github.io/devtools-protocol/tot/Input/#type-DragData)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct DragData {
             #[serde(rename = "items")]
@@ -50247,6 +50370,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for MakeSnapshotParams {
             type Response = MakeSnapshotReturns;
         }
+        #[doc = "[profileSnapshot](https://chromedevtools.github.io/devtools-protocol/tot/LayerTree/#method-profileSnapshot)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct ProfileSnapshotParams {
             #[doc = "The id of the layer snapshot."]
@@ -50331,6 +50455,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[profileSnapshot](https://chromedevtools.github.io/devtools-protocol/tot/LayerTree/#method-profileSnapshot)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct ProfileSnapshotReturns {
             #[doc = "The array of paint profiles, one per run."]
@@ -50669,6 +50794,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for SnapshotCommandLogParams {
             type Response = SnapshotCommandLogReturns;
         }
+        #[doc = "[layerPainted](https://chromedevtools.github.io/devtools-protocol/tot/LayerTree/#event-layerPainted)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventLayerPainted {
             #[doc = "The id of the painted layer."]
@@ -50694,6 +50820,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[layerTreeDidChange](https://chromedevtools.github.io/devtools-protocol/tot/LayerTree/#event-layerTreeDidChange)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct EventLayerTreeDidChange {
             #[doc = "Layer tree, absent if not in the comspositing mode."]
@@ -51333,7 +51460,7 @@ pub mod browser_protocol {
     }
     pub mod memory {
         use serde::{Deserialize, Serialize};
-        #[doc = "Memory pressure level."]
+        #[doc = "Memory pressure level.\n[PressureLevel](https://chromedevtools.github.io/devtools-protocol/tot/Memory/#type-PressureLevel)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum PressureLevel {
             #[serde(rename = "moderate")]
@@ -51587,6 +51714,7 @@ pub mod browser_protocol {
         impl Module {
             pub const IDENTIFIER: &'static str = "Memory.Module";
         }
+        #[doc = "[getDOMCounters](https://chromedevtools.github.io/devtools-protocol/tot/Memory/#method-getDOMCounters)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct GetDomCountersParams {}
         impl GetDomCountersParams {
@@ -51605,6 +51733,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[getDOMCounters](https://chromedevtools.github.io/devtools-protocol/tot/Memory/#method-getDOMCounters)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct GetDomCountersReturns {
             #[serde(rename = "documents")]
@@ -51671,6 +51800,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for GetDomCountersParams {
             type Response = GetDomCountersReturns;
         }
+        #[doc = "[prepareForLeakDetection](https://chromedevtools.github.io/devtools-protocol/tot/Memory/#method-prepareForLeakDetection)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct PrepareForLeakDetectionParams {}
         impl PrepareForLeakDetectionParams {
@@ -51689,6 +51819,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[prepareForLeakDetection](https://chromedevtools.github.io/devtools-protocol/tot/Memory/#method-prepareForLeakDetection)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct PrepareForLeakDetectionReturns {}
         impl chromiumoxide_types::Command for PrepareForLeakDetectionParams {
@@ -52096,7 +52227,7 @@ pub mod browser_protocol {
     #[doc = "Network domain allows tracking network activities of the page. It exposes information about http,\nfile, data and other requests and responses, their headers, bodies, timing, etc."]
     pub mod network {
         use serde::{Deserialize, Serialize};
-        #[doc = "Resource type as it was perceived by the rendering engine."]
+        #[doc = "Resource type as it was perceived by the rendering engine.\n[ResourceType](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-ResourceType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ResourceType {
             #[serde(rename = "Document")]
@@ -52290,7 +52421,7 @@ pub mod browser_protocol {
         impl InterceptionId {
             pub const IDENTIFIER: &'static str = "Network.InterceptionId";
         }
-        #[doc = "Network level fetch failure reason."]
+        #[doc = "Network level fetch failure reason.\n[ErrorReason](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-ErrorReason)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ErrorReason {
             #[serde(rename = "Failed")]
@@ -52410,7 +52541,7 @@ pub mod browser_protocol {
         impl Headers {
             pub const IDENTIFIER: &'static str = "Network.Headers";
         }
-        #[doc = "The underlying connection technology that the browser is supposedly using."]
+        #[doc = "The underlying connection technology that the browser is supposedly using.\n[ConnectionType](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-ConnectionType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ConnectionType {
             #[serde(rename = "none")]
@@ -52464,7 +52595,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Represents the cookie's 'SameSite' status:\nhttps://tools.ietf.org/html/draft-west-first-party-cookies"]
+        #[doc = "Represents the cookie's 'SameSite' status:\nhttps://tools.ietf.org/html/draft-west-first-party-cookies\n[CookieSameSite](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-CookieSameSite)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CookieSameSite {
             #[serde(rename = "Strict")]
@@ -52494,7 +52625,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Represents the cookie's 'Priority' status:\nhttps://tools.ietf.org/html/draft-west-cookie-priority-00"]
+        #[doc = "Represents the cookie's 'Priority' status:\nhttps://tools.ietf.org/html/draft-west-cookie-priority-00\n[CookiePriority](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-CookiePriority)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CookiePriority {
             #[serde(rename = "Low")]
@@ -52524,7 +52655,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Represents the source scheme of the origin that originally set the cookie.\nA value of \"Unset\" allows protocol clients to emulate legacy cookie scope for the scheme.\nThis is a temporary ability and it will be removed in the future."]
+        #[doc = "Represents the source scheme of the origin that originally set the cookie.\nA value of \"Unset\" allows protocol clients to emulate legacy cookie scope for the scheme.\nThis is a temporary ability and it will be removed in the future.\n[CookieSourceScheme](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-CookieSourceScheme)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CookieSourceScheme {
             #[serde(rename = "Unset")]
@@ -52787,7 +52918,7 @@ pub mod browser_protocol {
         impl ResourceTiming {
             pub const IDENTIFIER: &'static str = "Network.ResourceTiming";
         }
-        #[doc = "Loading priority of a resource request."]
+        #[doc = "Loading priority of a resource request.\n[ResourcePriority](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-ResourcePriority)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ResourcePriority {
             #[serde(rename = "VeryLow")]
@@ -53456,7 +53587,7 @@ pub mod browser_protocol {
         impl SecurityDetails {
             pub const IDENTIFIER: &'static str = "Network.SecurityDetails";
         }
-        #[doc = "Whether the request complied with Certificate Transparency policy."]
+        #[doc = "Whether the request complied with Certificate Transparency policy.\n[CertificateTransparencyCompliance](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-CertificateTransparencyCompliance)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CertificateTransparencyCompliance {
             #[serde(rename = "unknown")]
@@ -53488,7 +53619,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "The reason why request was blocked."]
+        #[doc = "The reason why request was blocked.\n[BlockedReason](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-BlockedReason)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum BlockedReason {
             #[serde(rename = "other")]
@@ -53573,7 +53704,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "The reason why request was blocked."]
+        #[doc = "The reason why request was blocked.\n[CorsError](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-CorsError)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CorsError {
             #[serde(rename = "DisallowedByMode")]
@@ -53800,6 +53931,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[CorsErrorStatus](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-CorsErrorStatus)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct CorsErrorStatus {
             #[serde(rename = "corsError")]
@@ -53855,7 +53987,7 @@ pub mod browser_protocol {
         impl CorsErrorStatus {
             pub const IDENTIFIER: &'static str = "Network.CorsErrorStatus";
         }
-        #[doc = "Source of serviceworker response."]
+        #[doc = "Source of serviceworker response.\n[ServiceWorkerResponseSource](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-ServiceWorkerResponseSource)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ServiceWorkerResponseSource {
             #[serde(rename = "cache-storage")]
@@ -54000,6 +54132,7 @@ pub mod browser_protocol {
         impl TrustTokenParams {
             pub const IDENTIFIER: &'static str = "Network.TrustTokenParams";
         }
+        #[doc = "[TrustTokenOperationType](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-TrustTokenOperationType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum TrustTokenOperationType {
             #[doc = "Type \"token-request\" in the Trust Token API."]
@@ -54916,7 +55049,7 @@ pub mod browser_protocol {
         impl Cookie {
             pub const IDENTIFIER: &'static str = "Network.Cookie";
         }
-        #[doc = "Types of reasons why a cookie may not be stored from a response."]
+        #[doc = "Types of reasons why a cookie may not be stored from a response.\n[SetCookieBlockedReason](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-SetCookieBlockedReason)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum SetCookieBlockedReason {
             #[doc = "The cookie had the \"Secure\" attribute but was not received over a secure connection."]
@@ -55060,7 +55193,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Types of reasons why a cookie may not be sent with a request."]
+        #[doc = "Types of reasons why a cookie may not be sent with a request.\n[CookieBlockedReason](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-CookieBlockedReason)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CookieBlockedReason {
             #[doc = "The cookie had the \"Secure\" attribute and the connection was not secure."]
@@ -55713,7 +55846,7 @@ pub mod browser_protocol {
         impl AuthChallengeResponse {
             pub const IDENTIFIER: &'static str = "Network.AuthChallengeResponse";
         }
-        #[doc = "Stages of the interception to begin intercepting. Request will intercept before the request is\nsent. Response will intercept after the response is received."]
+        #[doc = "Stages of the interception to begin intercepting. Request will intercept before the request is\nsent. Response will intercept after the response is received.\n[InterceptionStage](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-InterceptionStage)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum InterceptionStage {
             #[serde(rename = "Request")]
@@ -56021,7 +56154,7 @@ pub mod browser_protocol {
         impl SignedExchangeHeader {
             pub const IDENTIFIER: &'static str = "Network.SignedExchangeHeader";
         }
-        #[doc = "Field type for a signed exchange related error."]
+        #[doc = "Field type for a signed exchange related error.\n[SignedExchangeErrorField](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-SignedExchangeErrorField)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum SignedExchangeErrorField {
             #[serde(rename = "signatureSig")]
@@ -56230,7 +56363,7 @@ pub mod browser_protocol {
         impl SignedExchangeInfo {
             pub const IDENTIFIER: &'static str = "Network.SignedExchangeInfo";
         }
-        #[doc = "List of content encodings supported by the backend."]
+        #[doc = "List of content encodings supported by the backend.\n[ContentEncoding](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-ContentEncoding)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ContentEncoding {
             #[serde(rename = "deflate")]
@@ -56260,6 +56393,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[PrivateNetworkRequestPolicy](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-PrivateNetworkRequestPolicy)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum PrivateNetworkRequestPolicy {
             #[serde(rename = "Allow")]
@@ -56309,6 +56443,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[IPAddressSpace](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-IPAddressSpace)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum IpAddressSpace {
             #[serde(rename = "Local")]
@@ -56342,6 +56477,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[ConnectTiming](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-ConnectTiming)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct ConnectTiming {
             #[doc = "Timing's requestTime is a baseline in seconds, while the other numbers are ticks in\nmilliseconds relatively to this requestTime. Matches ResourceTiming's requestTime for\nthe same request (but not for redirected requests)."]
@@ -56380,6 +56516,7 @@ pub mod browser_protocol {
         impl ConnectTiming {
             pub const IDENTIFIER: &'static str = "Network.ConnectTiming";
         }
+        #[doc = "[ClientSecurityState](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-ClientSecurityState)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct ClientSecurityState {
             #[serde(rename = "initiatorIsSecureContext")]
@@ -56469,6 +56606,7 @@ pub mod browser_protocol {
         impl ClientSecurityState {
             pub const IDENTIFIER: &'static str = "Network.ClientSecurityState";
         }
+        #[doc = "[CrossOriginOpenerPolicyValue](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-CrossOriginOpenerPolicyValue)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CrossOriginOpenerPolicyValue {
             #[serde(rename = "SameOrigin")]
@@ -56520,6 +56658,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[CrossOriginOpenerPolicyStatus](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-CrossOriginOpenerPolicyStatus)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct CrossOriginOpenerPolicyStatus {
             #[serde(rename = "value")]
@@ -56602,6 +56741,7 @@ pub mod browser_protocol {
         impl CrossOriginOpenerPolicyStatus {
             pub const IDENTIFIER: &'static str = "Network.CrossOriginOpenerPolicyStatus";
         }
+        #[doc = "[CrossOriginEmbedderPolicyValue](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-CrossOriginEmbedderPolicyValue)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CrossOriginEmbedderPolicyValue {
             #[serde(rename = "None")]
@@ -56635,6 +56775,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[CrossOriginEmbedderPolicyStatus](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-CrossOriginEmbedderPolicyStatus)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct CrossOriginEmbedderPolicyStatus {
             #[serde(rename = "value")]
@@ -56717,6 +56858,7 @@ pub mod browser_protocol {
         impl CrossOriginEmbedderPolicyStatus {
             pub const IDENTIFIER: &'static str = "Network.CrossOriginEmbedderPolicyStatus";
         }
+        #[doc = "[SecurityIsolationStatus](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-SecurityIsolationStatus)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SecurityIsolationStatus {
             #[serde(rename = "coop")]
@@ -56755,7 +56897,7 @@ pub mod browser_protocol {
         impl SecurityIsolationStatus {
             pub const IDENTIFIER: &'static str = "Network.SecurityIsolationStatus";
         }
-        #[doc = "The status of a Reporting API report."]
+        #[doc = "The status of a Reporting API report.\n[ReportStatus](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-ReportStatus)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ReportStatus {
             #[doc = "Report has been queued and no attempt has been made to deliver it yet,\nor attempted previous upload failed (impermanently)."]
@@ -56793,6 +56935,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[ReportId](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-ReportId)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, Eq, Hash)]
         pub struct ReportId(String);
         impl ReportId {
@@ -56950,6 +57093,7 @@ pub mod browser_protocol {
         impl ReportingApiReport {
             pub const IDENTIFIER: &'static str = "Network.ReportingApiReport";
         }
+        #[doc = "[ReportingApiEndpoint](https://chromedevtools.github.io/devtools-protocol/tot/Network/#type-ReportingApiEndpoint)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct ReportingApiEndpoint {
             #[doc = "The URL of the endpoint to which reports may be delivered."]
@@ -60351,6 +60495,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[reportingApiReportUpdated](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-reportingApiReportUpdated)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventReportingApiReportUpdated {
             #[serde(rename = "report")]
@@ -60372,6 +60517,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[reportingApiEndpointsChangedForOrigin](https://chromedevtools.github.io/devtools-protocol/tot/Network/#event-reportingApiEndpointsChangedForOrigin)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventReportingApiEndpointsChangedForOrigin {
             #[doc = "Origin of the document(s) which configured the endpoints."]
@@ -60963,6 +61109,7 @@ pub mod browser_protocol {
         impl BoxStyle {
             pub const IDENTIFIER: &'static str = "Overlay.BoxStyle";
         }
+        #[doc = "[ContrastAlgorithm](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#type-ContrastAlgorithm)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ContrastAlgorithm {
             #[serde(rename = "aa")]
@@ -61237,6 +61384,7 @@ pub mod browser_protocol {
         impl HighlightConfig {
             pub const IDENTIFIER: &'static str = "Overlay.HighlightConfig";
         }
+        #[doc = "[ColorFormat](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#type-ColorFormat)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ColorFormat {
             #[serde(rename = "rgb")]
@@ -61330,6 +61478,7 @@ pub mod browser_protocol {
         impl GridNodeHighlightConfig {
             pub const IDENTIFIER: &'static str = "Overlay.GridNodeHighlightConfig";
         }
+        #[doc = "[FlexNodeHighlightConfig](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#type-FlexNodeHighlightConfig)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct FlexNodeHighlightConfig {
             #[doc = "A descriptor for the highlight appearance of flex containers."]
@@ -61391,6 +61540,7 @@ pub mod browser_protocol {
         impl FlexNodeHighlightConfig {
             pub const IDENTIFIER: &'static str = "Overlay.FlexNodeHighlightConfig";
         }
+        #[doc = "[ScrollSnapContainerHighlightConfig](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#type-ScrollSnapContainerHighlightConfig)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct ScrollSnapContainerHighlightConfig {
             #[doc = "The style of the snapport border (default: transparent)"]
@@ -61457,6 +61607,7 @@ pub mod browser_protocol {
         impl ScrollSnapContainerHighlightConfig {
             pub const IDENTIFIER: &'static str = "Overlay.ScrollSnapContainerHighlightConfig";
         }
+        #[doc = "[ScrollSnapHighlightConfig](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#type-ScrollSnapHighlightConfig)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct ScrollSnapHighlightConfig {
             #[doc = "A descriptor for the highlight appearance of scroll snap containers."]
@@ -61581,6 +61732,7 @@ pub mod browser_protocol {
         impl HingeConfig {
             pub const IDENTIFIER: &'static str = "Overlay.HingeConfig";
         }
+        #[doc = "[ContainerQueryHighlightConfig](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#type-ContainerQueryHighlightConfig)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct ContainerQueryHighlightConfig {
             #[doc = "A descriptor for the highlight appearance of container query containers."]
@@ -61649,6 +61801,7 @@ pub mod browser_protocol {
         impl ContainerQueryHighlightConfig {
             pub const IDENTIFIER: &'static str = "Overlay.ContainerQueryHighlightConfig";
         }
+        #[doc = "[ContainerQueryContainerHighlightConfig](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#type-ContainerQueryContainerHighlightConfig)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct ContainerQueryContainerHighlightConfig {
             #[doc = "The style of the container border."]
@@ -61689,6 +61842,7 @@ pub mod browser_protocol {
         impl ContainerQueryContainerHighlightConfig {
             pub const IDENTIFIER: &'static str = "Overlay.ContainerQueryContainerHighlightConfig";
         }
+        #[doc = "[IsolatedElementHighlightConfig](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#type-IsolatedElementHighlightConfig)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct IsolatedElementHighlightConfig {
             #[doc = "A descriptor for the highlight appearance of an element in isolation mode."]
@@ -61750,6 +61904,7 @@ pub mod browser_protocol {
         impl IsolatedElementHighlightConfig {
             pub const IDENTIFIER: &'static str = "Overlay.IsolatedElementHighlightConfig";
         }
+        #[doc = "[IsolationModeHighlightConfig](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#type-IsolationModeHighlightConfig)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct IsolationModeHighlightConfig {
             #[doc = "The fill color of the resizers (default: transparent)."]
@@ -61803,6 +61958,7 @@ pub mod browser_protocol {
         impl IsolationModeHighlightConfig {
             pub const IDENTIFIER: &'static str = "Overlay.IsolationModeHighlightConfig";
         }
+        #[doc = "[InspectMode](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#type-InspectMode)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum InspectMode {
             #[serde(rename = "searchForNode")]
@@ -62803,6 +62959,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for SetShowAdHighlightsParams {
             type Response = SetShowAdHighlightsReturns;
         }
+        #[doc = "[setPausedInDebuggerMessage](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#method-setPausedInDebuggerMessage)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SetPausedInDebuggerMessageParams {
             #[doc = "The message to display, also triggers resume and step over controls."]
@@ -62846,6 +63003,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[setPausedInDebuggerMessage](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#method-setPausedInDebuggerMessage)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SetPausedInDebuggerMessageReturns {}
         impl chromiumoxide_types::Command for SetPausedInDebuggerMessageParams {
@@ -63045,6 +63203,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for SetShowGridOverlaysParams {
             type Response = SetShowGridOverlaysReturns;
         }
+        #[doc = "[setShowFlexOverlays](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#method-setShowFlexOverlays)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct SetShowFlexOverlaysParams {
             #[doc = "An array of node identifiers and descriptors for the highlight appearance."]
@@ -63120,11 +63279,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[setShowFlexOverlays](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#method-setShowFlexOverlays)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SetShowFlexOverlaysReturns {}
         impl chromiumoxide_types::Command for SetShowFlexOverlaysParams {
             type Response = SetShowFlexOverlaysReturns;
         }
+        #[doc = "[setShowScrollSnapOverlays](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#method-setShowScrollSnapOverlays)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct SetShowScrollSnapOverlaysParams {
             #[doc = "An array of node identifiers and descriptors for the highlight appearance."]
@@ -63200,11 +63361,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[setShowScrollSnapOverlays](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#method-setShowScrollSnapOverlays)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SetShowScrollSnapOverlaysReturns {}
         impl chromiumoxide_types::Command for SetShowScrollSnapOverlaysParams {
             type Response = SetShowScrollSnapOverlaysReturns;
         }
+        #[doc = "[setShowContainerQueryOverlays](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#method-setShowContainerQueryOverlays)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct SetShowContainerQueryOverlaysParams {
             #[doc = "An array of node identifiers and descriptors for the highlight appearance."]
@@ -63286,6 +63449,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[setShowContainerQueryOverlays](https://chromedevtools.github.io/devtools-protocol/tot/Overlay/#method-setShowContainerQueryOverlays)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SetShowContainerQueryOverlaysReturns {}
         impl chromiumoxide_types::Command for SetShowContainerQueryOverlaysParams {
@@ -63837,7 +64001,7 @@ pub mod browser_protocol {
         impl FrameId {
             pub const IDENTIFIER: &'static str = "Page.FrameId";
         }
-        #[doc = "Indicates whether a frame has been identified as an ad."]
+        #[doc = "Indicates whether a frame has been identified as an ad.\n[AdFrameType](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-AdFrameType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum AdFrameType {
             #[serde(rename = "none")]
@@ -63869,6 +64033,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[AdFrameExplanation](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-AdFrameExplanation)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum AdFrameExplanation {
             #[serde(rename = "ParentIsAd")]
@@ -64023,7 +64188,7 @@ pub mod browser_protocol {
         impl AdScriptId {
             pub const IDENTIFIER: &'static str = "Page.AdScriptId";
         }
-        #[doc = "Indicates whether the frame is a secure context and why it is the case."]
+        #[doc = "Indicates whether the frame is a secure context and why it is the case.\n[SecureContextType](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-SecureContextType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum SecureContextType {
             #[doc = "The origin is a secure context."]
@@ -64063,7 +64228,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Indicates whether the frame is cross-origin isolated and why it is the case."]
+        #[doc = "Indicates whether the frame is cross-origin isolated and why it is the case.\n[CrossOriginIsolatedContextType](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-CrossOriginIsolatedContextType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CrossOriginIsolatedContextType {
             #[doc = "The origin is cross-origin isolated."]
@@ -64102,6 +64267,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[GatedAPIFeatures](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-GatedAPIFeatures)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum GatedApiFeatures {
             #[serde(rename = "SharedArrayBuffers")]
@@ -64145,7 +64311,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "All Permissions Policy features. This enum should match the one defined\nin third_party/blink/renderer/core/permissions_policy/permissions_policy_features.json5."]
+        #[doc = "All Permissions Policy features. This enum should match the one defined\nin third_party/blink/renderer/core/permissions_policy/permissions_policy_features.json5.\n[PermissionsPolicyFeature](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-PermissionsPolicyFeature)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum PermissionsPolicyFeature {
             #[serde(rename = "accelerometer")]
@@ -64531,7 +64697,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Reason for a permissions policy feature to be disabled."]
+        #[doc = "Reason for a permissions policy feature to be disabled.\n[PermissionsPolicyBlockReason](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-PermissionsPolicyBlockReason)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum PermissionsPolicyBlockReason {
             #[doc = "Declaration in HTTP header."]
@@ -64575,6 +64741,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[PermissionsPolicyBlockLocator](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-PermissionsPolicyBlockLocator)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct PermissionsPolicyBlockLocator {
             #[serde(rename = "frameId")]
@@ -64630,6 +64797,7 @@ pub mod browser_protocol {
         impl PermissionsPolicyBlockLocator {
             pub const IDENTIFIER: &'static str = "Page.PermissionsPolicyBlockLocator";
         }
+        #[doc = "[PermissionsPolicyFeatureState](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-PermissionsPolicyFeatureState)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct PermissionsPolicyFeatureState {
             #[serde(rename = "feature")]
@@ -64692,7 +64860,7 @@ pub mod browser_protocol {
         impl PermissionsPolicyFeatureState {
             pub const IDENTIFIER: &'static str = "Page.PermissionsPolicyFeatureState";
         }
-        #[doc = "Origin Trial(https://www.chromium.org/blink/origin-trials) support.\nStatus for an Origin Trial token."]
+        #[doc = "Origin Trial(https://www.chromium.org/blink/origin-trials) support.\nStatus for an Origin Trial token.\n[OriginTrialTokenStatus](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-OriginTrialTokenStatus)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum OriginTrialTokenStatus {
             #[serde(rename = "Success")]
@@ -64764,7 +64932,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Status for an Origin Trial."]
+        #[doc = "Status for an Origin Trial.\n[OriginTrialStatus](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-OriginTrialStatus)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum OriginTrialStatus {
             #[serde(rename = "Enabled")]
@@ -64802,6 +64970,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[OriginTrialUsageRestriction](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-OriginTrialUsageRestriction)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum OriginTrialUsageRestriction {
             #[serde(rename = "None")]
@@ -64827,6 +64996,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[OriginTrialToken](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-OriginTrialToken)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct OriginTrialToken {
             #[serde(rename = "origin")]
@@ -64920,6 +65090,7 @@ pub mod browser_protocol {
         impl OriginTrialToken {
             pub const IDENTIFIER: &'static str = "Page.OriginTrialToken";
         }
+        #[doc = "[OriginTrialTokenWithStatus](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-OriginTrialTokenWithStatus)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct OriginTrialTokenWithStatus {
             #[serde(rename = "rawTokenText")]
@@ -64983,6 +65154,7 @@ pub mod browser_protocol {
         impl OriginTrialTokenWithStatus {
             pub const IDENTIFIER: &'static str = "Page.OriginTrialTokenWithStatus";
         }
+        #[doc = "[OriginTrial](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-OriginTrial)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct OriginTrial {
             #[serde(rename = "trialName")]
@@ -65568,7 +65740,7 @@ pub mod browser_protocol {
         impl ScriptIdentifier {
             pub const IDENTIFIER: &'static str = "Page.ScriptIdentifier";
         }
-        #[doc = "Transition type."]
+        #[doc = "Transition type.\n[TransitionType](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-TransitionType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum TransitionType {
             #[serde(rename = "link")]
@@ -65820,7 +65992,7 @@ pub mod browser_protocol {
         impl ScreencastFrameMetadata {
             pub const IDENTIFIER: &'static str = "Page.ScreencastFrameMetadata";
         }
-        #[doc = "Javascript dialog type."]
+        #[doc = "Javascript dialog type.\n[DialogType](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-DialogType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum DialogType {
             #[serde(rename = "alert")]
@@ -66425,6 +66597,7 @@ pub mod browser_protocol {
         impl FontSizes {
             pub const IDENTIFIER: &'static str = "Page.FontSizes";
         }
+        #[doc = "[ClientNavigationReason](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-ClientNavigationReason)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ClientNavigationReason {
             #[serde(rename = "formSubmissionGet")]
@@ -66488,6 +66661,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[ClientNavigationDisposition](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-ClientNavigationDisposition)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ClientNavigationDisposition {
             #[serde(rename = "currentTab")]
@@ -66525,6 +66699,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[InstallabilityErrorArgument](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-InstallabilityErrorArgument)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct InstallabilityErrorArgument {
             #[doc = "Argument name (e.g. name:'minimum-icon-size-in-pixels')."]
@@ -66645,7 +66820,7 @@ pub mod browser_protocol {
         impl InstallabilityError {
             pub const IDENTIFIER: &'static str = "Page.InstallabilityError";
         }
-        #[doc = "The referring-policy used for the navigation."]
+        #[doc = "The referring-policy used for the navigation.\n[ReferrerPolicy](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-ReferrerPolicy)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ReferrerPolicy {
             #[serde(rename = "noReferrer")]
@@ -66760,7 +66935,7 @@ pub mod browser_protocol {
         impl CompilationCacheParams {
             pub const IDENTIFIER: &'static str = "Page.CompilationCacheParams";
         }
-        #[doc = "The type of a frameNavigated event."]
+        #[doc = "The type of a frameNavigated event.\n[NavigationType](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-NavigationType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum NavigationType {
             #[serde(rename = "Navigation")]
@@ -66788,7 +66963,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "List of not restored reasons for back-forward cache."]
+        #[doc = "List of not restored reasons for back-forward cache.\n[BackForwardCacheNotRestoredReason](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-BackForwardCacheNotRestoredReason)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum BackForwardCacheNotRestoredReason {
             #[serde(rename = "NotPrimaryMainFrame")]
@@ -67050,7 +67225,7 @@ pub mod browser_protocol {
                 match s { "NotPrimaryMainFrame" | "notprimarymainframe" => Ok (BackForwardCacheNotRestoredReason :: NotPrimaryMainFrame) , "BackForwardCacheDisabled" | "backforwardcachedisabled" => Ok (BackForwardCacheNotRestoredReason :: BackForwardCacheDisabled) , "RelatedActiveContentsExist" | "relatedactivecontentsexist" => Ok (BackForwardCacheNotRestoredReason :: RelatedActiveContentsExist) , "HTTPStatusNotOK" | "HttpStatusNotOk" | "httpstatusnotok" => Ok (BackForwardCacheNotRestoredReason :: HttpStatusNotOk) , "SchemeNotHTTPOrHTTPS" | "SchemeNotHttpOrHttps" | "schemenothttporhttps" => Ok (BackForwardCacheNotRestoredReason :: SchemeNotHttpOrHttps) , "Loading" | "loading" => Ok (BackForwardCacheNotRestoredReason :: Loading) , "WasGrantedMediaAccess" | "wasgrantedmediaaccess" => Ok (BackForwardCacheNotRestoredReason :: WasGrantedMediaAccess) , "DisableForRenderFrameHostCalled" | "disableforrenderframehostcalled" => Ok (BackForwardCacheNotRestoredReason :: DisableForRenderFrameHostCalled) , "DomainNotAllowed" | "domainnotallowed" => Ok (BackForwardCacheNotRestoredReason :: DomainNotAllowed) , "HTTPMethodNotGET" | "HttpMethodNotGet" | "httpmethodnotget" => Ok (BackForwardCacheNotRestoredReason :: HttpMethodNotGet) , "SubframeIsNavigating" | "subframeisnavigating" => Ok (BackForwardCacheNotRestoredReason :: SubframeIsNavigating) , "Timeout" | "timeout" => Ok (BackForwardCacheNotRestoredReason :: Timeout) , "CacheLimit" | "cachelimit" => Ok (BackForwardCacheNotRestoredReason :: CacheLimit) , "JavaScriptExecution" | "javascriptexecution" => Ok (BackForwardCacheNotRestoredReason :: JavaScriptExecution) , "RendererProcessKilled" | "rendererprocesskilled" => Ok (BackForwardCacheNotRestoredReason :: RendererProcessKilled) , "RendererProcessCrashed" | "rendererprocesscrashed" => Ok (BackForwardCacheNotRestoredReason :: RendererProcessCrashed) , "SchedulerTrackedFeatureUsed" | "schedulertrackedfeatureused" => Ok (BackForwardCacheNotRestoredReason :: SchedulerTrackedFeatureUsed) , "ConflictingBrowsingInstance" | "conflictingbrowsinginstance" => Ok (BackForwardCacheNotRestoredReason :: ConflictingBrowsingInstance) , "CacheFlushed" | "cacheflushed" => Ok (BackForwardCacheNotRestoredReason :: CacheFlushed) , "ServiceWorkerVersionActivation" | "serviceworkerversionactivation" => Ok (BackForwardCacheNotRestoredReason :: ServiceWorkerVersionActivation) , "SessionRestored" | "sessionrestored" => Ok (BackForwardCacheNotRestoredReason :: SessionRestored) , "ServiceWorkerPostMessage" | "serviceworkerpostmessage" => Ok (BackForwardCacheNotRestoredReason :: ServiceWorkerPostMessage) , "EnteredBackForwardCacheBeforeServiceWorkerHostAdded" | "enteredbackforwardcachebeforeserviceworkerhostadded" => Ok (BackForwardCacheNotRestoredReason :: EnteredBackForwardCacheBeforeServiceWorkerHostAdded) , "RenderFrameHostReused_SameSite" | "RenderFrameHostReusedSameSite" | "renderframehostreused_samesite" => Ok (BackForwardCacheNotRestoredReason :: RenderFrameHostReusedSameSite) , "RenderFrameHostReused_CrossSite" | "RenderFrameHostReusedCrossSite" | "renderframehostreused_crosssite" => Ok (BackForwardCacheNotRestoredReason :: RenderFrameHostReusedCrossSite) , "ServiceWorkerClaim" | "serviceworkerclaim" => Ok (BackForwardCacheNotRestoredReason :: ServiceWorkerClaim) , "IgnoreEventAndEvict" | "ignoreeventandevict" => Ok (BackForwardCacheNotRestoredReason :: IgnoreEventAndEvict) , "HaveInnerContents" | "haveinnercontents" => Ok (BackForwardCacheNotRestoredReason :: HaveInnerContents) , "TimeoutPuttingInCache" | "timeoutputtingincache" => Ok (BackForwardCacheNotRestoredReason :: TimeoutPuttingInCache) , "BackForwardCacheDisabledByLowMemory" | "backforwardcachedisabledbylowmemory" => Ok (BackForwardCacheNotRestoredReason :: BackForwardCacheDisabledByLowMemory) , "BackForwardCacheDisabledByCommandLine" | "backforwardcachedisabledbycommandline" => Ok (BackForwardCacheNotRestoredReason :: BackForwardCacheDisabledByCommandLine) , "NetworkRequestDatapipeDrainedAsBytesConsumer" | "networkrequestdatapipedrainedasbytesconsumer" => Ok (BackForwardCacheNotRestoredReason :: NetworkRequestDatapipeDrainedAsBytesConsumer) , "NetworkRequestRedirected" | "networkrequestredirected" => Ok (BackForwardCacheNotRestoredReason :: NetworkRequestRedirected) , "NetworkRequestTimeout" | "networkrequesttimeout" => Ok (BackForwardCacheNotRestoredReason :: NetworkRequestTimeout) , "NetworkExceedsBufferLimit" | "networkexceedsbufferlimit" => Ok (BackForwardCacheNotRestoredReason :: NetworkExceedsBufferLimit) , "NavigationCancelledWhileRestoring" | "navigationcancelledwhilerestoring" => Ok (BackForwardCacheNotRestoredReason :: NavigationCancelledWhileRestoring) , "NotMostRecentNavigationEntry" | "notmostrecentnavigationentry" => Ok (BackForwardCacheNotRestoredReason :: NotMostRecentNavigationEntry) , "BackForwardCacheDisabledForPrerender" | "backforwardcachedisabledforprerender" => Ok (BackForwardCacheNotRestoredReason :: BackForwardCacheDisabledForPrerender) , "UserAgentOverrideDiffers" | "useragentoverridediffers" => Ok (BackForwardCacheNotRestoredReason :: UserAgentOverrideDiffers) , "ForegroundCacheLimit" | "foregroundcachelimit" => Ok (BackForwardCacheNotRestoredReason :: ForegroundCacheLimit) , "BrowsingInstanceNotSwapped" | "browsinginstancenotswapped" => Ok (BackForwardCacheNotRestoredReason :: BrowsingInstanceNotSwapped) , "BackForwardCacheDisabledForDelegate" | "backforwardcachedisabledfordelegate" => Ok (BackForwardCacheNotRestoredReason :: BackForwardCacheDisabledForDelegate) , "UnloadHandlerExistsInMainFrame" | "unloadhandlerexistsinmainframe" => Ok (BackForwardCacheNotRestoredReason :: UnloadHandlerExistsInMainFrame) , "UnloadHandlerExistsInSubFrame" | "unloadhandlerexistsinsubframe" => Ok (BackForwardCacheNotRestoredReason :: UnloadHandlerExistsInSubFrame) , "ServiceWorkerUnregistration" | "serviceworkerunregistration" => Ok (BackForwardCacheNotRestoredReason :: ServiceWorkerUnregistration) , "CacheControlNoStore" | "cachecontrolnostore" => Ok (BackForwardCacheNotRestoredReason :: CacheControlNoStore) , "CacheControlNoStoreCookieModified" | "cachecontrolnostorecookiemodified" => Ok (BackForwardCacheNotRestoredReason :: CacheControlNoStoreCookieModified) , "CacheControlNoStoreHTTPOnlyCookieModified" | "CacheControlNoStoreHttpOnlyCookieModified" | "cachecontrolnostorehttponlycookiemodified" => Ok (BackForwardCacheNotRestoredReason :: CacheControlNoStoreHttpOnlyCookieModified) , "NoResponseHead" | "noresponsehead" => Ok (BackForwardCacheNotRestoredReason :: NoResponseHead) , "Unknown" | "unknown" => Ok (BackForwardCacheNotRestoredReason :: Unknown) , "ActivationNavigationsDisallowedForBug1234857" | "activationnavigationsdisallowedforbug1234857" => Ok (BackForwardCacheNotRestoredReason :: ActivationNavigationsDisallowedForBug1234857) , "ErrorDocument" | "errordocument" => Ok (BackForwardCacheNotRestoredReason :: ErrorDocument) , "FencedFramesEmbedder" | "fencedframesembedder" => Ok (BackForwardCacheNotRestoredReason :: FencedFramesEmbedder) , "WebSocket" | "websocket" => Ok (BackForwardCacheNotRestoredReason :: WebSocket) , "WebTransport" | "webtransport" => Ok (BackForwardCacheNotRestoredReason :: WebTransport) , "WebRTC" | "WebRtc" | "webrtc" => Ok (BackForwardCacheNotRestoredReason :: WebRtc) , "MainResourceHasCacheControlNoStore" | "mainresourcehascachecontrolnostore" => Ok (BackForwardCacheNotRestoredReason :: MainResourceHasCacheControlNoStore) , "MainResourceHasCacheControlNoCache" | "mainresourcehascachecontrolnocache" => Ok (BackForwardCacheNotRestoredReason :: MainResourceHasCacheControlNoCache) , "SubresourceHasCacheControlNoStore" | "subresourcehascachecontrolnostore" => Ok (BackForwardCacheNotRestoredReason :: SubresourceHasCacheControlNoStore) , "SubresourceHasCacheControlNoCache" | "subresourcehascachecontrolnocache" => Ok (BackForwardCacheNotRestoredReason :: SubresourceHasCacheControlNoCache) , "ContainsPlugins" | "containsplugins" => Ok (BackForwardCacheNotRestoredReason :: ContainsPlugins) , "DocumentLoaded" | "documentloaded" => Ok (BackForwardCacheNotRestoredReason :: DocumentLoaded) , "DedicatedWorkerOrWorklet" | "dedicatedworkerorworklet" => Ok (BackForwardCacheNotRestoredReason :: DedicatedWorkerOrWorklet) , "OutstandingNetworkRequestOthers" | "outstandingnetworkrequestothers" => Ok (BackForwardCacheNotRestoredReason :: OutstandingNetworkRequestOthers) , "OutstandingIndexedDBTransaction" | "OutstandingIndexedDbTransaction" | "outstandingindexeddbtransaction" => Ok (BackForwardCacheNotRestoredReason :: OutstandingIndexedDbTransaction) , "RequestedNotificationsPermission" | "requestednotificationspermission" => Ok (BackForwardCacheNotRestoredReason :: RequestedNotificationsPermission) , "RequestedMIDIPermission" | "RequestedMidiPermission" | "requestedmidipermission" => Ok (BackForwardCacheNotRestoredReason :: RequestedMidiPermission) , "RequestedAudioCapturePermission" | "requestedaudiocapturepermission" => Ok (BackForwardCacheNotRestoredReason :: RequestedAudioCapturePermission) , "RequestedVideoCapturePermission" | "requestedvideocapturepermission" => Ok (BackForwardCacheNotRestoredReason :: RequestedVideoCapturePermission) , "RequestedBackForwardCacheBlockedSensors" | "requestedbackforwardcacheblockedsensors" => Ok (BackForwardCacheNotRestoredReason :: RequestedBackForwardCacheBlockedSensors) , "RequestedBackgroundWorkPermission" | "requestedbackgroundworkpermission" => Ok (BackForwardCacheNotRestoredReason :: RequestedBackgroundWorkPermission) , "BroadcastChannel" | "broadcastchannel" => Ok (BackForwardCacheNotRestoredReason :: BroadcastChannel) , "IndexedDBConnection" | "IndexedDbConnection" | "indexeddbconnection" => Ok (BackForwardCacheNotRestoredReason :: IndexedDbConnection) , "WebXR" | "WebXr" | "webxr" => Ok (BackForwardCacheNotRestoredReason :: WebXr) , "SharedWorker" | "sharedworker" => Ok (BackForwardCacheNotRestoredReason :: SharedWorker) , "WebLocks" | "weblocks" => Ok (BackForwardCacheNotRestoredReason :: WebLocks) , "WebHID" | "WebHid" | "webhid" => Ok (BackForwardCacheNotRestoredReason :: WebHid) , "WebShare" | "webshare" => Ok (BackForwardCacheNotRestoredReason :: WebShare) , "RequestedStorageAccessGrant" | "requestedstorageaccessgrant" => Ok (BackForwardCacheNotRestoredReason :: RequestedStorageAccessGrant) , "WebNfc" | "webnfc" => Ok (BackForwardCacheNotRestoredReason :: WebNfc) , "OutstandingNetworkRequestFetch" | "outstandingnetworkrequestfetch" => Ok (BackForwardCacheNotRestoredReason :: OutstandingNetworkRequestFetch) , "OutstandingNetworkRequestXHR" | "OutstandingNetworkRequestXhr" | "outstandingnetworkrequestxhr" => Ok (BackForwardCacheNotRestoredReason :: OutstandingNetworkRequestXhr) , "AppBanner" | "appbanner" => Ok (BackForwardCacheNotRestoredReason :: AppBanner) , "Printing" | "printing" => Ok (BackForwardCacheNotRestoredReason :: Printing) , "WebDatabase" | "webdatabase" => Ok (BackForwardCacheNotRestoredReason :: WebDatabase) , "PictureInPicture" | "pictureinpicture" => Ok (BackForwardCacheNotRestoredReason :: PictureInPicture) , "Portal" | "portal" => Ok (BackForwardCacheNotRestoredReason :: Portal) , "SpeechRecognizer" | "speechrecognizer" => Ok (BackForwardCacheNotRestoredReason :: SpeechRecognizer) , "IdleManager" | "idlemanager" => Ok (BackForwardCacheNotRestoredReason :: IdleManager) , "PaymentManager" | "paymentmanager" => Ok (BackForwardCacheNotRestoredReason :: PaymentManager) , "SpeechSynthesis" | "speechsynthesis" => Ok (BackForwardCacheNotRestoredReason :: SpeechSynthesis) , "KeyboardLock" | "keyboardlock" => Ok (BackForwardCacheNotRestoredReason :: KeyboardLock) , "WebOTPService" | "WebOtpService" | "webotpservice" => Ok (BackForwardCacheNotRestoredReason :: WebOtpService) , "OutstandingNetworkRequestDirectSocket" | "outstandingnetworkrequestdirectsocket" => Ok (BackForwardCacheNotRestoredReason :: OutstandingNetworkRequestDirectSocket) , "InjectedJavascript" | "injectedjavascript" => Ok (BackForwardCacheNotRestoredReason :: InjectedJavascript) , "InjectedStyleSheet" | "injectedstylesheet" => Ok (BackForwardCacheNotRestoredReason :: InjectedStyleSheet) , "Dummy" | "dummy" => Ok (BackForwardCacheNotRestoredReason :: Dummy) , "ContentSecurityHandler" | "contentsecurityhandler" => Ok (BackForwardCacheNotRestoredReason :: ContentSecurityHandler) , "ContentWebAuthenticationAPI" | "ContentWebAuthenticationApi" | "contentwebauthenticationapi" => Ok (BackForwardCacheNotRestoredReason :: ContentWebAuthenticationApi) , "ContentFileChooser" | "contentfilechooser" => Ok (BackForwardCacheNotRestoredReason :: ContentFileChooser) , "ContentSerial" | "contentserial" => Ok (BackForwardCacheNotRestoredReason :: ContentSerial) , "ContentFileSystemAccess" | "contentfilesystemaccess" => Ok (BackForwardCacheNotRestoredReason :: ContentFileSystemAccess) , "ContentMediaDevicesDispatcherHost" | "contentmediadevicesdispatcherhost" => Ok (BackForwardCacheNotRestoredReason :: ContentMediaDevicesDispatcherHost) , "ContentWebBluetooth" | "contentwebbluetooth" => Ok (BackForwardCacheNotRestoredReason :: ContentWebBluetooth) , "ContentWebUSB" | "ContentWebUsb" | "contentwebusb" => Ok (BackForwardCacheNotRestoredReason :: ContentWebUsb) , "ContentMediaSessionService" | "contentmediasessionservice" => Ok (BackForwardCacheNotRestoredReason :: ContentMediaSessionService) , "ContentScreenReader" | "contentscreenreader" => Ok (BackForwardCacheNotRestoredReason :: ContentScreenReader) , "EmbedderPopupBlockerTabHelper" | "embedderpopupblockertabhelper" => Ok (BackForwardCacheNotRestoredReason :: EmbedderPopupBlockerTabHelper) , "EmbedderSafeBrowsingTriggeredPopupBlocker" | "embeddersafebrowsingtriggeredpopupblocker" => Ok (BackForwardCacheNotRestoredReason :: EmbedderSafeBrowsingTriggeredPopupBlocker) , "EmbedderSafeBrowsingThreatDetails" | "embeddersafebrowsingthreatdetails" => Ok (BackForwardCacheNotRestoredReason :: EmbedderSafeBrowsingThreatDetails) , "EmbedderAppBannerManager" | "embedderappbannermanager" => Ok (BackForwardCacheNotRestoredReason :: EmbedderAppBannerManager) , "EmbedderDomDistillerViewerSource" | "embedderdomdistillerviewersource" => Ok (BackForwardCacheNotRestoredReason :: EmbedderDomDistillerViewerSource) , "EmbedderDomDistillerSelfDeletingRequestDelegate" | "embedderdomdistillerselfdeletingrequestdelegate" => Ok (BackForwardCacheNotRestoredReason :: EmbedderDomDistillerSelfDeletingRequestDelegate) , "EmbedderOomInterventionTabHelper" | "embedderoominterventiontabhelper" => Ok (BackForwardCacheNotRestoredReason :: EmbedderOomInterventionTabHelper) , "EmbedderOfflinePage" | "embedderofflinepage" => Ok (BackForwardCacheNotRestoredReason :: EmbedderOfflinePage) , "EmbedderChromePasswordManagerClientBindCredentialManager" | "embedderchromepasswordmanagerclientbindcredentialmanager" => Ok (BackForwardCacheNotRestoredReason :: EmbedderChromePasswordManagerClientBindCredentialManager) , "EmbedderPermissionRequestManager" | "embedderpermissionrequestmanager" => Ok (BackForwardCacheNotRestoredReason :: EmbedderPermissionRequestManager) , "EmbedderModalDialog" | "embeddermodaldialog" => Ok (BackForwardCacheNotRestoredReason :: EmbedderModalDialog) , "EmbedderExtensions" | "embedderextensions" => Ok (BackForwardCacheNotRestoredReason :: EmbedderExtensions) , "EmbedderExtensionMessaging" | "embedderextensionmessaging" => Ok (BackForwardCacheNotRestoredReason :: EmbedderExtensionMessaging) , "EmbedderExtensionMessagingForOpenPort" | "embedderextensionmessagingforopenport" => Ok (BackForwardCacheNotRestoredReason :: EmbedderExtensionMessagingForOpenPort) , "EmbedderExtensionSentMessageToCachedFrame" | "embedderextensionsentmessagetocachedframe" => Ok (BackForwardCacheNotRestoredReason :: EmbedderExtensionSentMessageToCachedFrame) , _ => Err (s . to_string ()) }
             }
         }
-        #[doc = "Types of not restored reasons for back-forward cache."]
+        #[doc = "Types of not restored reasons for back-forward cache.\n[BackForwardCacheNotRestoredReasonType](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-BackForwardCacheNotRestoredReasonType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum BackForwardCacheNotRestoredReasonType {
             #[serde(rename = "SupportPending")]
@@ -67086,6 +67261,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[BackForwardCacheNotRestoredExplanation](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-BackForwardCacheNotRestoredExplanation)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct BackForwardCacheNotRestoredExplanation {
             #[doc = "Type of the reason"]
@@ -67155,6 +67331,7 @@ pub mod browser_protocol {
         impl BackForwardCacheNotRestoredExplanation {
             pub const IDENTIFIER: &'static str = "Page.BackForwardCacheNotRestoredExplanation";
         }
+        #[doc = "[BackForwardCacheNotRestoredExplanationTree](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-BackForwardCacheNotRestoredExplanationTree)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct BackForwardCacheNotRestoredExplanationTree {
             #[doc = "URL of each frame"]
@@ -67253,7 +67430,7 @@ pub mod browser_protocol {
         impl BackForwardCacheNotRestoredExplanationTree {
             pub const IDENTIFIER: &'static str = "Page.BackForwardCacheNotRestoredExplanationTree";
         }
-        #[doc = "List of FinalStatus reasons for Prerender2."]
+        #[doc = "List of FinalStatus reasons for Prerender2.\n[PrerenderFinalStatus](https://chromedevtools.github.io/devtools-protocol/tot/Page/#type-PrerenderFinalStatus)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum PrerenderFinalStatus {
             #[serde(rename = "Activated")]
@@ -68070,6 +68247,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for EnableParams {
             type Response = EnableReturns;
         }
+        #[doc = "[getAppManifest](https://chromedevtools.github.io/devtools-protocol/tot/Page/#method-getAppManifest)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct GetAppManifestParams {}
         impl GetAppManifestParams {
@@ -68088,6 +68266,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[getAppManifest](https://chromedevtools.github.io/devtools-protocol/tot/Page/#method-getAppManifest)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct GetAppManifestReturns {
             #[doc = "Manifest location."]
@@ -68172,6 +68351,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for GetAppManifestParams {
             type Response = GetAppManifestReturns;
         }
+        #[doc = "[getInstallabilityErrors](https://chromedevtools.github.io/devtools-protocol/tot/Page/#method-getInstallabilityErrors)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct GetInstallabilityErrorsParams {}
         impl GetInstallabilityErrorsParams {
@@ -68190,6 +68370,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[getInstallabilityErrors](https://chromedevtools.github.io/devtools-protocol/tot/Page/#method-getInstallabilityErrors)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct GetInstallabilityErrorsReturns {
             #[serde(rename = "installabilityErrors")]
@@ -68246,6 +68427,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for GetInstallabilityErrorsParams {
             type Response = GetInstallabilityErrorsReturns;
         }
+        #[doc = "[getManifestIcons](https://chromedevtools.github.io/devtools-protocol/tot/Page/#method-getManifestIcons)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct GetManifestIconsParams {}
         impl GetManifestIconsParams {
@@ -68264,6 +68446,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[getManifestIcons](https://chromedevtools.github.io/devtools-protocol/tot/Page/#method-getManifestIcons)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct GetManifestIconsReturns {
             #[serde(rename = "primaryIcon")]
@@ -70986,6 +71169,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for SetInterceptFileChooserDialogParams {
             type Response = SetInterceptFileChooserDialogReturns;
         }
+        #[doc = "[domContentEventFired](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-domContentEventFired)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventDomContentEventFired {
             #[serde(rename = "timestamp")]
@@ -71204,6 +71388,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[frameResized](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-frameResized)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct EventFrameResized {}
         impl EventFrameResized {
@@ -71499,6 +71684,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[loadEventFired](https://chromedevtools.github.io/devtools-protocol/tot/Page/#event-loadEventFired)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventLoadEventFired {
             #[serde(rename = "timestamp")]
@@ -72020,6 +72206,7 @@ pub mod browser_protocol {
         impl LargestContentfulPaint {
             pub const IDENTIFIER: &'static str = "PerformanceTimeline.LargestContentfulPaint";
         }
+        #[doc = "[LayoutShiftAttribution](https://chromedevtools.github.io/devtools-protocol/tot/PerformanceTimeline/#type-LayoutShiftAttribution)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct LayoutShiftAttribution {
             #[serde(rename = "previousRect")]
@@ -72177,6 +72364,7 @@ pub mod browser_protocol {
         impl LayoutShift {
             pub const IDENTIFIER: &'static str = "PerformanceTimeline.LayoutShift";
         }
+        #[doc = "[TimelineEvent](https://chromedevtools.github.io/devtools-protocol/tot/PerformanceTimeline/#type-TimelineEvent)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct TimelineEvent {
             #[doc = "Identifies the frame that this event is related to. Empty for non-frame targets."]
@@ -72399,7 +72587,7 @@ pub mod browser_protocol {
         impl CertificateId {
             pub const IDENTIFIER: &'static str = "Security.CertificateId";
         }
-        #[doc = "A description of mixed content (HTTP resources on HTTPS pages), as defined by\nhttps://www.w3.org/TR/mixed-content/#categories"]
+        #[doc = "A description of mixed content (HTTP resources on HTTPS pages), as defined by\nhttps://www.w3.org/TR/mixed-content/#categories\n[MixedContentType](https://chromedevtools.github.io/devtools-protocol/tot/Security/#type-MixedContentType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum MixedContentType {
             #[serde(rename = "blockable")]
@@ -72431,7 +72619,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "The security level of a page or resource."]
+        #[doc = "The security level of a page or resource.\n[SecurityState](https://chromedevtools.github.io/devtools-protocol/tot/Security/#type-SecurityState)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum SecurityState {
             #[serde(rename = "unknown")]
@@ -72742,6 +72930,7 @@ pub mod browser_protocol {
         impl CertificateSecurityState {
             pub const IDENTIFIER: &'static str = "Security.CertificateSecurityState";
         }
+        #[doc = "[SafetyTipStatus](https://chromedevtools.github.io/devtools-protocol/tot/Security/#type-SafetyTipStatus)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum SafetyTipStatus {
             #[serde(rename = "badReputation")]
@@ -72769,6 +72958,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[SafetyTipInfo](https://chromedevtools.github.io/devtools-protocol/tot/Security/#type-SafetyTipInfo)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct SafetyTipInfo {
             #[doc = "Describes whether the page triggers any safety tips or reputation warnings. Default is unknown."]
@@ -73054,7 +73244,7 @@ pub mod browser_protocol {
         impl SecurityStateExplanation {
             pub const IDENTIFIER: &'static str = "Security.SecurityStateExplanation";
         }
-        #[doc = "The action to take when a certificate error occurs. continue will continue processing the\nrequest and cancel will cancel the request."]
+        #[doc = "The action to take when a certificate error occurs. continue will continue processing the\nrequest and cancel will cancel the request.\n[CertificateErrorAction](https://chromedevtools.github.io/devtools-protocol/tot/Security/#type-CertificateErrorAction)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum CertificateErrorAction {
             #[serde(rename = "continue")]
@@ -73214,6 +73404,7 @@ pub mod browser_protocol {
     }
     pub mod service_worker {
         use serde::{Deserialize, Serialize};
+        #[doc = "[RegistrationID](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#type-RegistrationID)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, Eq, Hash)]
         pub struct RegistrationId(String);
         impl RegistrationId {
@@ -73311,6 +73502,7 @@ pub mod browser_protocol {
         impl ServiceWorkerRegistration {
             pub const IDENTIFIER: &'static str = "ServiceWorker.ServiceWorkerRegistration";
         }
+        #[doc = "[ServiceWorkerVersionRunningStatus](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#type-ServiceWorkerVersionRunningStatus)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ServiceWorkerVersionRunningStatus {
             #[serde(rename = "stopped")]
@@ -73344,6 +73536,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[ServiceWorkerVersionStatus](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#type-ServiceWorkerVersionStatus)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ServiceWorkerVersionStatus {
             #[serde(rename = "new")]
@@ -73595,6 +73788,7 @@ pub mod browser_protocol {
         impl ServiceWorkerErrorMessage {
             pub const IDENTIFIER: &'static str = "ServiceWorker.ServiceWorkerErrorMessage";
         }
+        #[doc = "[deliverPushMessage](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-deliverPushMessage)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct DeliverPushMessageParams {
             #[serde(rename = "origin")]
@@ -73671,11 +73865,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[deliverPushMessage](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-deliverPushMessage)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct DeliverPushMessageReturns {}
         impl chromiumoxide_types::Command for DeliverPushMessageParams {
             type Response = DeliverPushMessageReturns;
         }
+        #[doc = "[disable](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-disable)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct DisableParams {}
         impl DisableParams {
@@ -73694,11 +73890,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[disable](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-disable)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct DisableReturns {}
         impl chromiumoxide_types::Command for DisableParams {
             type Response = DisableReturns;
         }
+        #[doc = "[dispatchSyncEvent](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-dispatchSyncEvent)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct DispatchSyncEventParams {
             #[serde(rename = "origin")]
@@ -73787,11 +73985,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[dispatchSyncEvent](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-dispatchSyncEvent)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct DispatchSyncEventReturns {}
         impl chromiumoxide_types::Command for DispatchSyncEventParams {
             type Response = DispatchSyncEventReturns;
         }
+        #[doc = "[dispatchPeriodicSyncEvent](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-dispatchPeriodicSyncEvent)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct DispatchPeriodicSyncEventParams {
             #[serde(rename = "origin")]
@@ -73868,11 +74068,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[dispatchPeriodicSyncEvent](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-dispatchPeriodicSyncEvent)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct DispatchPeriodicSyncEventReturns {}
         impl chromiumoxide_types::Command for DispatchPeriodicSyncEventParams {
             type Response = DispatchPeriodicSyncEventReturns;
         }
+        #[doc = "[enable](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-enable)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct EnableParams {}
         impl EnableParams {
@@ -73891,11 +74093,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[enable](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-enable)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct EnableReturns {}
         impl chromiumoxide_types::Command for EnableParams {
             type Response = EnableReturns;
         }
+        #[doc = "[inspectWorker](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-inspectWorker)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct InspectWorkerParams {
             #[serde(rename = "versionId")]
@@ -73951,11 +74155,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[inspectWorker](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-inspectWorker)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct InspectWorkerReturns {}
         impl chromiumoxide_types::Command for InspectWorkerParams {
             type Response = InspectWorkerReturns;
         }
+        #[doc = "[setForceUpdateOnPageLoad](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-setForceUpdateOnPageLoad)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct SetForceUpdateOnPageLoadParams {
             #[serde(rename = "forceUpdateOnPageLoad")]
@@ -74012,11 +74218,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[setForceUpdateOnPageLoad](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-setForceUpdateOnPageLoad)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SetForceUpdateOnPageLoadReturns {}
         impl chromiumoxide_types::Command for SetForceUpdateOnPageLoadParams {
             type Response = SetForceUpdateOnPageLoadReturns;
         }
+        #[doc = "[skipWaiting](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-skipWaiting)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct SkipWaitingParams {
             #[serde(rename = "scopeURL")]
@@ -74072,11 +74280,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[skipWaiting](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-skipWaiting)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct SkipWaitingReturns {}
         impl chromiumoxide_types::Command for SkipWaitingParams {
             type Response = SkipWaitingReturns;
         }
+        #[doc = "[startWorker](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-startWorker)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct StartWorkerParams {
             #[serde(rename = "scopeURL")]
@@ -74132,11 +74342,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[startWorker](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-startWorker)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct StartWorkerReturns {}
         impl chromiumoxide_types::Command for StartWorkerParams {
             type Response = StartWorkerReturns;
         }
+        #[doc = "[stopAllWorkers](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-stopAllWorkers)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct StopAllWorkersParams {}
         impl StopAllWorkersParams {
@@ -74155,11 +74367,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[stopAllWorkers](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-stopAllWorkers)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct StopAllWorkersReturns {}
         impl chromiumoxide_types::Command for StopAllWorkersParams {
             type Response = StopAllWorkersReturns;
         }
+        #[doc = "[stopWorker](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-stopWorker)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct StopWorkerParams {
             #[serde(rename = "versionId")]
@@ -74215,11 +74429,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[stopWorker](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-stopWorker)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct StopWorkerReturns {}
         impl chromiumoxide_types::Command for StopWorkerParams {
             type Response = StopWorkerReturns;
         }
+        #[doc = "[unregister](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-unregister)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct UnregisterParams {
             #[serde(rename = "scopeURL")]
@@ -74275,11 +74491,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[unregister](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-unregister)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct UnregisterReturns {}
         impl chromiumoxide_types::Command for UnregisterParams {
             type Response = UnregisterReturns;
         }
+        #[doc = "[updateRegistration](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-updateRegistration)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct UpdateRegistrationParams {
             #[serde(rename = "scopeURL")]
@@ -74335,11 +74553,13 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[updateRegistration](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#method-updateRegistration)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct UpdateRegistrationReturns {}
         impl chromiumoxide_types::Command for UpdateRegistrationParams {
             type Response = UpdateRegistrationReturns;
         }
+        #[doc = "[workerErrorReported](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#event-workerErrorReported)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventWorkerErrorReported {
             #[serde(rename = "errorMessage")]
@@ -74361,6 +74581,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[workerRegistrationUpdated](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#event-workerRegistrationUpdated)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventWorkerRegistrationUpdated {
             #[serde(rename = "registrations")]
@@ -74383,6 +74604,7 @@ pub mod browser_protocol {
                 Self::IDENTIFIER.into()
             }
         }
+        #[doc = "[workerVersionUpdated](https://chromedevtools.github.io/devtools-protocol/tot/ServiceWorker/#event-workerVersionUpdated)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct EventWorkerVersionUpdated {
             #[serde(rename = "versions")]
@@ -74408,6 +74630,7 @@ pub mod browser_protocol {
     }
     pub mod storage {
         use serde::{Deserialize, Serialize};
+        #[doc = "[SerializedStorageKey](https://chromedevtools.github.io/devtools-protocol/tot/Storage/#type-SerializedStorageKey)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, Eq, Hash)]
         pub struct SerializedStorageKey(String);
         impl SerializedStorageKey {
@@ -74436,7 +74659,7 @@ pub mod browser_protocol {
         impl SerializedStorageKey {
             pub const IDENTIFIER: &'static str = "Storage.SerializedStorageKey";
         }
-        #[doc = "Enum of possible storage types."]
+        #[doc = "Enum of possible storage types.\n[StorageType](https://chromedevtools.github.io/devtools-protocol/tot/Storage/#type-StorageType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum StorageType {
             #[serde(rename = "appcache")]
@@ -74603,7 +74826,7 @@ pub mod browser_protocol {
         impl TrustTokens {
             pub const IDENTIFIER: &'static str = "Storage.TrustTokens";
         }
-        #[doc = "Enum of interest group access types."]
+        #[doc = "Enum of interest group access types.\n[InterestGroupAccessType](https://chromedevtools.github.io/devtools-protocol/tot/Storage/#type-InterestGroupAccessType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum InterestGroupAccessType {
             #[serde(rename = "join")]
@@ -76734,7 +76957,7 @@ pub mod browser_protocol {
         impl VideoEncodeAcceleratorCapability {
             pub const IDENTIFIER: &'static str = "SystemInfo.VideoEncodeAcceleratorCapability";
         }
-        #[doc = "YUV subsampling type of the pixels of a given image."]
+        #[doc = "YUV subsampling type of the pixels of a given image.\n[SubsamplingFormat](https://chromedevtools.github.io/devtools-protocol/tot/SystemInfo/#type-SubsamplingFormat)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum SubsamplingFormat {
             #[serde(rename = "yuv420")]
@@ -76764,7 +76987,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Image format of a given image."]
+        #[doc = "Image format of a given image.\n[ImageType](https://chromedevtools.github.io/devtools-protocol/tot/SystemInfo/#type-ImageType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ImageType {
             #[serde(rename = "jpeg")]
@@ -77303,6 +77526,7 @@ pub mod browser_protocol {
     #[doc = "Supports additional targets discovery and allows to attach to them."]
     pub mod target {
         use serde::{Deserialize, Serialize};
+        #[doc = "[TargetID](https://chromedevtools.github.io/devtools-protocol/tot/Target/#type-TargetID)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, Eq, Hash)]
         pub struct TargetId(String);
         impl TargetId {
@@ -77370,6 +77594,7 @@ pub mod browser_protocol {
         impl SessionId {
             pub const IDENTIFIER: &'static str = "Target.SessionID";
         }
+        #[doc = "[TargetInfo](https://chromedevtools.github.io/devtools-protocol/tot/Target/#type-TargetInfo)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct TargetInfo {
             #[serde(rename = "targetId")]
@@ -77545,6 +77770,7 @@ pub mod browser_protocol {
         impl TargetFilter {
             pub const IDENTIFIER: &'static str = "Target.TargetFilter";
         }
+        #[doc = "[RemoteLocation](https://chromedevtools.github.io/devtools-protocol/tot/Target/#type-RemoteLocation)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct RemoteLocation {
             #[serde(rename = "host")]
@@ -79269,6 +79495,7 @@ pub mod browser_protocol {
         impl MemoryDumpConfig {
             pub const IDENTIFIER: &'static str = "Tracing.MemoryDumpConfig";
         }
+        #[doc = "[TraceConfig](https://chromedevtools.github.io/devtools-protocol/tot/Tracing/#type-TraceConfig)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct TraceConfig {
             #[doc = "Controls how the trace buffer stores data."]
@@ -79468,7 +79695,7 @@ pub mod browser_protocol {
         impl TraceConfig {
             pub const IDENTIFIER: &'static str = "Tracing.TraceConfig";
         }
-        #[doc = "Data format of a trace. Can be either the legacy JSON format or the\nprotocol buffer format. Note that the JSON format will be deprecated soon."]
+        #[doc = "Data format of a trace. Can be either the legacy JSON format or the\nprotocol buffer format. Note that the JSON format will be deprecated soon.\n[StreamFormat](https://chromedevtools.github.io/devtools-protocol/tot/Tracing/#type-StreamFormat)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum StreamFormat {
             #[serde(rename = "json")]
@@ -79494,7 +79721,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Compression type to use for traces returned via streams."]
+        #[doc = "Compression type to use for traces returned via streams.\n[StreamCompression](https://chromedevtools.github.io/devtools-protocol/tot/Tracing/#type-StreamCompression)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum StreamCompression {
             #[serde(rename = "none")]
@@ -79520,7 +79747,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Details exposed when memory request explicitly declared.\nKeep consistent with memory_dump_request_args.h and\nmemory_instrumentation.mojom"]
+        #[doc = "Details exposed when memory request explicitly declared.\nKeep consistent with memory_dump_request_args.h and\nmemory_instrumentation.mojom\n[MemoryDumpLevelOfDetail](https://chromedevtools.github.io/devtools-protocol/tot/Tracing/#type-MemoryDumpLevelOfDetail)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum MemoryDumpLevelOfDetail {
             #[serde(rename = "background")]
@@ -79550,7 +79777,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Backend type to use for tracing. `chrome` uses the Chrome-integrated\ntracing service and is supported on all platforms. `system` is only\nsupported on Chrome OS and uses the Perfetto system tracing service.\n`auto` chooses `system` when the perfettoConfig provided to Tracing.start\nspecifies at least one non-Chrome data source; otherwise uses `chrome`."]
+        #[doc = "Backend type to use for tracing. `chrome` uses the Chrome-integrated\ntracing service and is supported on all platforms. `system` is only\nsupported on Chrome OS and uses the Perfetto system tracing service.\n`auto` chooses `system` when the perfettoConfig provided to Tracing.start\nspecifies at least one non-Chrome data source; otherwise uses `chrome`.\n[TracingBackend](https://chromedevtools.github.io/devtools-protocol/tot/Tracing/#type-TracingBackend)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum TracingBackend {
             #[serde(rename = "auto")]
@@ -79999,6 +80226,7 @@ pub mod browser_protocol {
         impl chromiumoxide_types::Command for StartParams {
             type Response = StartReturns;
         }
+        #[doc = "[bufferUsage](https://chromedevtools.github.io/devtools-protocol/tot/Tracing/#event-bufferUsage)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct EventBufferUsage {
             #[doc = "A number in range [0..1] that indicates the used size of event buffer as a fraction of its\ntotal size."]
@@ -80130,7 +80358,7 @@ pub mod browser_protocol {
         impl RequestId {
             pub const IDENTIFIER: &'static str = "Fetch.RequestId";
         }
-        #[doc = "Stages of the request to handle. Request will intercept before the request is\nsent. Response will intercept after the response is received (but before response\nbody is received)."]
+        #[doc = "Stages of the request to handle. Request will intercept before the request is\nsent. Response will intercept after the response is received (but before response\nbody is received).\n[RequestStage](https://chromedevtools.github.io/devtools-protocol/tot/Fetch/#type-RequestStage)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum RequestStage {
             #[serde(rename = "Request")]
@@ -80156,6 +80384,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[RequestPattern](https://chromedevtools.github.io/devtools-protocol/tot/Fetch/#type-RequestPattern)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct RequestPattern {
             #[doc = "Wildcards (`'*'` -> zero or more, `'?'` -> exactly one) are allowed. Escape character is\nbackslash. Omitting is equivalent to `\"*\"`."]
@@ -81407,7 +81636,7 @@ pub mod browser_protocol {
         impl GraphObjectId {
             pub const IDENTIFIER: &'static str = "WebAudio.GraphObjectId";
         }
-        #[doc = "Enum of BaseAudioContext types"]
+        #[doc = "Enum of BaseAudioContext types\n[ContextType](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#type-ContextType)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ContextType {
             #[serde(rename = "realtime")]
@@ -81433,7 +81662,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Enum of AudioContextState from the spec"]
+        #[doc = "Enum of AudioContextState from the spec\n[ContextState](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#type-ContextState)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ContextState {
             #[serde(rename = "suspended")]
@@ -81492,7 +81721,7 @@ pub mod browser_protocol {
         impl NodeType {
             pub const IDENTIFIER: &'static str = "WebAudio.NodeType";
         }
-        #[doc = "Enum of AudioNode::ChannelCountMode from the spec"]
+        #[doc = "Enum of AudioNode::ChannelCountMode from the spec\n[ChannelCountMode](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#type-ChannelCountMode)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ChannelCountMode {
             #[serde(rename = "clamped-max")]
@@ -81522,7 +81751,7 @@ pub mod browser_protocol {
                 }
             }
         }
-        #[doc = "Enum of AudioNode::ChannelInterpretation from the spec"]
+        #[doc = "Enum of AudioNode::ChannelInterpretation from the spec\n[ChannelInterpretation](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#type-ChannelInterpretation)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum ChannelInterpretation {
             #[serde(rename = "discrete")]
@@ -81577,7 +81806,7 @@ pub mod browser_protocol {
         impl ParamType {
             pub const IDENTIFIER: &'static str = "WebAudio.ParamType";
         }
-        #[doc = "Enum of AudioParam::AutomationRate from the spec"]
+        #[doc = "Enum of AudioParam::AutomationRate from the spec\n[AutomationRate](https://chromedevtools.github.io/devtools-protocol/tot/WebAudio/#type-AutomationRate)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum AutomationRate {
             #[serde(rename = "a-rate")]
@@ -82547,6 +82776,7 @@ pub mod browser_protocol {
     #[doc = "This domain allows configuring virtual authenticators to test the WebAuthn\nAPI."]
     pub mod web_authn {
         use serde::{Deserialize, Serialize};
+        #[doc = "[AuthenticatorId](https://chromedevtools.github.io/devtools-protocol/tot/WebAuthn/#type-AuthenticatorId)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, Eq, Hash)]
         pub struct AuthenticatorId(String);
         impl AuthenticatorId {
@@ -82580,6 +82810,7 @@ pub mod browser_protocol {
         impl AuthenticatorId {
             pub const IDENTIFIER: &'static str = "WebAuthn.AuthenticatorId";
         }
+        #[doc = "[AuthenticatorProtocol](https://chromedevtools.github.io/devtools-protocol/tot/WebAuthn/#type-AuthenticatorProtocol)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum AuthenticatorProtocol {
             #[doc = "Universal 2nd Factor."]
@@ -82607,6 +82838,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[Ctap2Version](https://chromedevtools.github.io/devtools-protocol/tot/WebAuthn/#type-Ctap2Version)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum Ctap2Version {
             #[serde(rename = "ctap2_0")]
@@ -82632,6 +82864,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[AuthenticatorTransport](https://chromedevtools.github.io/devtools-protocol/tot/WebAuthn/#type-AuthenticatorTransport)"]
         #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
         pub enum AuthenticatorTransport {
             #[doc = "Cross-Platform authenticator attachments:"]
@@ -82671,6 +82904,7 @@ pub mod browser_protocol {
                 }
             }
         }
+        #[doc = "[VirtualAuthenticatorOptions](https://chromedevtools.github.io/devtools-protocol/tot/WebAuthn/#type-VirtualAuthenticatorOptions)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct VirtualAuthenticatorOptions {
             #[serde(rename = "protocol")]
@@ -82817,6 +83051,7 @@ pub mod browser_protocol {
         impl VirtualAuthenticatorOptions {
             pub const IDENTIFIER: &'static str = "WebAuthn.VirtualAuthenticatorOptions";
         }
+        #[doc = "[Credential](https://chromedevtools.github.io/devtools-protocol/tot/WebAuthn/#type-Credential)"]
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
         pub struct Credential {
             #[serde(rename = "credentialId")]
@@ -83811,6 +84046,7 @@ pub mod browser_protocol {
         impl PlayerId {
             pub const IDENTIFIER: &'static str = "Media.PlayerId";
         }
+        #[doc = "[Timestamp](https://chromedevtools.github.io/devtools-protocol/tot/Media/#type-Timestamp)"]
         #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
         pub struct Timestamp(f64);
         impl Timestamp {
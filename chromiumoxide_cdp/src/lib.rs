@@ -1,7 +1,9 @@
 use std::fmt;
 
 use crate::cdp::browser_protocol::fetch;
-use crate::cdp::browser_protocol::network::{self, CookieParam, DeleteCookiesParams};
+use crate::cdp::browser_protocol::network::{
+    self, Cookie, CookieParam, DeleteCookiesParams, TimeSinceEpoch,
+};
 use crate::cdp::browser_protocol::target::CreateTargetParams;
 use crate::cdp::js_protocol::runtime::{
     CallFunctionOnParams, EvaluateParams, ExceptionDetails, StackTrace,
@@ -16,6 +18,9 @@ pub mod revision;
 /// Currently built CDP revision
 pub const CURRENT_REVISION: Revision = Revision(1045489);
 
+/// Major version of the chromium release `CURRENT_REVISION` belongs to
+pub const CURRENT_MAJOR_VERSION: u32 = 107;
+
 /// convenience fixups
 impl Default for CreateTargetParams {
     fn default() -> Self {
@@ -79,6 +84,46 @@ impl DeleteCookiesParams {
     }
 }
 
+impl From<f64> for TimeSinceEpoch {
+    fn from(secs: f64) -> Self {
+        TimeSinceEpoch::new(secs)
+    }
+}
+
+/// Converts a cookie returned by the browser into a parameter that sets the
+/// same cookie again, preserving all of its attributes.
+impl From<&Cookie> for CookieParam {
+    fn from(cookie: &Cookie) -> Self {
+        CookieParam {
+            name: cookie.name.clone(),
+            value: cookie.value.clone(),
+            url: None,
+            domain: Some(cookie.domain.clone()),
+            path: Some(cookie.path.clone()),
+            secure: Some(cookie.secure),
+            http_only: Some(cookie.http_only),
+            same_site: cookie.same_site.clone(),
+            // session cookies report an expiry of `-1`
+            expires: if cookie.session {
+                None
+            } else {
+                Some(TimeSinceEpoch::new(cookie.expires))
+            },
+            priority: Some(cookie.priority.clone()),
+            same_party: Some(cookie.same_party),
+            source_scheme: Some(cookie.source_scheme.clone()),
+            source_port: Some(cookie.source_port),
+            partition_key: cookie.partition_key.clone(),
+        }
+    }
+}
+
+impl From<Cookie> for CookieParam {
+    fn from(cookie: Cookie) -> Self {
+        CookieParam::from(&cookie)
+    }
+}
+
 impl From<EvaluateParams> for CallFunctionOnParams {
     fn from(params: EvaluateParams) -> CallFunctionOnParams {
         CallFunctionOnParams {
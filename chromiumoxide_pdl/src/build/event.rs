@@ -9,6 +9,8 @@ pub struct EventType<'a> {
     pub domain: &'a Domain<'a>,
     pub inner: &'a Event<'a>,
     pub needs_box: bool,
+    /// The `cfg` attribute of the event's domain, if any
+    pub cfg: TokenStream,
 }
 
 impl<'a> EventType<'a> {
@@ -27,16 +29,27 @@ impl<'a> EventType<'a> {
 
 pub struct EventBuilder<'a> {
     events: Vec<EventType<'a>>,
+    /// Whether events are gated behind their domain's feature
+    gated: bool,
 }
 
 impl<'a> EventBuilder<'a> {
     pub fn new(events: Vec<EventType<'a>>) -> Self {
-        Self { events }
+        Self {
+            events,
+            gated: false,
+        }
+    }
+
+    pub fn gated(mut self, gated: bool) -> Self {
+        self.gated = gated;
+        self
     }
 
     pub fn build(self) -> TokenStream {
         let mut variants_stream = TokenStream::default();
         let mut var_idents = Vec::new();
+        let mut var_cfgs = Vec::new();
         let mut deserialize_from_method = TokenStream::default();
         let mut conversion_impls = TokenStream::default();
         let mut event_trait_impls = TokenStream::default();
@@ -56,6 +69,7 @@ impl<'a> EventBuilder<'a> {
 
             let domain_mod = format_ident!("{}", event.domain.name.to_snake_case());
             let protocol_mod = &event.protocol_mod;
+            let cfg = &event.cfg;
 
             let ty_qualifier = quote! {super::#protocol_mod::#domain_mod::#ty_ident};
 
@@ -67,6 +81,7 @@ impl<'a> EventBuilder<'a> {
 
             variants_stream.extend(quote! {
                 #deprecated
+                #cfg
                 #var_ident(#ty_ident),
             });
 
@@ -103,11 +118,18 @@ impl<'a> EventBuilder<'a> {
                     )
                 };
 
-            event_as_boxed_results.extend(event_as_boxed_result);
+            event_as_boxed_results.extend(quote! {
+                #cfg
+                #event_as_boxed_result
+            });
 
-            consume_event_macro_exprs.extend(consume_event_macro_expr);
+            consume_event_macro_exprs.extend(quote! {
+                #cfg
+                #consume_event_macro_expr
+            });
 
             conversion_impls.extend(quote! {
+                #cfg
                 impl std::convert::TryFrom<CdpEvent> for  #ty_qualifier {
                     type Error = CdpEvent;
 
@@ -118,6 +140,7 @@ impl<'a> EventBuilder<'a> {
                         }
                     }
                 }
+                #cfg
                 impl From<#ty_qualifier> for CdpEvent {
                     fn from(el: #ty_qualifier) -> CdpEvent {
                         #into_event
@@ -126,11 +149,13 @@ impl<'a> EventBuilder<'a> {
             });
 
             event_trait_impls.extend(quote! {
+                    #cfg
                     impl super::sealed::SealedEvent for #ty_qualifier {
                         fn as_any(&self) -> &dyn ::std::any::Any {
                             self
                         }
                     }
+                     #cfg
                      impl super::IntoEventKind for #ty_qualifier {
 
                        fn event_kind() -> super::EventKind where Self: Sized + 'static  {
@@ -142,10 +167,12 @@ impl<'a> EventBuilder<'a> {
 
             let deserialize_from = if event.needs_box {
                 quote! {
+                        #cfg
                         #ty_qualifier::IDENTIFIER =>CdpEvent::#var_ident(Box::new(map.next_value::<#ty_qualifier>()?)),
                 }
             } else {
                 quote! {
+                        #cfg
                         #ty_qualifier::IDENTIFIER =>CdpEvent::#var_ident(map.next_value::<#ty_qualifier>()?),
                 }
             };
@@ -153,8 +180,20 @@ impl<'a> EventBuilder<'a> {
             deserialize_from_method.extend(deserialize_from);
 
             var_idents.push(var_ident);
+            var_cfgs.push(cfg.clone());
         }
 
+        // the `cfg`s of the macro are evaluated in the calling crate, so events
+        // of domains that are enabled here but not there must be ignored
+        let consume_event_fallback = if self.gated {
+            quote! {
+                #[allow(unreachable_patterns)]
+                _ => {}
+            }
+        } else {
+            TokenStream::default()
+        };
+
         let event_impl = quote! {
             #[derive(Debug, PartialEq, Clone)]
             pub struct CdpEventMessage {
@@ -168,7 +207,7 @@ impl<'a> EventBuilder<'a> {
             impl chromiumoxide_types::Method for CdpEventMessage {
                 fn identifier(&self) -> chromiumoxide_types::MethodId {
                    match &self.params {
-                        #(CdpEvent::#var_idents(inner) => inner.identifier(),)*
+                        #(#var_cfgs CdpEvent::#var_idents(inner) => inner.identifier(),)*
                         _=> self.method.clone()
                     }
                 }
@@ -194,7 +233,7 @@ impl<'a> EventBuilder<'a> {
                 /// Serializes the event as Json
                 pub fn into_json(self) -> serde_json::Result<serde_json::Value> {
                     match self {
-                        #(CdpEvent::#var_idents(inner) => serde_json::to_value(inner),)*
+                        #(#var_cfgs CdpEvent::#var_idents(inner) => serde_json::to_value(inner),)*
                          CdpEvent::Other(val) => Ok(val)
                     }
                 }
@@ -341,6 +380,7 @@ impl<'a> EventBuilder<'a> {
                         match $ev {
                            #consume_event_macro_exprs
                            CdpEvent::Other(json) => {$custom(json);}
+                           #consume_event_fallback
                         }
                     }
                 };
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::fs;
 use std::io::{self, Error, ErrorKind};
 use std::ops::Deref;
@@ -14,7 +14,7 @@ use crate::build::builder::Builder;
 use crate::build::event::{EventBuilder, EventType};
 use crate::build::types::*;
 use crate::pdl::parser::parse_pdl;
-use crate::pdl::{DataType, Domain, Param, Protocol, Type, Variant};
+use crate::pdl::{DataType, Domain, Item, Param, Protocol, Type, Variant};
 
 /// Compile `.pdl` files into Rust files during a Cargo build.
 ///
@@ -50,6 +50,11 @@ pub fn compile_pdls<P: AsRef<Path>>(pdls: &[P]) -> io::Result<()> {
     Generator::default().compile_pdls(pdls)
 }
 
+/// The maximum size of an enum's variant before it gets boxed.
+///
+/// See https://rust-lang.github.io/rust-clippy/master/#large_enum_variant
+const MAX_UNBOXED_SIZE: usize = 200;
+
 /// Generates rust code for the Chrome DevTools Protocol
 #[derive(Debug, Clone)]
 pub struct Generator {
@@ -57,6 +62,10 @@ pub struct Generator {
     with_experimental: bool,
     with_deprecated: bool,
     out_dir: Option<PathBuf>,
+    /// Whether to gate each domain module behind a `domain_<name>` feature
+    domain_features: bool,
+    /// Whether to run `rustfmt` on the generated code
+    format: bool,
     protocol_mods: Vec<String>,
     domains: HashMap<String, usize>,
     target_mod: Option<String>,
@@ -79,6 +88,8 @@ impl Default for Generator {
             with_experimental: true,
             with_deprecated: false,
             out_dir: None,
+            domain_features: false,
+            format: std::env::var_os("CI").is_none(),
             protocol_mods: Vec::new(),
             domains: Default::default(),
             target_mod: Default::default(),
@@ -126,6 +137,32 @@ impl Generator {
         self
     }
 
+    /// Configures whether every domain module should be gated behind a
+    /// `domain_<name>` cargo feature, e.g. `domain_css` for the `CSS` domain.
+    ///
+    /// If enabled, a `<target_mod>_features.toml` file is written next to the
+    /// generated code. It lists all domain features including the features of
+    /// the domains they reference and is meant to be copied into the
+    /// `[features]` section of the crate that includes the generated code.
+    ///
+    /// Crates that invoke the generated `consume_event!` macro need to forward
+    /// the same features, since the `cfg`s of the macro are evaluated in the
+    /// calling crate.
+    pub fn domain_features(&mut self, domain_features: bool) -> &mut Self {
+        self.domain_features = domain_features;
+        self
+    }
+
+    /// Configures whether the generated code should be formatted with
+    /// `rustfmt`.
+    ///
+    /// Formatting requires the `rustfmt` binary to be installed. Defaults to
+    /// `true`, unless the `CI` environment variable is set.
+    pub fn format(&mut self, format: bool) -> &mut Self {
+        self.format = format;
+        self
+    }
+
     /// Configures the name of the module and file generated.
     pub fn target_mod(&mut self, mod_name: impl Into<String>) -> &mut Self {
         self.target_mod = Some(mod_name.into());
@@ -223,7 +260,14 @@ impl Generator {
             } else {
                 sequential_retries += 1;
                 if sequential_retries > refs.len() {
-                    panic!("No type found for ref {reff}");
+                    // the referenced type was not generated, e.g. because it
+                    // was filtered out, so assume the worst
+                    cargo_warning(format!(
+                        "No type found for ref {reff} of {name}, assuming it needs to be boxed"
+                    ));
+                    sequential_retries = 0;
+                    self.store_size(&name, Either::Left(MAX_UNBOXED_SIZE + 1));
+                    continue;
                 }
                 refs.push_back((name, reff));
             }
@@ -341,9 +385,9 @@ impl Generator {
                     }
 
                     #[inline]
-                    pub fn downcast_arc<T: SealedEvent>(self: ::std::sync::Arc<Self>) -> Result<::std::sync::Arc<T>, ::std::sync::Arc<Self>>
+                    pub fn downcast_arc<T>(self: ::std::sync::Arc<Self>) -> Result<::std::sync::Arc<T>, ::std::sync::Arc<Self>>
                         where
-                            T: ::std::any::Any + Send + Sync,
+                            T: ::std::any::Any + Send + Sync + SealedEvent,
                     {
                         if self.is::<T>() {
                             Ok(ArcAny::into_any_arc(self).downcast::<T>().unwrap())
@@ -388,7 +432,14 @@ impl Generator {
         let output = target.join(format!("{mod_name}.rs"));
         fs::write(output, stream.to_string())?;
 
-        fmt(target);
+        if self.domain_features {
+            let features = self.generate_domain_features(&protocols);
+            fs::write(target.join(format!("{mod_name}_features.toml")), features)?;
+        }
+
+        if self.format {
+            fmt(target)?;
+        }
         Ok(())
     }
 
@@ -419,6 +470,8 @@ impl Generator {
                 desc.extend(quote! {#[deprecated]})
             }
 
+            desc.extend(self.domain_cfg(domain));
+
             modules.extend(quote! {
                 #desc
                 pub mod #mod_name {
@@ -429,6 +482,63 @@ impl Generator {
         modules
     }
 
+    /// The `#[cfg(feature = "domain_<name>")]` attribute for the domain, if
+    /// domain features are enabled
+    fn domain_cfg(&self, domain: &Domain) -> TokenStream {
+        if self.domain_features {
+            let feature = domain_feature_name(domain);
+            quote! {
+                #[cfg(feature = #feature)]
+            }
+        } else {
+            TokenStream::default()
+        }
+    }
+
+    /// Generates the `[features]` table for all included domains.
+    ///
+    /// Every domain feature enables the features of all domains it references
+    /// and `all_domains` enables every domain.
+    fn generate_domain_features(&self, pdls: &[Protocol]) -> String {
+        let domains: Vec<_> = pdls
+            .iter()
+            .flat_map(|p| p.domains.iter())
+            .filter(|d| self.with_deprecated || !d.deprecated)
+            .filter(|d| self.with_experimental || !d.experimental)
+            .collect();
+        let features: BTreeMap<_, _> = domains
+            .iter()
+            .map(|d| (d.name.as_ref(), domain_feature_name(d)))
+            .collect();
+
+        let mut out = String::from(
+            "# This file is generated and should not be edited directly.\n[features]\n",
+        );
+        out.push_str(&format!(
+            "all_domains = [{}]\n",
+            features
+                .values()
+                .map(|f| format!("{f:?}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+        for domain in domains {
+            let deps = domain_dependencies(domain)
+                .into_iter()
+                .filter(|d| d != domain.name.as_ref())
+                .filter_map(|d| features.get(d.as_str()))
+                .map(|f| format!("{f:?}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            out.push_str(&format!(
+                "{} = [{}]\n",
+                features[domain.name.as_ref()],
+                deps
+            ));
+        }
+        out
+    }
+
     /// Generates all types are not circular for a single domain
     pub fn generate_domain(&mut self, domain: &Domain) -> TokenStream {
         let mut stream = self.serde_support.generate_serde_imports();
@@ -448,7 +558,11 @@ impl Generator {
     /// Type)
     fn generate_type(&mut self, domain: &Domain, dt: DomainDatatype) -> TokenStream {
         let stream = if let Some(vars) = dt.as_enum() {
-            self.generate_enum(&Variant::from(&dt), vars)
+            let ident = Variant {
+                description: Some(Cow::Owned(dt.type_description(domain.name.as_ref()))),
+                name: Cow::Borrowed(dt.name()),
+            };
+            self.generate_enum(&ident, vars)
         } else {
             let with_deprecated = self.with_deprecated;
             let with_experimental = self.with_experimental;
@@ -942,24 +1056,27 @@ impl Generator {
 
                 let ev_name = format!("Event{}", event.name().to_upper_camel_case());
 
-                let size = *self
-                    .type_size
-                    .get(&ev_name)
-                    .unwrap_or_else(|| panic!("No type found for ref {ev_name}"));
-
-                // See https://rust-lang.github.io/rust-clippy/master/#large_enum_variant
-                // The maximum size of a enum’s variant to avoid box suggestion is 200
-                let needs_box = size > 200;
+                let needs_box = if let Some(size) = self.type_size.get(&ev_name) {
+                    *size > MAX_UNBOXED_SIZE
+                } else {
+                    cargo_warning(format!(
+                        "No type found for event {ev_name}, assuming it needs to be boxed"
+                    ));
+                    true
+                };
 
                 events.push(EventType {
                     protocol_mod,
                     domain,
                     inner: event,
                     needs_box,
+                    cfg: self.domain_cfg(domain),
                 });
             }
         }
-        EventBuilder::new(events).build()
+        EventBuilder::new(events)
+            .gated(self.domain_features)
+            .build()
     }
 }
 
@@ -996,6 +1113,52 @@ fn generate_enum_str_fns(name: &Ident, vars: &[Ident], str_vals: &[Vec<String>])
     }
 }
 
+/// Emits a warning that cargo displays when running the build script
+fn cargo_warning(msg: impl std::fmt::Display) {
+    println!("cargo:warning={msg}");
+}
+
+/// The name of the feature that gates the domain: `domain_<name>`
+fn domain_feature_name(domain: &Domain) -> String {
+    format!("domain_{}", domain.name.to_snake_case())
+}
+
+/// Collects the names of all domains the domain depends on, either
+/// explicitly via `depends on` or by referencing one of its types
+fn domain_dependencies(domain: &Domain) -> BTreeSet<String> {
+    fn collect(ty: &Type, deps: &mut BTreeSet<String>) {
+        match ty {
+            Type::ArrayOf(ty) => collect(ty, deps),
+            Type::Ref(name) => {
+                if let Some((domain, _)) = name.rsplit_once('.') {
+                    deps.insert(domain.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut deps: BTreeSet<_> = domain.dependencies.iter().map(|d| d.to_string()).collect();
+    for ty in &domain.types {
+        collect(&ty.extends, &mut deps);
+        if let Some(Item::Properties(params)) = &ty.item {
+            params.iter().for_each(|p| collect(&p.r#type, &mut deps));
+        }
+    }
+    for cmd in &domain.commands {
+        cmd.parameters
+            .iter()
+            .chain(cmd.returns.iter())
+            .for_each(|p| collect(&p.r#type, &mut deps));
+    }
+    for ev in &domain.events {
+        ev.parameters
+            .iter()
+            .for_each(|p| collect(&p.r#type, &mut deps));
+    }
+    deps
+}
+
 /// Escapes reserved rust keywords
 pub(crate) fn generate_field_name(name: &str) -> String {
     let name = name.to_snake_case();
@@ -1164,36 +1327,42 @@ impl SerdeSupport {
     }
 }
 
-pub fn fmt(out_dir: impl AsRef<Path>) {
-    use std::io::Write;
+/// Formats all `.rs` files in the directory with `rustfmt`
+pub fn fmt(out_dir: impl AsRef<Path>) -> io::Result<()> {
     use std::process::Command;
     let out_dir = out_dir.as_ref();
-    let dir = std::fs::read_dir(out_dir).unwrap();
 
-    for entry in dir {
-        let file = entry.unwrap().file_name().into_string().unwrap();
-        if !file.ends_with(".rs") {
+    for entry in fs::read_dir(out_dir)? {
+        let path = entry?.path();
+        if path.extension().map_or(true, |ext| ext != "rs") {
             continue;
         }
-        let result = Command::new("rustfmt")
+        let output = Command::new("rustfmt")
             .arg("--emit")
             .arg("files")
             .arg("--edition")
             .arg("2018")
-            .arg(out_dir.join(file))
-            .output();
+            .arg(&path)
+            .output()
+            .map_err(|err| {
+                Error::new(
+                    err.kind(),
+                    format!("Failed to run rustfmt on {}: {err}", path.display()),
+                )
+            })?;
 
-        match result {
-            Err(e) => {
-                eprintln!("error running rustfmt: {e:?}");
-            }
-            Ok(output) => {
-                if !output.status.success() {
-                    io::stderr().write_all(&output.stderr).unwrap();
-                }
-            }
+        if !output.status.success() {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "rustfmt failed on {}: {}",
+                    path.display(),
+                    String::from_utf8_lossy(&output.stderr)
+                ),
+            ));
         }
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -1214,4 +1383,117 @@ mod tests {
             ])
             .unwrap();
     }
+
+    #[test]
+    fn unresolved_type_size_is_boxed() {
+        let pdl = r#"
+version
+  major 1
+  minor 3
+
+domain Test
+
+  experimental type Hidden extends object
+    properties
+      string value
+
+  event changed
+    parameters
+      Hidden hidden
+"#;
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let input = dir.join("test.pdl");
+        fs::write(&input, pdl).unwrap();
+
+        Generator::default()
+            .out_dir(dir)
+            .experimental(false)
+            .format(false)
+            .compile_pdls(&[input])
+            .unwrap();
+
+        let generated: String = fs::read_to_string(dir.join("cdp.rs"))
+            .unwrap()
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        assert!(generated.contains("TestChanged(Box<"));
+    }
+
+    const DOMAINS_PDL: &str = r#"
+version
+  major 1
+  minor 3
+
+domain Page
+
+  type FrameId extends string
+
+  event frameAttached
+    parameters
+      FrameId frameId
+
+domain Network
+  depends on Page
+
+  event requestWillBeSent
+    parameters
+      Page.FrameId frameId
+"#;
+
+    /// Generates the code for the `pdl` and returns it without whitespace
+    /// together with the generated features, if any.
+    fn generate(pdl: &str, domain_features: bool) -> (String, Option<String>) {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let input = dir.join("test.pdl");
+        fs::write(&input, pdl).unwrap();
+
+        Generator::default()
+            .out_dir(dir)
+            .domain_features(domain_features)
+            .format(false)
+            .compile_pdls(&[input])
+            .unwrap();
+
+        let generated = fs::read_to_string(dir.join("cdp.rs"))
+            .unwrap()
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        let features = fs::read_to_string(dir.join("cdp_features.toml")).ok();
+        (generated, features)
+    }
+
+    #[test]
+    fn domain_features_gate_domains() {
+        let (generated, features) = generate(DOMAINS_PDL, true);
+
+        assert!(generated.contains(r#"#[cfg(feature="domain_page")]pubmodpage{"#));
+        assert!(generated.contains(r#"#[cfg(feature="domain_network")]pubmodnetwork{"#));
+        assert!(generated.contains(r#"#[cfg(feature="domain_page")]PageFrameAttached("#));
+        assert!(generated.contains(r#"#[cfg(feature="domain_network")]NetworkRequestWillBeSent("#));
+        // events of domains the calling crate doesn't enable are ignored
+        assert!(generated.contains("#[allow(unreachable_patterns)]_=>{}"));
+
+        assert_eq!(
+            features.unwrap(),
+            "# This file is generated and should not be edited directly.\n\
+             [features]\n\
+             all_domains = [\"domain_network\", \"domain_page\"]\n\
+             domain_page = []\n\
+             domain_network = [\"domain_page\"]\n"
+        );
+    }
+
+    #[test]
+    fn no_domain_features_by_default() {
+        let (generated, features) = generate(DOMAINS_PDL, false);
+
+        assert!(!generated.contains("domain_page"));
+        assert!(!generated.contains("#[allow(unreachable_patterns)]_=>{}"));
+        assert!(generated.contains("pubmodpage{"));
+        assert!(features.is_none());
+    }
 }
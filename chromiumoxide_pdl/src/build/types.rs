@@ -64,10 +64,11 @@ impl<'a> DomainDatatype<'a> {
         todo!()
     }
 
-    pub fn type_description_tokens(&self, domain_name: &str) -> TokenStream {
+    /// The canonical url of this type's entry in the DevTools protocol viewer
+    pub fn doc_url(&self, domain_name: &str) -> String {
         let base_url = "https://chromedevtools.github.io/devtools-protocol/tot/";
 
-        let url = match self {
+        match self {
             DomainDatatype::Type(ty) => format!("{}{}/#type-{}", base_url, domain_name, ty.name()),
             DomainDatatype::Commnad(cmd) => {
                 format!("{}{}/#method-{}", base_url, domain_name, cmd.name())
@@ -75,15 +76,25 @@ impl<'a> DomainDatatype<'a> {
             DomainDatatype::Event(ev) => {
                 format!("{}{}/#event-{}", base_url, domain_name, ev.name())
             }
-        };
+        }
+    }
 
+    /// The type's description followed by a link to the protocol viewer.
+    ///
+    /// The link is always included, even if the type has no description.
+    pub fn type_description(&self, domain_name: &str) -> String {
+        let link = format!("[{}]({})", self.name(), self.doc_url(domain_name));
         if let Some(desc) = self.description() {
-            let desc = format!("{}\n[{}]({})", desc, self.name(), url);
-            quote! {
-                #[doc = #desc]
-            }
+            format!("{desc}\n{link}")
         } else {
-            TokenStream::default()
+            link
+        }
+    }
+
+    pub fn type_description_tokens(&self, domain_name: &str) -> TokenStream {
+        let desc = self.type_description(domain_name);
+        quote! {
+            #[doc = #desc]
         }
     }
 
@@ -231,12 +231,78 @@ pub struct Error {
     pub message: String,
 }
 
+impl Error {
+    /// The kind of the error, derived from its JSON-RPC `code`
+    pub fn kind(&self) -> ErrorCode {
+        ErrorCode::from(self.code)
+    }
+
+    /// Whether the browser does not know the requested method, which usually
+    /// means it speaks a different protocol revision.
+    pub fn is_method_not_found(&self) -> bool {
+        self.kind() == ErrorCode::MethodNotFound
+    }
+
+    /// Whether the parameters of the request were rejected
+    pub fn is_invalid_params(&self) -> bool {
+        self.kind() == ErrorCode::InvalidParams
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Error {}: {}", self.code, self.message)
     }
 }
 
+/// The JSON-RPC error codes the chromium server responds with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    /// `-32700`: the request was not valid JSON
+    ParseError,
+    /// `-32600`: the request was not a valid request object
+    InvalidRequest,
+    /// `-32601`: the method does not exist or is not available
+    MethodNotFound,
+    /// `-32602`: invalid method parameters
+    InvalidParams,
+    /// `-32603`: internal error of the server
+    InternalError,
+    /// `-32000`: generic server error, e.g. a node that could not be found
+    ServerError,
+    /// Any other code
+    Other(i64),
+}
+
+impl ErrorCode {
+    /// The numeric code
+    pub fn code(&self) -> i64 {
+        match self {
+            ErrorCode::ParseError => -32700,
+            ErrorCode::InvalidRequest => -32600,
+            ErrorCode::MethodNotFound => -32601,
+            ErrorCode::InvalidParams => -32602,
+            ErrorCode::InternalError => -32603,
+            ErrorCode::ServerError => -32000,
+            ErrorCode::Other(code) => *code,
+        }
+    }
+}
+
+impl From<i64> for ErrorCode {
+    fn from(code: i64) -> Self {
+        match code {
+            -32700 => ErrorCode::ParseError,
+            -32600 => ErrorCode::InvalidRequest,
+            -32601 => ErrorCode::MethodNotFound,
+            -32602 => ErrorCode::InvalidParams,
+            -32603 => ErrorCode::InternalError,
+            -32000 => ErrorCode::ServerError,
+            code => ErrorCode::Other(code),
+        }
+    }
+}
+
 impl std::error::Error for Error {}
 
 /// Represents a binary type as defined in the CDP protocol.
@@ -266,3 +332,30 @@ impl From<String> for Binary {
         Self(expr)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_code_round_trip() {
+        for code in [
+            -32700, -32600, -32601, -32602, -32603, -32000, -32001, 0, 42,
+        ] {
+            assert_eq!(ErrorCode::from(code).code(), code);
+        }
+        assert_eq!(ErrorCode::from(-32601), ErrorCode::MethodNotFound);
+        assert_eq!(ErrorCode::from(-32001), ErrorCode::Other(-32001));
+    }
+
+    #[test]
+    fn error_kind() {
+        let err = Error {
+            code: -32601,
+            message: "'Foo.bar' wasn't found".to_string(),
+        };
+        assert_eq!(err.kind(), ErrorCode::MethodNotFound);
+        assert!(err.is_method_not_found());
+        assert!(!err.is_invalid_params());
+    }
+}
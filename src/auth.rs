@@ -4,3 +4,18 @@ pub struct Credentials {
     pub username: String,
     pub password: String,
 }
+
+impl Credentials {
+    pub fn new(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+}
+
+impl<U: Into<String>, P: Into<String>> From<(U, P)> for Credentials {
+    fn from((username, password): (U, P)) -> Self {
+        Self::new(username, password)
+    }
+}
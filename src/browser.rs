@@ -6,14 +6,16 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use futures::channel::mpsc::{channel, unbounded, Sender};
+use futures::channel::mpsc::{channel, unbounded, Sender, UnboundedReceiver};
 use futures::channel::oneshot::channel as oneshot_channel;
-use futures::select;
-use futures::SinkExt;
+use futures::{future, select};
+use futures::{SinkExt, StreamExt};
 
+use chromiumoxide_cdp::cdp::browser_protocol::network::ClearBrowserCacheParams;
+use chromiumoxide_cdp::cdp::browser_protocol::storage::ClearCookiesParams;
 use chromiumoxide_cdp::cdp::browser_protocol::target::{
-    CreateBrowserContextParams, CreateTargetParams, DisposeBrowserContextParams, TargetId,
-    TargetInfo,
+    CreateBrowserContextParams, CreateTargetParams, DisposeBrowserContextParams, GetTargetsParams,
+    TargetId, TargetInfo,
 };
 use chromiumoxide_cdp::cdp::{CdpEventMessage, IntoEventKind};
 use chromiumoxide_types::*;
@@ -24,11 +26,15 @@ use crate::conn::Connection;
 use crate::detection::{self, DetectionOptions};
 use crate::error::{BrowserStderr, CdpError, Result};
 use crate::handler::browser::BrowserContext;
+use crate::handler::frame::UTILITY_WORLD_NAME;
+use crate::handler::target::TargetFilter;
 use crate::handler::viewport::Viewport;
 use crate::handler::{Handler, HandlerConfig, HandlerMessage, REQUEST_TIMEOUT};
 use crate::listeners::{EventListenerRequest, EventStream};
 use crate::page::Page;
+use crate::stealth::StealthPatch;
 use crate::utils;
+use crate::worker::Worker;
 use chromiumoxide_cdp::cdp::browser_protocol::browser::{
     BrowserContextId, CloseReturns, GetVersionParams, GetVersionReturns,
 };
@@ -50,10 +56,13 @@ pub struct Browser {
     debug_ws_url: String,
     /// The context of the browser
     browser_context: BrowserContext,
+    /// The errors of the handler, if it was spawned by `Browser::launch_and_drive`
+    handler_errors: Option<UnboundedReceiver<CdpError>>,
 }
 
 /// Browser connection information.
 #[derive(serde::Deserialize, Debug, Default)]
+#[serde(default)]
 pub struct BrowserConnection {
     #[serde(rename = "Browser")]
     /// The browser name
@@ -75,6 +84,109 @@ pub struct BrowserConnection {
     pub web_socket_debugger_url: String,
 }
 
+/// The version information of a browser with the parsed product version,
+/// see [`Browser::browser_version`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrowserVersion {
+    /// The product name, e.g. `HeadlessChrome`
+    pub product: String,
+    /// The product version, e.g. `120.0.6099.109`
+    pub version: String,
+    /// The major product version, e.g. `120`
+    pub major: Option<u32>,
+    /// The devtools protocol version, e.g. `1.3`
+    pub protocol_version: String,
+    /// The product revision
+    pub revision: String,
+    /// The default user agent
+    pub user_agent: String,
+    /// The V8 version
+    pub js_version: String,
+}
+
+impl From<GetVersionReturns> for BrowserVersion {
+    fn from(version: GetVersionReturns) -> Self {
+        let (product, product_version) = version
+            .product
+            .split_once('/')
+            .unwrap_or((version.product.as_str(), ""));
+        let major = product_version
+            .split('.')
+            .next()
+            .and_then(|major| major.parse().ok());
+        Self {
+            product: product.to_string(),
+            version: product_version.to_string(),
+            major,
+            protocol_version: version.protocol_version,
+            revision: version.revision,
+            user_agent: version.user_agent,
+            js_version: version.js_version,
+        }
+    }
+}
+
+/// Options to connect to an already running chromium instance with
+/// [`Browser::connect_with`].
+#[derive(Debug, Clone)]
+pub struct ConnectOptions {
+    /// The websocket or http URL of the chromium instance
+    url: String,
+    /// Additional headers to send with the websocket handshake
+    headers: HashMap<String, String>,
+    /// How to configure the handler
+    handler_config: HandlerConfig,
+    /// The maximum size of a websocket message in bytes
+    max_ws_message_size: Option<usize>,
+}
+
+impl ConnectOptions {
+    /// Options to connect to the given URL with the default `HandlerConfig`
+    /// and no additional headers.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            headers: HashMap::new(),
+            handler_config: HandlerConfig::default(),
+            max_ws_message_size: None,
+        }
+    }
+
+    /// Adds a header that is sent with the websocket handshake, like an
+    /// `Authorization` or `Host` header required by a reverse proxy.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Adds all the headers that are sent with the websocket handshake.
+    pub fn headers<I, K, V>(mut self, headers: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.headers
+            .extend(headers.into_iter().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    /// Sets the `HandlerConfig` to use for the connection.
+    pub fn handler_config(mut self, config: HandlerConfig) -> Self {
+        self.handler_config = config;
+        self
+    }
+
+    /// Limits the size of a message received over the websocket in bytes.
+    ///
+    /// The connection is closed if the browser sends a larger message, like
+    /// a huge screenshot or pdf. By default the size is unlimited.
+    pub fn max_ws_message_size(mut self, size: usize) -> Self {
+        self.max_ws_message_size = Some(size);
+        self
+    }
+}
+
 impl Browser {
     /// Connect to an already running chromium instance via the given URL.
     ///
@@ -83,6 +195,28 @@ impl Browser {
         Self::connect_with_config(url, HandlerConfig::default()).await
     }
 
+    /// Connect to an already running chromium instance via its http endpoint,
+    /// like `http://localhost:9222`, with the given `HandlerConfig`.
+    ///
+    /// This retrieves the websocket URL of the browser from the
+    /// `json/version` endpoint first, so the browser id, which changes on
+    /// every restart of the browser, doesn't need to be known.
+    ///
+    /// Unlike `Browser::connect_with_config`, which falls back to connecting
+    /// to the given URL directly, this fails if the endpoint doesn't respond
+    /// or doesn't report a `webSocketDebuggerUrl`.
+    pub async fn connect_http(
+        http_endpoint: impl Into<String>,
+        config: HandlerConfig,
+    ) -> Result<(Self, Handler)> {
+        let ws_url = fetch_ws_url(&http_endpoint.into(), &HashMap::new())
+            .await?
+            .ok_or_else(|| {
+                CdpError::msg("The `json/version` endpoint did not return a `webSocketDebuggerUrl`")
+            })?;
+        Self::connect_with_config(ws_url, config).await
+    }
+
     // Connect to an already running chromium instance with a given `HandlerConfig`.
     ///
     /// If the URL is a http URL, it will first attempt to retrieve the Websocket URL from the `json/version` endpoint.
@@ -90,45 +224,49 @@ impl Browser {
         url: impl Into<String>,
         config: HandlerConfig,
     ) -> Result<(Self, Handler)> {
-        let mut debug_ws_url = url.into();
+        Self::connect_with(ConnectOptions::new(url).handler_config(config)).await
+    }
+
+    /// Connect to an already running chromium instance with the given
+    /// [`ConnectOptions`].
+    ///
+    /// The configured headers are sent with the websocket handshake and, if
+    /// the URL is a http URL, with the request to the `json/version` endpoint.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::browser::{Browser, ConnectOptions};
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo() -> Result<()> {
+    ///     let (browser, handler) = Browser::connect_with(
+    ///         ConnectOptions::new("wss://browser.example.com/devtools/browser")
+    ///             .header("Authorization", "Bearer <token>"),
+    ///     )
+    ///     .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn connect_with(options: ConnectOptions) -> Result<(Self, Handler)> {
+        let ConnectOptions {
+            url: mut debug_ws_url,
+            headers,
+            handler_config: config,
+            max_ws_message_size,
+        } = options;
 
         if debug_ws_url.starts_with("http") {
-            match reqwest::Client::new()
-                .get(
-                    if debug_ws_url.ends_with("/json/version")
-                        || debug_ws_url.ends_with("/json/version/")
-                    {
-                        debug_ws_url.clone()
-                    } else {
-                        format!(
-                            "{}{}json/version",
-                            &debug_ws_url,
-                            if debug_ws_url.ends_with('/') { "" } else { "/" }
-                        )
-                    },
-                )
-                .header("content-type", "application/json")
-                .send()
-                .await
-            {
-                Ok(req) => {
-                    let socketaddr = req.remote_addr().unwrap();
-                    let connection: BrowserConnection =
-                        serde_json::from_slice(&req.bytes().await.unwrap_or_default())
-                            .unwrap_or_default();
-
-                    if !connection.web_socket_debugger_url.is_empty() {
-                        // prevent proxy interfaces from returning local ips to connect to the exact machine
-                        debug_ws_url = connection
-                            .web_socket_debugger_url
-                            .replace("127.0.0.1", &socketaddr.ip().to_string());
-                    }
-                }
-                Err(_) => return Err(CdpError::NoResponse),
+            if let Some(ws_url) = fetch_ws_url(&debug_ws_url, &headers).await? {
+                debug_ws_url = ws_url;
             }
         }
 
-        let conn = Connection::<CdpEventMessage>::connect(&debug_ws_url).await?;
+        let conn = Connection::<CdpEventMessage>::connect_with_max_message_size(
+            &debug_ws_url,
+            &headers,
+            max_ws_message_size,
+        )
+        .await?;
 
         let (tx, rx) = channel(1);
 
@@ -141,6 +279,7 @@ impl Browser {
             child: None,
             debug_ws_url,
             browser_context,
+            handler_errors: None,
         };
         Ok((browser, fut))
     }
@@ -180,7 +319,12 @@ impl Browser {
             };
             // extract the ws:
             let debug_ws_url = ws_url_from_output(child, timeout_fut).await?;
-            let conn = Connection::<CdpEventMessage>::connect(&debug_ws_url).await?;
+            let conn = Connection::<CdpEventMessage>::connect_with_max_message_size(
+                &debug_ws_url,
+                std::iter::empty::<(String, String)>(),
+                config.max_ws_message_size,
+            )
+            .await?;
             Ok((debug_ws_url, conn))
         }
 
@@ -209,8 +353,17 @@ impl Browser {
             viewport: config.viewport.clone(),
             context_ids: Vec::new(),
             request_timeout: config.request_timeout,
+            navigation_timeout: config.navigation_timeout,
             request_intercept: config.request_intercept,
             cache_enabled: config.cache_enabled,
+            javascript_enabled: config.javascript_enabled,
+            stealth_patches: config.stealth_patches.clone(),
+            init_scripts: config.init_scripts.clone(),
+            utility_world_name: config.utility_world_name.clone(),
+            target_init_timeout: config.target_init_timeout,
+            target_init_retries: config.target_init_retries,
+            strict_protocol_version: config.strict_protocol_version,
+            log_protocol: config.log_protocol,
         };
 
         let fut = Handler::new(conn, rx, handler_config);
@@ -222,11 +375,44 @@ impl Browser {
             child: Some(child),
             debug_ws_url,
             browser_context,
+            handler_errors: None,
         };
 
         Ok((browser, fut))
     }
 
+    /// Same as [`Browser::launch`], but drives the [`Handler`] on a background
+    /// task with [`Handler::spawn`].
+    ///
+    /// Errors of the handler are available via [`Browser::handler_error`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::{Browser, BrowserConfig};
+    /// # async fn demo() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let browser = Browser::launch_and_drive(BrowserConfig::builder().build()?).await?;
+    ///     let page = browser.new_page("https://en.wikipedia.org").await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(feature = "async-std-runtime", feature = "tokio-runtime"))]
+    pub async fn launch_and_drive(config: BrowserConfig) -> Result<Self> {
+        let (mut browser, handler) = Self::launch(config).await?;
+        let (_, errors) = handler.spawn();
+        browser.handler_errors = Some(errors);
+        Ok(browser)
+    }
+
+    /// Resolves with the next error of the handler spawned by
+    /// [`Browser::launch_and_drive`].
+    ///
+    /// Resolves with `None` once the handler finished, or immediately if the
+    /// handler is driven by the caller.
+    pub async fn handler_error(&mut self) -> Option<CdpError> {
+        self.handler_errors.as_mut()?.next().await
+    }
+
     /// Request to fetch all existing browser targets.
     ///
     /// By default, only targets launched after the browser connection are tracked
@@ -347,6 +533,43 @@ impl Browser {
         Ok(self)
     }
 
+    /// Creates a new isolated browser context that doesn't share cookies or
+    /// cache with other contexts.
+    ///
+    /// In contrast to `Browser::start_incognito_context` this doesn't change
+    /// the context of this browser, so any number of contexts can be used at
+    /// the same time. The context and all of its pages are disposed once the
+    /// returned [`BrowserContextGuard`] is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::browser::Browser;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(browser: Browser) -> Result<()> {
+    ///     let alice = browser.create_context().await?;
+    ///     let bob = browser.create_context().await?;
+    ///     let alice_page = alice.new_page("https://example.com/login").await?;
+    ///     let bob_page = bob.new_page("https://example.com/login").await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_context(&self) -> Result<BrowserContextGuard> {
+        let browser_context_id = self
+            .create_browser_context(CreateBrowserContextParams::default())
+            .await?;
+        self.sender
+            .clone()
+            .send(HandlerMessage::InsertContext(BrowserContext::from(
+                browser_context_id.clone(),
+            )))
+            .await?;
+        Ok(BrowserContextGuard {
+            id: Some(browser_context_id),
+            sender: self.sender.clone(),
+        })
+    }
+
     /// If a incognito session was created with
     /// `Browser::start_incognito_context` this disposes this context.
     ///
@@ -404,11 +627,59 @@ impl Browser {
         rx.await?
     }
 
+    /// Create a new browser page in the given browser context, regardless of
+    /// the context of this browser.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::browser::Browser;
+    /// # use chromiumoxide::error::Result;
+    /// # use chromiumoxide::cdp::browser_protocol::target::CreateBrowserContextParams;
+    /// # async fn demo(browser: Browser) -> Result<()> {
+    ///     let context = browser
+    ///         .create_browser_context(CreateBrowserContextParams::default())
+    ///         .await?;
+    ///     let page = browser
+    ///         .new_page_in_context(context, "https://example.com")
+    ///         .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn new_page_in_context(
+        &self,
+        browser_context_id: BrowserContextId,
+        params: impl Into<CreateTargetParams>,
+    ) -> Result<Page> {
+        let mut params = params.into();
+        params.browser_context_id = Some(browser_context_id.clone());
+
+        // the handler only assigns pages to contexts it knows
+        self.sender
+            .clone()
+            .send(HandlerMessage::InsertContext(BrowserContext::from(
+                browser_context_id,
+            )))
+            .await?;
+
+        self.new_page(params).await
+    }
+
     /// Version information about the browser
     pub async fn version(&self) -> Result<GetVersionReturns> {
         Ok(self.execute(GetVersionParams::default()).await?.result)
     }
 
+    /// Version information about the browser with the parsed product version
+    pub async fn browser_version(&self) -> Result<BrowserVersion> {
+        Ok(self.version().await?.into())
+    }
+
+    /// The major version of the browser, e.g. `120` for Chrome 120
+    pub async fn major_version(&self) -> Result<Option<u32>> {
+        Ok(self.browser_version().await?.major)
+    }
+
     /// Returns the user agent of the browser
     pub async fn user_agent(&self) -> Result<String> {
         Ok(self.version().await?.user_agent)
@@ -430,10 +701,30 @@ impl Browser {
 
     /// Return all of the pages of the browser
     pub async fn pages(&self) -> Result<Vec<Page>> {
+        self.pages_filtered(TargetFilter::default()).await
+    }
+
+    /// Return the pages of all targets that match the [`TargetFilter`].
+    ///
+    /// # Example pages of a browser context
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::browser::Browser;
+    /// # use chromiumoxide::cdp::browser_protocol::browser::BrowserContextId;
+    /// # use chromiumoxide::handler::target::TargetFilter;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(browser: Browser, context: BrowserContextId) -> Result<()> {
+    ///     let pages = browser
+    ///         .pages_filtered(TargetFilter::default().browser_context(context))
+    ///         .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn pages_filtered(&self, filter: TargetFilter) -> Result<Vec<Page>> {
         let (tx, rx) = oneshot_channel();
         self.sender
             .clone()
-            .send(HandlerMessage::GetPages(tx))
+            .send(HandlerMessage::GetPages(filter, tx))
             .await?;
         Ok(rx.await?)
     }
@@ -448,6 +739,89 @@ impl Browser {
         rx.await?.ok_or(CdpError::NotFound)
     }
 
+    /// Returns an initialized [`Page`] for every page that was already open
+    /// in the browser's context, e.g. the tabs of a browser connected to
+    /// with [`Browser::connect`].
+    ///
+    /// In contrast to [`Browser::pages`], which only returns the pages the
+    /// handler has discovered so far, this resolves once the page of every
+    /// open tab is initialized and its main frame has finished loading.
+    ///
+    /// Fails with [`CdpError::Timeout`] if the main frame of a tab didn't
+    /// finish loading within the navigation timeout.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::browser::Browser;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(browser: Browser) -> Result<()> {
+    ///     for page in browser.attach_to_existing().await? {
+    ///         println!("{:?}", page.url().await?);
+    ///     }
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn attach_to_existing(&self) -> Result<Vec<Page>> {
+        let targets = self
+            .execute(GetTargetsParams { filter: None })
+            .await?
+            .result
+            .target_infos;
+
+        let mut pages = Vec::new();
+        for info in targets {
+            if info.r#type != "page"
+                || (self.browser_context.id().is_some()
+                    && self.browser_context.id() != info.browser_context_id.as_ref())
+            {
+                continue;
+            }
+            let (tx, rx) = oneshot_channel();
+            self.sender
+                .clone()
+                .send(HandlerMessage::AttachToTarget(info, tx))
+                .await?;
+            pages.push(async move { rx.await? });
+        }
+        future::try_join_all(pages).await
+    }
+
+    /// Attaches to all service workers of the browser's context and returns
+    /// a [`Worker`] handle for each of them.
+    ///
+    /// The session of a worker is reused by subsequent calls until it is
+    /// detached with [`Worker::detach`].
+    pub async fn service_workers(&self) -> Result<Vec<Worker>> {
+        let targets = self
+            .execute(GetTargetsParams { filter: None })
+            .await?
+            .result
+            .target_infos;
+
+        let mut workers = Vec::new();
+        for info in targets {
+            if info.r#type != "service_worker"
+                || (self.browser_context.id().is_some()
+                    && self.browser_context.id() != info.browser_context_id.as_ref())
+            {
+                continue;
+            }
+            let (tx, rx) = oneshot_channel();
+            let target_id = info.target_id.clone();
+            self.sender
+                .clone()
+                .send(HandlerMessage::AttachToWorker(info, tx))
+                .await?;
+            let sender = self.sender.clone();
+            workers.push(async move {
+                let session_id = rx.await??;
+                Ok::<_, CdpError>(Worker::new(target_id, session_id, sender))
+            });
+        }
+        future::try_join_all(workers).await
+    }
+
     /// Set listener for browser event
     pub async fn event_listener<T: IntoEventKind>(&self) -> Result<EventStream<T>> {
         let (tx, rx) = unbounded();
@@ -461,6 +835,29 @@ impl Browser {
         Ok(EventStream::new(rx))
     }
 
+    /// Clears the cache of the browser.
+    ///
+    /// The browser target doesn't support the `Network` domain, so this is
+    /// executed within one of the browser's pages and requires at least one
+    /// open page, see `Browser::new_page`. The cache is shared by all pages,
+    /// so it doesn't matter which one.
+    pub async fn clear_browser_cache(&self) -> Result<()> {
+        let page = self.pages().await?.into_iter().next().ok_or_else(|| {
+            CdpError::msg("Clearing the browser cache requires an open page, but no page is open")
+        })?;
+        page.execute(ClearBrowserCacheParams::default()).await?;
+        Ok(())
+    }
+
+    /// Clears all cookies of the browser's current context.
+    pub async fn clear_browser_cookies(&self) -> Result<()> {
+        self.execute(ClearCookiesParams {
+            browser_context_id: self.browser_context.id().cloned(),
+        })
+        .await?;
+        Ok(())
+    }
+
     /// Creates a new empty browser context.
     pub async fn create_browser_context(
         &self,
@@ -501,6 +898,120 @@ impl Drop for Browser {
     }
 }
 
+/// An isolated browser context created with [`Browser::create_context`].
+///
+/// Dropping the guard disposes the context and closes all of its pages.
+#[derive(Debug)]
+pub struct BrowserContextGuard {
+    /// The id of the context, `None` once disposed
+    id: Option<BrowserContextId>,
+    sender: Sender<HandlerMessage>,
+}
+
+impl BrowserContextGuard {
+    /// The identifier of the browser context
+    pub fn id(&self) -> &BrowserContextId {
+        self.id.as_ref().expect("context is only disposed on drop")
+    }
+
+    /// Create a new page in this browser context
+    pub async fn new_page(&self, params: impl Into<CreateTargetParams>) -> Result<Page> {
+        let (tx, rx) = oneshot_channel();
+        let mut params = params.into();
+        params.browser_context_id = Some(self.id().clone());
+
+        self.sender
+            .clone()
+            .send(HandlerMessage::CreatePage(params, tx))
+            .await?;
+
+        rx.await?
+    }
+
+    /// Disposes the browser context and waits until the browser closed all of
+    /// its pages.
+    pub async fn dispose(mut self) -> Result<()> {
+        let id = self.id.take().expect("context is only disposed on drop");
+        let (tx, rx) = oneshot_channel();
+        let params = DisposeBrowserContextParams::new(id.clone());
+        let method = params.identifier();
+        let msg = CommandMessage::new(params, tx)?;
+
+        self.sender
+            .clone()
+            .send(HandlerMessage::Command(msg))
+            .await?;
+        let resp = rx.await??;
+        to_command_response::<DisposeBrowserContextParams>(resp, method)?;
+
+        self.sender
+            .clone()
+            .send(HandlerMessage::DisposeContext(BrowserContext::from(id)))
+            .await?;
+        Ok(())
+    }
+}
+
+impl Drop for BrowserContextGuard {
+    fn drop(&mut self) {
+        if let Some(id) = self.id.take() {
+            // every clone of the sender has a guaranteed slot in the channel, so
+            // this only fails if the handler is gone together with the browser
+            let (tx, _) = oneshot_channel();
+            if let Ok(msg) = CommandMessage::new(DisposeBrowserContextParams::new(id.clone()), tx) {
+                let _ = self.sender.clone().try_send(HandlerMessage::Command(msg));
+            }
+            let _ = self
+                .sender
+                .clone()
+                .try_send(HandlerMessage::DisposeContext(BrowserContext::from(id)));
+        }
+    }
+}
+
+/// Retrieve the websocket URL of the browser from the `json/version` endpoint
+/// of the browser's http `endpoint`.
+///
+/// Fails with [`CdpError::NoResponse`] if the endpoint doesn't respond and
+/// returns `None` if the response doesn't contain a `webSocketDebuggerUrl`.
+/// The host of the returned URL is replaced with the address that responded,
+/// since the browser reports its local address.
+async fn fetch_ws_url(endpoint: &str, headers: &HashMap<String, String>) -> Result<Option<String>> {
+    let url = if endpoint.ends_with("/json/version") || endpoint.ends_with("/json/version/") {
+        endpoint.to_string()
+    } else {
+        format!(
+            "{}{}json/version",
+            endpoint,
+            if endpoint.ends_with('/') { "" } else { "/" }
+        )
+    };
+
+    let mut req = reqwest::Client::new().get(url);
+    for (name, value) in headers {
+        req = req.header(name, value);
+    }
+    let resp = req
+        .header("content-type", "application/json")
+        .send()
+        .await
+        .map_err(|_| CdpError::NoResponse)?;
+
+    let remote_addr = resp.remote_addr();
+    let connection: BrowserConnection =
+        serde_json::from_slice(&resp.bytes().await.unwrap_or_default()).unwrap_or_default();
+    if connection.web_socket_debugger_url.is_empty() {
+        return Ok(None);
+    }
+
+    let mut ws_url = connection.web_socket_debugger_url;
+    if let Some(addr) = remote_addr {
+        // prevent proxy interfaces from returning local ips to connect to the exact machine
+        ws_url = ws_url.replace("127.0.0.1", &addr.ip().to_string());
+    }
+    Ok(Some(ws_url))
+}
+
 /// Resolve devtools WebSocket URL from the provided browser process
 ///
 /// If an error occurs, it returns the browser's stderr output.
@@ -614,6 +1125,13 @@ pub struct BrowserConfig {
     viewport: Option<Viewport>,
     /// The duration after a request with no response should time out
     request_timeout: Duration,
+    /// The duration after a navigation that didn't finish loading should
+    /// time out
+    navigation_timeout: Duration,
+    /// The duration after which the initialization of a new page fails
+    target_init_timeout: Duration,
+    /// How often a page initialization command without response is reissued
+    target_init_retries: u32,
 
     /// Additional command line arguments to pass to the browser instance.
     args: Vec<String>,
@@ -626,6 +1144,28 @@ pub struct BrowserConfig {
 
     /// Whether to enable cache
     pub cache_enabled: bool,
+
+    /// Whether javascript is enabled on new pages
+    pub javascript_enabled: bool,
+
+    /// The `StealthPatch`es to apply to every page
+    stealth_patches: Vec<StealthPatch>,
+
+    /// Scripts to evaluate in every new document of every page
+    init_scripts: Vec<String>,
+
+    /// The maximum size of a websocket message in bytes, unlimited if `None`
+    max_ws_message_size: Option<usize>,
+
+    /// Whether the handler fails if the browser's major version is older than
+    /// the supported one
+    strict_protocol_version: bool,
+
+    /// Whether all CDP traffic is logged with `tracing::debug!`
+    log_protocol: bool,
+
+    /// The name of the isolated world created in every frame
+    utility_world_name: String,
 }
 
 #[derive(Debug, Clone)]
@@ -644,10 +1184,27 @@ pub struct BrowserConfigBuilder {
     ignore_https_errors: bool,
     viewport: Option<Viewport>,
     request_timeout: Duration,
+    navigation_timeout: Duration,
+    target_init_timeout: Duration,
+    target_init_retries: u32,
     args: Vec<String>,
     disable_default_args: bool,
     request_intercept: bool,
     cache_enabled: bool,
+    javascript_enabled: bool,
+    stealth_patches: Vec<StealthPatch>,
+    init_scripts: Vec<InitScript>,
+    max_ws_message_size: Option<usize>,
+    strict_protocol_version: bool,
+    log_protocol: bool,
+    utility_world_name: String,
+}
+
+/// The source of a script added with `BrowserConfigBuilder::init_script`
+#[derive(Debug, Clone)]
+enum InitScript {
+    Source(String),
+    File(PathBuf),
 }
 
 impl BrowserConfig {
@@ -677,10 +1234,20 @@ impl Default for BrowserConfigBuilder {
             ignore_https_errors: true,
             viewport: Some(Default::default()),
             request_timeout: Duration::from_millis(REQUEST_TIMEOUT),
+            navigation_timeout: Duration::from_millis(REQUEST_TIMEOUT),
+            target_init_timeout: Duration::from_millis(REQUEST_TIMEOUT),
+            target_init_retries: 0,
             args: Vec::new(),
             disable_default_args: false,
             request_intercept: false,
             cache_enabled: true,
+            javascript_enabled: true,
+            stealth_patches: Vec::new(),
+            init_scripts: Vec::new(),
+            max_ws_message_size: None,
+            strict_protocol_version: false,
+            log_protocol: false,
+            utility_world_name: UTILITY_WORLD_NAME.to_string(),
         }
     }
 }
@@ -736,6 +1303,29 @@ impl BrowserConfigBuilder {
         self
     }
 
+    /// The duration after which a navigation that didn't finish loading
+    /// times out, independent of the `request_timeout` of regular commands.
+    ///
+    /// Can be overridden per page with `Page::set_default_navigation_timeout`.
+    pub fn navigation_timeout(mut self, timeout: Duration) -> Self {
+        self.navigation_timeout = timeout;
+        self
+    }
+
+    /// The duration after which the initialization of a new page fails with
+    /// `CdpError::Timeout` instead of leaving `Browser::new_page` pending.
+    pub fn target_init_timeout(mut self, timeout: Duration) -> Self {
+        self.target_init_timeout = timeout;
+        self
+    }
+
+    /// How often a command that initializes a new page is reissued if it
+    /// didn't receive a response within the `request_timeout`, defaults to 0.
+    pub fn target_init_retries(mut self, retries: u32) -> Self {
+        self.target_init_retries = retries;
+        self
+    }
+
     /// Configures the viewport of the browser, which defaults to `800x600`.
     /// `None` disables viewport emulation (i.e., it uses the browsers default
     /// configuration, which fills the available space. This is similar to what
@@ -837,6 +1427,85 @@ impl BrowserConfigBuilder {
         self
     }
 
+    /// Enables javascript on every new page, which is the default, see
+    /// `BrowserConfigBuilder::disable_javascript`.
+    pub fn enable_javascript(mut self) -> Self {
+        self.javascript_enabled = true;
+        self
+    }
+
+    /// Disables javascript on every new page, see
+    /// `Page::set_javascript_enabled`.
+    pub fn disable_javascript(mut self) -> Self {
+        self.javascript_enabled = false;
+        self
+    }
+
+    /// Applies all `StealthPatch`es to every page and removes the
+    /// `--enable-automation` flag, to make it harder to detect the browser as
+    /// automated.
+    pub fn stealth(mut self) -> Self {
+        self.stealth_patches = StealthPatch::ALL.to_vec();
+        self
+    }
+
+    /// Applies the `StealthPatch` to every page.
+    ///
+    /// `StealthPatch::Webdriver` also removes the `--enable-automation` flag
+    /// from the default arguments.
+    pub fn stealth_patch(mut self, patch: StealthPatch) -> Self {
+        if !self.stealth_patches.contains(&patch) {
+            self.stealth_patches.push(patch);
+        }
+        self
+    }
+
+    /// Evaluates the javascript `source` in every new document of every page
+    /// before any of the page's scripts run.
+    pub fn init_script(mut self, source: impl Into<String>) -> Self {
+        self.init_scripts.push(InitScript::Source(source.into()));
+        self
+    }
+
+    /// Same as `BrowserConfigBuilder::init_script` but with the script read
+    /// from the file at `path` when the config is built.
+    pub fn init_script_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.init_scripts
+            .push(InitScript::File(path.as_ref().to_path_buf()));
+        self
+    }
+
+    /// Limits the size of a message received over the websocket in bytes,
+    /// see `ConnectOptions::max_ws_message_size`.
+    pub fn max_ws_message_size(mut self, size: usize) -> Self {
+        self.max_ws_message_size = Some(size);
+        self
+    }
+
+    /// Fails the handler with `CdpError::ProtocolVersionMismatch` if the major
+    /// version of the launched browser is older than
+    /// `chromiumoxide_cdp::CURRENT_MAJOR_VERSION`, instead of only logging a
+    /// warning, see `HandlerConfig::strict_protocol_version`.
+    pub fn strict_protocol_version(mut self) -> Self {
+        self.strict_protocol_version = true;
+        self
+    }
+
+    /// Logs every command sent to and every response and event received from
+    /// the launched browser with `tracing::debug!`, see
+    /// `HandlerConfig::log_protocol`.
+    pub fn log_protocol(mut self) -> Self {
+        self.log_protocol = true;
+        self
+    }
+
+    /// The name of the isolated world that is created in every frame, see
+    /// `HandlerConfig::utility_world_name`.
+    pub fn utility_world_name(mut self, name: impl Into<String>) -> Self {
+        self.utility_world_name = name.into();
+        self
+    }
+
     pub fn build(self) -> std::result::Result<BrowserConfig, String> {
         let executable = if let Some(e) = self.executable {
             e
@@ -844,6 +1513,17 @@ impl BrowserConfigBuilder {
             detection::default_executable(self.executation_detection)?
         };
 
+        let init_scripts = self
+            .init_scripts
+            .into_iter()
+            .map(|script| match script {
+                InitScript::Source(source) => Ok(source),
+                InitScript::File(path) => std::fs::read_to_string(&path).map_err(|err| {
+                    format!("Failed to read init script {}: {}", path.display(), err)
+                }),
+            })
+            .collect::<std::result::Result<Vec<_>, String>>()?;
+
         Ok(BrowserConfig {
             headless: self.headless,
             sandbox: self.sandbox,
@@ -858,10 +1538,20 @@ impl BrowserConfigBuilder {
             ignore_https_errors: self.ignore_https_errors,
             viewport: self.viewport,
             request_timeout: self.request_timeout,
+            navigation_timeout: self.navigation_timeout,
+            target_init_timeout: self.target_init_timeout,
+            target_init_retries: self.target_init_retries,
             args: self.args,
             disable_default_args: self.disable_default_args,
             request_intercept: self.request_intercept,
             cache_enabled: self.cache_enabled,
+            javascript_enabled: self.javascript_enabled,
+            stealth_patches: self.stealth_patches,
+            init_scripts,
+            max_ws_message_size: self.max_ws_message_size,
+            strict_protocol_version: self.strict_protocol_version,
+            log_protocol: self.log_protocol,
+            utility_world_name: self.utility_world_name,
         })
     }
 }
@@ -873,7 +1563,16 @@ impl BrowserConfig {
         if self.disable_default_args {
             cmd.args(&self.args);
         } else {
-            cmd.args(DEFAULT_ARGS).args(&self.args);
+            // a custom `--lang` replaces the default one
+            let custom_lang = self.args.iter().any(|arg| arg.starts_with("--lang="));
+            // the automation flag sets `navigator.webdriver`
+            let hide_automation = self.stealth_patches.contains(&StealthPatch::Webdriver);
+            cmd.args(DEFAULT_ARGS.iter().filter(|arg| {
+                let replaced_lang = custom_lang && arg.starts_with("--lang=");
+                let automation = hide_automation && **arg == "--enable-automation";
+                !replaced_lang && !automation
+            }))
+            .args(&self.args);
         }
 
         if !self
@@ -949,6 +1648,9 @@ pub fn default_executable() -> Result<std::path::PathBuf, String> {
 }
 
 /// These are passed to the Chrome binary by default.
+///
+/// `--lang=en_US` determines the locale of all pages and is replaced by a
+/// custom `--lang` argument, see `BrowserConfigBuilder::arg`.
 /// Via https://github.com/puppeteer/puppeteer/blob/4846b8723cf20d3551c0d755df394cc5e0c82a94/src/node/Launcher.ts#L157
 static DEFAULT_ARGS: [&str; 25] = [
     "--disable-background-networking",
@@ -977,3 +1679,40 @@ static DEFAULT_ARGS: [&str; 25] = [
     "--enable-blink-features=IdleDetection",
     "--lang=en_US",
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn browser_version(product: &str) -> BrowserVersion {
+        BrowserVersion::from(GetVersionReturns {
+            protocol_version: "1.3".to_string(),
+            product: product.to_string(),
+            revision: "@0d27df8b1c0d7a4cf3b2e1a5e3e8f6e0a9d6c8b7".to_string(),
+            user_agent: "Mozilla/5.0 HeadlessChrome/120.0.6099.109".to_string(),
+            js_version: "12.0.267.8".to_string(),
+        })
+    }
+
+    #[test]
+    fn browser_version_from_product() {
+        let version = browser_version("HeadlessChrome/120.0.6099.109");
+        assert_eq!(version.product, "HeadlessChrome");
+        assert_eq!(version.version, "120.0.6099.109");
+        assert_eq!(version.major, Some(120));
+        assert_eq!(version.protocol_version, "1.3");
+        assert_eq!(version.js_version, "12.0.267.8");
+
+        assert_eq!(browser_version("Chrome/107.0.5296.0").major, Some(107));
+    }
+
+    #[test]
+    fn browser_version_without_version() {
+        let version = browser_version("Chrome");
+        assert_eq!(version.product, "Chrome");
+        assert_eq!(version.version, "");
+        assert_eq!(version.major, None);
+
+        assert_eq!(browser_version("Chrome/dev").major, None);
+    }
+}
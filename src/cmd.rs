@@ -96,8 +96,14 @@ pub struct CommandChain {
     cmds: VecDeque<(MethodId, serde_json::Value)>,
     /// The last issued command we currently waiting for its completion
     waiting: Option<(MethodId, Instant)>,
+    /// The params of the last issued command, in case it needs to be reissued
+    waiting_params: Option<serde_json::Value>,
     /// The window a response after issuing a request must arrive
     timeout: Duration,
+    /// How often a command that exceeded its deadline is reissued
+    retries: u32,
+    /// How often the last issued command was already reissued
+    attempts: u32,
 }
 
 pub type NextCommand = Poll<Option<Result<(MethodId, serde_json::Value), DeadlineExceeded>>>;
@@ -113,10 +119,19 @@ impl CommandChain {
         Self {
             cmds: VecDeque::from_iter(cmds),
             waiting: None,
+            waiting_params: None,
             timeout,
+            retries: 0,
+            attempts: 0,
         }
     }
 
+    /// Sets how often a command that didn't receive a response within the
+    /// timeout is reissued before the chain fails
+    pub fn set_retries(&mut self, retries: u32) {
+        self.retries = retries;
+    }
+
     /// queue in another request
     pub fn push_back(&mut self, method: MethodId, params: serde_json::Value) {
         self.cmds.push_back((method, params))
@@ -127,6 +142,7 @@ impl CommandChain {
     pub fn received_response(&mut self, identifier: &str) -> bool {
         if self.waiting.as_ref().map(|(c, _)| c.as_ref()) == Some(identifier) {
             self.waiting.take();
+            self.waiting_params.take();
             true
         } else {
             false
@@ -137,7 +153,18 @@ impl CommandChain {
     /// If the response timeout an error is returned instead
     pub fn poll(&mut self, now: Instant) -> NextCommand {
         if let Some((cmd, deadline)) = self.waiting.as_ref() {
-            if now > *deadline {
+            if now > *deadline && self.attempts < self.retries {
+                tracing::warn!(
+                    "Command {:?} exceeded deadline by {:?}, retrying",
+                    cmd,
+                    now - *deadline
+                );
+                self.attempts += 1;
+                let method = cmd.clone();
+                self.waiting = Some((method.clone(), now + self.timeout));
+                let params = self.waiting_params.clone().unwrap_or_default();
+                Poll::Ready(Some(Ok((method, params))))
+            } else if now > *deadline {
                 tracing::error!(
                     "Command {:?} exceeded deadline by {:?}",
                     cmd,
@@ -149,6 +176,8 @@ impl CommandChain {
             }
         } else if let Some((method, val)) = self.cmds.pop_front() {
             self.waiting = Some((method.clone(), now + self.timeout));
+            self.waiting_params = Some(val.clone());
+            self.attempts = 0;
             Poll::Ready(Some(Ok((method, val))))
         } else {
             Poll::Ready(None)
@@ -161,7 +190,10 @@ impl Default for CommandChain {
         Self {
             cmds: Default::default(),
             waiting: None,
+            waiting_params: None,
             timeout: Duration::from_millis(REQUEST_TIMEOUT),
+            retries: 0,
+            attempts: 0,
         }
     }
 }
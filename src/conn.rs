@@ -3,6 +3,8 @@ use std::marker::PhantomData;
 use std::pin::Pin;
 use std::task::ready;
 
+use async_tungstenite::tungstenite::client::IntoClientRequest;
+use async_tungstenite::tungstenite::http::{HeaderName, HeaderValue};
 use async_tungstenite::tungstenite::Message as WsMessage;
 use async_tungstenite::{tungstenite::protocol::WebSocketConfig, WebSocketStream};
 use futures::stream::Stream;
@@ -35,22 +37,62 @@ pub struct Connection<T: EventMessage> {
     needs_flush: bool,
     /// The message that is currently being proceessed
     pending_flush: Option<MethodCall>,
+    /// Whether all sent and received messages are logged
+    log_protocol: bool,
     _marker: PhantomData<T>,
 }
 
 impl<T: EventMessage + Unpin> Connection<T> {
     pub async fn connect(debug_ws_url: impl AsRef<str>) -> Result<Self> {
+        Self::connect_with_headers(debug_ws_url, std::iter::empty::<(String, String)>()).await
+    }
+
+    /// Connect to the websocket and send the additional `headers` with the
+    /// handshake request, e.g. an `Authorization` header required by a
+    /// reverse proxy in front of the browser.
+    pub async fn connect_with_headers<K, V>(
+        debug_ws_url: impl AsRef<str>,
+        headers: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Self>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        Self::connect_with_max_message_size(debug_ws_url, headers, None).await
+    }
+
+    /// Like [`Connection::connect_with_headers`], but closes the connection
+    /// if a message larger than `max_message_size` bytes is received. The
+    /// size of messages is unlimited if this is `None`.
+    pub async fn connect_with_max_message_size<K, V>(
+        debug_ws_url: impl AsRef<str>,
+        headers: impl IntoIterator<Item = (K, V)>,
+        max_message_size: Option<usize>,
+    ) -> Result<Self>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut request = debug_ws_url.as_ref().into_client_request()?;
+        for (name, value) in headers {
+            let name = HeaderName::from_bytes(name.as_ref().as_bytes())
+                .map_err(|err| async_tungstenite::tungstenite::Error::HttpFormat(err.into()))?;
+            let value = HeaderValue::from_str(value.as_ref())
+                .map_err(|err| async_tungstenite::tungstenite::Error::HttpFormat(err.into()))?;
+            request.headers_mut().insert(name, value);
+        }
+
         let config = WebSocketConfig {
-            max_message_size: None,
-            max_frame_size: None,
+            max_message_size,
+            max_frame_size: max_message_size,
             ..Default::default()
         };
 
         cfg_if::cfg_if! {
             if #[cfg(feature = "async-std-runtime")] {
-               let (ws, _) = async_tungstenite::async_std::connect_async_with_config(debug_ws_url.as_ref(), Some(config)).await?;
+               let (ws, _) = async_tungstenite::async_std::connect_async_with_config(request, Some(config)).await?;
             } else if #[cfg(feature = "tokio-runtime")] {
-                 let (ws, _) = async_tungstenite::tokio::connect_async_with_config(debug_ws_url.as_ref(), Some(config)).await?;
+                 let (ws, _) = async_tungstenite::tokio::connect_async_with_config(request, Some(config)).await?;
             }
         }
 
@@ -60,12 +102,19 @@ impl<T: EventMessage + Unpin> Connection<T> {
             next_id: 0,
             needs_flush: false,
             pending_flush: None,
+            log_protocol: false,
             _marker: Default::default(),
         })
     }
 }
 
 impl<T: EventMessage> Connection<T> {
+    /// Logs every outgoing command and incoming response and event with
+    /// `tracing::debug!` if enabled.
+    pub fn set_log_protocol(&mut self, enabled: bool) {
+        self.log_protocol = enabled;
+    }
+
     fn next_call_id(&mut self) -> CallId {
         let id = CallId::new(self.next_id);
         self.next_id = self.next_id.wrapping_add(1);
@@ -102,6 +151,15 @@ impl<T: EventMessage> Connection<T> {
         if self.pending_flush.is_none() && !self.needs_flush {
             if let Some(cmd) = self.pending_commands.pop_front() {
                 tracing::trace!("Sending {:?}", cmd);
+                if self.log_protocol {
+                    tracing::debug!(
+                        id = ?cmd.id,
+                        method = %cmd.method,
+                        session = ?cmd.session_id,
+                        params = %cmd.params,
+                        "Sending command"
+                    );
+                }
                 let msg = serde_json::to_string(&cmd)?;
                 self.ws.start_send_unpin(msg.into())?;
                 self.pending_flush = Some(cmd);
@@ -143,6 +201,20 @@ impl<T: EventMessage + Unpin> Stream for Connection<T> {
                 let ready = match serde_json::from_str::<Message<T>>(&text) {
                     Ok(msg) => {
                         tracing::trace!("Received {:?}", msg);
+                        if pin.log_protocol {
+                            match &msg {
+                                Message::Response(resp) => tracing::debug!(
+                                    id = ?resp.id,
+                                    error = ?resp.error,
+                                    "Received response"
+                                ),
+                                Message::Event(event) => tracing::debug!(
+                                    method = %event.identifier(),
+                                    session = ?event.session_id(),
+                                    "Received event"
+                                ),
+                            }
+                        }
                         Ok(msg)
                     }
                     Err(err) => {
@@ -3,25 +3,33 @@ use std::path::Path;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
 
-use futures::{future, Future, FutureExt, Stream};
+use futures::channel::oneshot::Canceled;
+use futures::{future, Future, FutureExt, Stream, StreamExt};
 
+use chromiumoxide_cdp::cdp::browser_protocol::accessibility::{AxNode, GetPartialAxTreeParams};
 use chromiumoxide_cdp::cdp::browser_protocol::dom::{
     BackendNodeId, DescribeNodeParams, GetBoxModelParams, GetContentQuadsParams, Node, NodeId,
-    ResolveNodeParams,
+    RequestNodeParams, ResolveNodeParams, SetFileInputFilesParams,
 };
+use chromiumoxide_cdp::cdp::browser_protocol::input::MouseButton;
 use chromiumoxide_cdp::cdp::browser_protocol::page::{
-    CaptureScreenshotFormat, CaptureScreenshotParams, Viewport,
+    CaptureScreenshotFormat, CaptureScreenshotParams, EventFrameNavigated, Viewport,
 };
 use chromiumoxide_cdp::cdp::js_protocol::runtime::{
-    CallFunctionOnReturns, GetPropertiesParams, PropertyDescriptor, RemoteObjectId,
-    RemoteObjectType,
+    CallFunctionOnReturns, GetPropertiesParams, PropertyDescriptor, ReleaseObjectParams,
+    RemoteObjectId, RemoteObjectType,
 };
 
 use crate::error::{CdpError, Result};
 use crate::handler::PageInner;
 use crate::layout::{BoundingBox, BoxModel, ElementQuad, Point};
-use crate::utils;
+use crate::page::{with_timeout, ClickOptions};
+use crate::{utils, ArcHttpRequest};
+
+/// How often `Element::wait_for_enabled` checks the element's state
+const WAIT_FOR_ENABLED_INTERVAL: Duration = Duration::from_millis(100);
 
 /// Represents a [DOM Element](https://developer.mozilla.org/en-US/docs/Web/API/Element).
 #[derive(Debug)]
@@ -82,14 +90,31 @@ impl Element {
         .collect::<Result<Vec<_>, _>>()
     }
 
-    /// Returns the first element in the document which matches the given CSS
-    /// selector.
+    /// Returns the first descendant of this element which matches the given
+    /// CSS selector.
+    ///
+    /// The query is scoped to this element, like
+    /// [`Element.querySelector()`](https://developer.mozilla.org/en-US/docs/Web/API/Element/querySelector).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     for card in page.find_elements(".card").await? {
+    ///         let title = card.find_element("h2").await?.inner_text().await?;
+    ///     }
+    ///     # Ok(())
+    /// # }
+    /// ```
     pub async fn find_element(&self, selector: impl Into<String>) -> Result<Self> {
         let node_id = self.tab.find_element(selector, self.node_id).await?;
         Element::new(Arc::clone(&self.tab), node_id).await
     }
 
-    /// Return all `Element`s in the document that match the given selector
+    /// Return all descendants of this element that match the given CSS
+    /// selector
     pub async fn find_elements(&self, selector: impl Into<String>) -> Result<Vec<Element>> {
         Element::from_nodes(
             &self.tab,
@@ -98,6 +123,82 @@ impl Element {
         .await
     }
 
+    /// Returns the parent element of this element, `None` for the root element
+    pub async fn parent(&self) -> Result<Option<Element>> {
+        self.resolve_js_fn_element("function() { return this.parentElement; }")
+            .await
+    }
+
+    /// Returns the child elements of this element
+    pub async fn children(&self) -> Result<Vec<Element>> {
+        let array = self
+            .call_js_fn("function() { return Array.from(this.children); }", false)
+            .await?
+            .result
+            .object_id
+            .ok_or(CdpError::NotFound)?;
+
+        let mut params = GetPropertiesParams::new(array.clone());
+        params.own_properties = Some(true);
+        let properties = self.tab.execute(params).await?.result.result;
+        self.tab.execute(ReleaseObjectParams::new(array)).await?;
+
+        // only the indices of the array are elements
+        let mut children = properties
+            .into_iter()
+            .filter_map(|p| {
+                let idx = p.name.parse::<usize>().ok()?;
+                Some((idx, p.value?.object_id?))
+            })
+            .collect::<Vec<_>>();
+        children.sort_by_key(|(idx, _)| *idx);
+
+        let mut elements = Vec::with_capacity(children.len());
+        for (_, object_id) in children {
+            elements.push(self.resolve_element(object_id).await?);
+        }
+        Ok(elements)
+    }
+
+    /// Returns the element that immediately follows this element among its
+    /// parent's children
+    pub async fn next_sibling(&self) -> Result<Option<Element>> {
+        self.resolve_js_fn_element("function() { return this.nextElementSibling; }")
+            .await
+    }
+
+    /// Returns the element that immediately precedes this element among its
+    /// parent's children
+    pub async fn previous_sibling(&self) -> Result<Option<Element>> {
+        self.resolve_js_fn_element("function() { return this.previousElementSibling; }")
+            .await
+    }
+
+    /// Calls the function on this element and resolves the element it returns,
+    /// `None` if it returns `null`
+    async fn resolve_js_fn_element(&self, function_declaration: &str) -> Result<Option<Element>> {
+        match self
+            .call_js_fn(function_declaration, false)
+            .await?
+            .result
+            .object_id
+        {
+            Some(object_id) => Ok(Some(self.resolve_element(object_id).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Resolves the element of the remote object
+    async fn resolve_element(&self, object_id: RemoteObjectId) -> Result<Element> {
+        let node_id = self
+            .tab
+            .execute(RequestNodeParams::new(object_id))
+            .await?
+            .result
+            .node_id;
+        Element::new(Arc::clone(&self.tab), node_id).await
+    }
+
     async fn box_model(&self) -> Result<BoxModel> {
         let model = self
             .tab
@@ -213,6 +314,60 @@ impl Element {
         Ok(self)
     }
 
+    /// Whether the element is enabled, i.e. it doesn't match `:disabled` and
+    /// has no `aria-disabled="true"` attribute.
+    pub async fn is_enabled(&self) -> Result<bool> {
+        let resp = self
+            .call_js_fn(
+                "function() {
+                    return !this.matches(':disabled')
+                        && this.getAttribute('aria-disabled') !== 'true';
+                }",
+                false,
+            )
+            .await?;
+        Ok(resp
+            .result
+            .value
+            .and_then(|value| value.as_bool())
+            .unwrap_or_default())
+    }
+
+    /// Waits until the element is enabled, see `Element::is_enabled`.
+    ///
+    /// Fails with `CdpError::Timeout` if the element is still disabled after
+    /// `timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.find_element("button[type=submit]")
+    ///         .await?
+    ///         .wait_for_enabled(Duration::from_secs(5))
+    ///         .await?
+    ///         .click()
+    ///         .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_enabled(&self, timeout: Duration) -> Result<&Self> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if self.is_enabled().await? {
+                return Ok(self);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(CdpError::Timeout);
+            }
+            futures_timer::Delay::new(WAIT_FOR_ENABLED_INTERVAL.min(deadline - now)).await;
+        }
+    }
+
     /// Scrolls the element into view and uses a mouse event to move the mouse
     /// over the center of this element.
     pub async fn hover(&self) -> Result<&Self> {
@@ -271,6 +426,103 @@ impl Element {
         Ok(self)
     }
 
+    /// Scrolls the element into view and clicks it with the given
+    /// `ClickOptions`, e.g. to double click or click with the right button.
+    ///
+    /// # Example double click the element
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::element::Element;
+    /// # use chromiumoxide::page::ClickOptions;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(element: Element) -> Result<()> {
+    ///     element
+    ///         .click_with_options(ClickOptions {
+    ///             click_count: 2,
+    ///             ..Default::default()
+    ///         })
+    ///         .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn click_with_options(&self, options: impl Into<ClickOptions>) -> Result<&Self> {
+        let center = self.scroll_into_view().await?.clickable_point().await?;
+        self.tab.click_with_options(center, &options.into()).await?;
+        Ok(self)
+    }
+
+    /// Scrolls the element into view and right clicks it, which opens its
+    /// context menu.
+    pub async fn right_click(&self) -> Result<&Self> {
+        self.click_with_options(ClickOptions {
+            button: MouseButton::Right,
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Checks or unchecks this `<input type="checkbox">` or
+    /// `<input type="radio">` element.
+    ///
+    /// Unlike `Element::click`, which toggles the element, this only clicks
+    /// the element if its `checked` state differs, so it's safe to call
+    /// repeatedly. The click dispatches the `input` and `change` events. If
+    /// the click doesn't change the state, e.g. because the input is covered
+    /// by a custom styled label, the state is set directly and both events
+    /// are dispatched on the element.
+    ///
+    /// Returns an error for `set_checked(false)` on a radio button, since a
+    /// radio can only be unchecked by checking another one of its group.
+    pub async fn set_checked(&self, checked: bool) -> Result<&Self> {
+        if self.checked_state(checked).await? == checked {
+            return Ok(self);
+        }
+        if self.click().await.is_ok() && self.checked_state(checked).await? == checked {
+            return Ok(self);
+        }
+        self.call_js_fn(
+            format!(
+                "function() {{
+                    this.checked = {checked};
+                    this.dispatchEvent(new Event('input', {{ bubbles: true }}));
+                    this.dispatchEvent(new Event('change', {{ bubbles: true }}));
+                }}"
+            ),
+            false,
+        )
+        .await?;
+        Ok(self)
+    }
+
+    /// Whether this checkbox or radio button is checked.
+    ///
+    /// Returns an error if this is neither, or if this is a radio button and
+    /// it should be unchecked.
+    async fn checked_state(&self, checked: bool) -> Result<bool> {
+        let resp = self
+            .call_js_fn(
+                format!(
+                    "function() {{
+                    if (!(this instanceof HTMLInputElement)
+                        || (this.type !== 'checkbox' && this.type !== 'radio')) {{
+                        return 'Element is not a checkbox or radio button';
+                    }}
+                    if (this.type === 'radio' && !{checked}) {{
+                        return 'A radio button can not be unchecked directly';
+                    }}
+                    return this.checked;
+                }}"
+                ),
+                false,
+            )
+            .await?;
+        match resp.result.value {
+            Some(serde_json::Value::Bool(checked)) => Ok(checked),
+            Some(serde_json::Value::String(error_text)) => Err(CdpError::msg(error_text)),
+            _ => Err(CdpError::msg("Failed to read the checked state")),
+        }
+    }
+
     /// Type the input
     ///
     /// # Example type text into an input element
@@ -289,6 +541,106 @@ impl Element {
         Ok(self)
     }
 
+    /// Focuses the element and inserts the whole `text` with a single
+    /// `Input.insertText` command.
+    ///
+    /// This is much faster than [`Element::type_str`] for long input, but
+    /// doesn't dispatch any key events, similar to pasting the text.
+    ///
+    /// # Example fill a textarea
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let element = page.find_element("textarea").await?;
+    ///     element.insert_text("a very long text").await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn insert_text(&self, text: impl Into<String>) -> Result<&Self> {
+        self.focus().await?;
+        self.tab.insert_text(text).await?;
+        Ok(self)
+    }
+
+    /// Replaces the value of an `<input>`, `<textarea>` or contenteditable
+    /// element with `value`.
+    ///
+    /// This focuses the element, selects its current text and replaces the
+    /// selection with `value` via `Input.insertText`, or deletes it if
+    /// `value` is empty, which dispatches an `input` event. Afterwards a
+    /// `change` event is dispatched. In contrast to [`Element::type_str`],
+    /// this doesn't append to pre-filled fields.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.find_element("input[name=email]")
+    ///         .await?
+    ///         .fill("user@example.com")
+    ///         .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn fill(&self, value: impl AsRef<str>) -> Result<&Self> {
+        let value = value.as_ref();
+        self.focus().await?;
+        self.select_text().await?;
+        if value.is_empty() {
+            self.tab.press_key("Delete").await?;
+        } else {
+            self.tab.insert_text(value).await?;
+        }
+        self.call_js_fn(
+            "function() { this.dispatchEvent(new Event('change', { bubbles: true })); }",
+            false,
+        )
+        .await?;
+        Ok(self)
+    }
+
+    /// Empties an `<input>`, `<textarea>` or contenteditable element.
+    ///
+    /// This focuses the element, selects its text and deletes it, which
+    /// dispatches an `input` event, followed by a `change` event. Same as
+    /// `Element::fill("")`.
+    pub async fn clear(&self) -> Result<&Self> {
+        self.fill("").await
+    }
+
+    /// Selects the whole text of an `<input>`, `<textarea>` or
+    /// contenteditable element.
+    async fn select_text(&self) -> Result<&Self> {
+        let resp = self
+            .call_js_fn(
+                "function() {
+                if (this instanceof HTMLInputElement || this instanceof HTMLTextAreaElement) {
+                    this.select();
+                } else if (this.isContentEditable) {
+                    const range = document.createRange();
+                    range.selectNodeContents(this);
+                    const selection = window.getSelection();
+                    selection.removeAllRanges();
+                    selection.addRange(range);
+                } else {
+                    return 'Element is not an <input>, <textarea> or contenteditable element';
+                }
+                return false;
+            }",
+                false,
+            )
+            .await?;
+
+        if let Some(error_text) = resp.result.value.as_ref().and_then(|v| v.as_str()) {
+            return Err(CdpError::msg(error_text));
+        }
+        Ok(self)
+    }
+
     /// Presses the key.
     ///
     /// # Example type text into an input element and hit enter
@@ -308,6 +660,106 @@ impl Element {
         Ok(self)
     }
 
+    /// Selects the `files` of this `<input type="file">` element.
+    ///
+    /// The paths should be absolute.
+    pub async fn set_input_files<I, P>(&self, files: I) -> Result<&Self>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let files = files
+            .into_iter()
+            .map(|file| file.as_ref().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        let params = SetFileInputFilesParams::builder()
+            .files(files)
+            .backend_node_id(self.backend_node_id)
+            .build()
+            .map_err(CdpError::msg)?;
+        self.tab.execute(params).await?;
+        Ok(self)
+    }
+
+    /// Submits the form this element belongs to, i.e. its form owner or the
+    /// nearest ancestor `<form>`.
+    ///
+    /// The form is submitted with
+    /// [`requestSubmit()`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLFormElement/requestSubmit),
+    /// so its constraint validation and `submit` event handlers run as if the
+    /// user submitted it. Fails if the element is not inside a form.
+    pub async fn submit_form(&self) -> Result<&Self> {
+        let resp = self
+            .call_js_fn(
+                "function() {
+                    const form = this instanceof HTMLFormElement
+                        ? this
+                        : (this.form || this.closest('form'));
+                    if (!form) {
+                        return 'Element is not inside a <form>';
+                    }
+                    form.requestSubmit();
+                    return false;
+                }",
+                false,
+            )
+            .await?;
+
+        if let Some(error_text) = resp.result.value.as_ref().and_then(|v| v.as_str()) {
+            return Err(CdpError::msg(error_text));
+        }
+        Ok(self)
+    }
+
+    /// Same as `Element::submit_form` but also waits for the navigation the
+    /// submission triggers.
+    ///
+    /// `requestSubmit()` only schedules the navigation, so this waits for the
+    /// main frame to commit a new document before waiting for it to load.
+    /// Fails with [`CdpError::Timeout`] if the submission does not navigate
+    /// the main frame within the page's navigation timeout.
+    pub async fn submit_form_and_wait(&self) -> Result<ArcHttpRequest> {
+        // subscribe before submitting so the navigation can't be missed
+        let mut navigated = self.tab.event_listener::<EventFrameNavigated>().await?;
+        self.submit_form().await?;
+        let navigation = async {
+            loop {
+                match navigated.next().await {
+                    Some(event) if event.frame.parent_id.is_none() => break,
+                    Some(_) => {}
+                    None => return Err(Canceled.into()),
+                }
+            }
+            self.tab.wait_for_navigation().await
+        };
+        with_timeout(navigation, self.tab.navigation_timeout()).await
+    }
+
+    /// Selects the `files` of this `<input type="file">` element, submits its
+    /// form and waits for the resulting navigation.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.find_element("input[type=file]")
+    ///         .await?
+    ///         .upload_and_submit(["/tmp/report.pdf"])
+    ///         .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn upload_and_submit<I, P>(&self, files: I) -> Result<ArcHttpRequest>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        self.set_input_files(files).await?;
+        self.submit_form_and_wait().await
+    }
+
     /// The description of the element's node
     pub async fn description(&self) -> Result<Node> {
         Ok(self
@@ -323,6 +775,29 @@ impl Element {
             .node)
     }
 
+    /// Returns the node of this element in the accessibility tree, with its
+    /// computed role, name and properties like `focusable` or `checked`.
+    ///
+    /// This enables the `Accessibility` domain of the page on first use.
+    pub async fn accessibility_node(&self) -> Result<AxNode> {
+        self.tab.enable_accessibility().await?;
+        let nodes = self
+            .tab
+            .execute(
+                GetPartialAxTreeParams::builder()
+                    .backend_node_id(self.backend_node_id)
+                    .fetch_relatives(false)
+                    .build(),
+            )
+            .await?
+            .result
+            .nodes;
+        nodes
+            .into_iter()
+            .find(|node| node.backend_dom_node_id == Some(self.backend_node_id))
+            .ok_or(CdpError::NotFound)
+    }
+
     /// Attributes of the `Element` node in the form of flat array `[name1,
     /// value1, name2, value2]
     pub async fn attributes(&self) -> Result<Vec<String>> {
@@ -1,7 +1,7 @@
 use std::fmt;
 use std::io;
 use std::process::ExitStatus;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use async_tungstenite::tungstenite;
 use async_tungstenite::tungstenite::Message;
@@ -11,6 +11,7 @@ use futures::channel::oneshot::Canceled;
 use thiserror::Error;
 
 use chromiumoxide_cdp::cdp::browser_protocol::page::FrameId;
+use chromiumoxide_types::{CallId, MethodId};
 
 use crate::handler::frame::NavigationError;
 use chromiumoxide_cdp::cdp::js_protocol::runtime::ExceptionDetails;
@@ -43,6 +44,19 @@ pub enum CdpError {
     LaunchIo(#[source] io::Error, BrowserStderr),
     #[error("Request timed out.")]
     Timeout,
+    /// A command sent to the browser received no response in time
+    #[error("Command {method} ({call_id:?}) timed out after {elapsed:?}.")]
+    CommandTimeout {
+        method: MethodId,
+        call_id: CallId,
+        elapsed: Duration,
+    },
+    /// A command that initializes a new target failed
+    #[error("Failed to initialize target, {method} failed with {error}.")]
+    TargetInitialization {
+        method: MethodId,
+        error: chromiumoxide_types::Error,
+    },
     #[error("FrameId {0:?} not found.")]
     FrameNotFound(FrameId),
     /// Error message related to a cdp response that is not a
@@ -61,11 +75,61 @@ pub enum CdpError {
     JavascriptException(Box<ExceptionDetails>),
     #[error("{0}")]
     Url(#[from] url::ParseError),
+    #[error("Navigation failed with HTTP status {0}.")]
+    HttpStatus(i64),
+    #[error("Invalid timezone id {0:?}.")]
+    InvalidTimezone(String),
+    #[error("The browser has version {actual} but chromium {expected} or newer is required.")]
+    ProtocolVersionMismatch { expected: String, actual: String },
+    /// A navigation failed with a chromium network error
+    #[error("Navigation failed: {0}")]
+    Navigation(NetError),
 }
 impl CdpError {
     pub fn msg(msg: impl Into<String>) -> Self {
         CdpError::ChromeMessage(msg.into())
     }
+
+    /// Whether a request or the browser launch timed out
+    pub fn is_timeout(&self) -> bool {
+        matches!(
+            self,
+            CdpError::Timeout | CdpError::CommandTimeout { .. } | CdpError::LaunchTimeout(_)
+        )
+    }
+
+    /// Whether the connection to the browser was closed, either the websocket
+    /// itself or the channel to the handler that drives it.
+    pub fn is_connection_closed(&self) -> bool {
+        match self {
+            CdpError::Ws(err) => matches!(
+                err,
+                tungstenite::Error::ConnectionClosed
+                    | tungstenite::Error::AlreadyClosed
+                    | tungstenite::Error::Protocol(
+                        tungstenite::error::ProtocolError::ResetWithoutClosingHandshake
+                    )
+            ),
+            CdpError::ChannelSendError(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the browser responded to a request with an error
+    pub fn is_chrome_error(&self) -> bool {
+        matches!(
+            self,
+            CdpError::Chrome(_) | CdpError::TargetInitialization { .. }
+        )
+    }
+
+    /// The error the browser responded with, including its code
+    pub fn as_chrome_error(&self) -> Option<&chromiumoxide_types::Error> {
+        match self {
+            CdpError::Chrome(err) | CdpError::TargetInitialization { error: err, .. } => Some(err),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Error)]
@@ -97,6 +161,95 @@ impl From<NavigationError> for CdpError {
     }
 }
 
+/// A chromium network error (`net::ERR_*`) a navigation failed with.
+///
+/// The `Display` implementation returns the error text reported by chromium,
+/// like `net::ERR_NAME_NOT_RESOLVED`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetError {
+    /// `net::ERR_NAME_NOT_RESOLVED`: the host name could not be resolved.
+    NameNotResolved,
+    /// `net::ERR_CONNECTION_REFUSED`
+    ConnectionRefused,
+    /// `net::ERR_CONNECTION_RESET`
+    ConnectionReset,
+    /// `net::ERR_CONNECTION_CLOSED`
+    ConnectionClosed,
+    /// `net::ERR_CONNECTION_TIMED_OUT`
+    ConnectionTimedOut,
+    /// `net::ERR_TIMED_OUT`
+    TimedOut,
+    /// `net::ERR_ABORTED`: the navigation was aborted, e.g. by another
+    /// navigation or because the response was a download.
+    Aborted,
+    /// `net::ERR_INTERNET_DISCONNECTED`
+    InternetDisconnected,
+    /// `net::ERR_ADDRESS_UNREACHABLE`
+    AddressUnreachable,
+    /// `net::ERR_EMPTY_RESPONSE`
+    EmptyResponse,
+    /// `net::ERR_TOO_MANY_REDIRECTS`
+    TooManyRedirects,
+    /// `net::ERR_BLOCKED_BY_CLIENT`, e.g. a request that was blocked by
+    /// request interception.
+    BlockedByClient,
+    /// `net::ERR_CERT_*`: the server's certificate is invalid, with the full
+    /// error text.
+    Certificate(String),
+    /// Any other network error, with the full error text.
+    Other(String),
+}
+
+impl NetError {
+    /// Parses the error text of a failed navigation, returns `None` if it is
+    /// not a network error.
+    pub fn parse(error_text: &str) -> Option<Self> {
+        let code = error_text.strip_prefix("net::ERR_")?;
+        let err = match code {
+            "NAME_NOT_RESOLVED" => NetError::NameNotResolved,
+            "CONNECTION_REFUSED" => NetError::ConnectionRefused,
+            "CONNECTION_RESET" => NetError::ConnectionReset,
+            "CONNECTION_CLOSED" => NetError::ConnectionClosed,
+            "CONNECTION_TIMED_OUT" => NetError::ConnectionTimedOut,
+            "TIMED_OUT" => NetError::TimedOut,
+            "ABORTED" => NetError::Aborted,
+            "INTERNET_DISCONNECTED" => NetError::InternetDisconnected,
+            "ADDRESS_UNREACHABLE" => NetError::AddressUnreachable,
+            "EMPTY_RESPONSE" => NetError::EmptyResponse,
+            "TOO_MANY_REDIRECTS" => NetError::TooManyRedirects,
+            "BLOCKED_BY_CLIENT" => NetError::BlockedByClient,
+            _ if code.starts_with("CERT_") => NetError::Certificate(error_text.to_string()),
+            _ => NetError::Other(error_text.to_string()),
+        };
+        Some(err)
+    }
+
+    /// The error text reported by chromium, like `net::ERR_ABORTED`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            NetError::NameNotResolved => "net::ERR_NAME_NOT_RESOLVED",
+            NetError::ConnectionRefused => "net::ERR_CONNECTION_REFUSED",
+            NetError::ConnectionReset => "net::ERR_CONNECTION_RESET",
+            NetError::ConnectionClosed => "net::ERR_CONNECTION_CLOSED",
+            NetError::ConnectionTimedOut => "net::ERR_CONNECTION_TIMED_OUT",
+            NetError::TimedOut => "net::ERR_TIMED_OUT",
+            NetError::Aborted => "net::ERR_ABORTED",
+            NetError::InternetDisconnected => "net::ERR_INTERNET_DISCONNECTED",
+            NetError::AddressUnreachable => "net::ERR_ADDRESS_UNREACHABLE",
+            NetError::EmptyResponse => "net::ERR_EMPTY_RESPONSE",
+            NetError::TooManyRedirects => "net::ERR_TOO_MANY_REDIRECTS",
+            NetError::BlockedByClient => "net::ERR_BLOCKED_BY_CLIENT",
+            NetError::Certificate(text) | NetError::Other(text) => text,
+        }
+    }
+}
+
+impl fmt::Display for NetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// An Error where `now > deadline`
 #[derive(Debug, Clone)]
 pub struct DeadlineExceeded {
@@ -150,3 +303,62 @@ impl fmt::Debug for BrowserStderr {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn net_error_round_trip() {
+        let errors = [
+            NetError::NameNotResolved,
+            NetError::ConnectionRefused,
+            NetError::ConnectionReset,
+            NetError::ConnectionClosed,
+            NetError::ConnectionTimedOut,
+            NetError::TimedOut,
+            NetError::Aborted,
+            NetError::InternetDisconnected,
+            NetError::AddressUnreachable,
+            NetError::EmptyResponse,
+            NetError::TooManyRedirects,
+            NetError::BlockedByClient,
+            NetError::Certificate("net::ERR_CERT_DATE_INVALID".to_string()),
+            NetError::Other("net::ERR_SSL_PROTOCOL_ERROR".to_string()),
+        ];
+        for err in errors {
+            assert_eq!(NetError::parse(err.as_str()), Some(err.clone()));
+            assert_eq!(err.to_string(), err.as_str());
+        }
+    }
+
+    #[test]
+    fn parse_certificate_errors() {
+        for text in [
+            "net::ERR_CERT_AUTHORITY_INVALID",
+            "net::ERR_CERT_COMMON_NAME_INVALID",
+            "net::ERR_CERT_DATE_INVALID",
+        ] {
+            assert_eq!(
+                NetError::parse(text),
+                Some(NetError::Certificate(text.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_unknown_net_errors() {
+        assert_eq!(
+            NetError::parse("net::ERR_HTTP2_PROTOCOL_ERROR"),
+            Some(NetError::Other("net::ERR_HTTP2_PROTOCOL_ERROR".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_non_net_errors() {
+        assert_eq!(NetError::parse(""), None);
+        assert_eq!(NetError::parse("Cannot navigate to invalid URL"), None);
+        assert_eq!(NetError::parse("ERR_ABORTED"), None);
+        assert_eq!(NetError::parse("net::ABORTED"), None);
+    }
+}
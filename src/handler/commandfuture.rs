@@ -7,6 +7,7 @@ use std::future::Future;
 use std::marker::PhantomData;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use crate::cmd::{to_command_response, CommandMessage};
 use crate::error::Result;
@@ -20,11 +21,9 @@ pin_project! {
         rx_command: oneshot::Receiver<M>,
         #[pin]
         target_sender: mpsc::Sender<TargetMessage>,
-        // We need delay to be pinned because it's a future
-        // and we need to be able to poll it
-        // it is used to timeout the command if page was closed while waiting for response
-        #[pin]
-        delay: futures_timer::Delay,
+        // a timeout other than the request timeout, commands without one
+        // are timed out by the handler with `CdpError::CommandTimeout`
+        delay: Option<futures_timer::Delay>,
 
         message: Option<TargetMessage>,
 
@@ -35,6 +34,8 @@ pin_project! {
 }
 
 impl<T: Command> CommandFuture<T> {
+    /// Creates a future for the command that fails with
+    /// `CdpError::CommandTimeout` once the handler's request timeout elapsed
     pub fn new(
         cmd: T,
         target_sender: mpsc::Sender<TargetMessage>,
@@ -47,19 +48,28 @@ impl<T: Command> CommandFuture<T> {
             cmd, tx, session,
         )?));
 
-        let delay = futures_timer::Delay::new(std::time::Duration::from_millis(
-            crate::handler::REQUEST_TIMEOUT,
-        ));
-
         Ok(Self {
             target_sender,
             rx_command,
             message,
-            delay,
+            delay: None,
             method,
             _marker: PhantomData,
         })
     }
+
+    /// Same as `CommandFuture::new` but fails with `CdpError::Timeout` after
+    /// `timeout` if it is shorter than the handler's request timeout
+    pub fn with_timeout(
+        cmd: T,
+        target_sender: mpsc::Sender<TargetMessage>,
+        session: Option<SessionId>,
+        timeout: Duration,
+    ) -> Result<Self> {
+        let mut fut = Self::new(cmd, target_sender, session)?;
+        fut.delay = Some(futures_timer::Delay::new(timeout));
+        Ok(fut)
+    }
 }
 
 impl<T> Future for CommandFuture<T>
@@ -83,7 +93,11 @@ where
                 }
                 Poll::Pending => Poll::Pending,
             }
-        } else if this.delay.poll(cx).is_ready() {
+        } else if this
+            .delay
+            .as_mut()
+            .is_some_and(|delay| Pin::new(delay).poll(cx).is_ready())
+        {
             Poll::Ready(Err(crate::error::CdpError::Timeout))
         } else {
             match this.rx_command.as_mut().poll(cx) {
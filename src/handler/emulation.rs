@@ -1,6 +1,5 @@
 use chromiumoxide_cdp::cdp::browser_protocol::emulation::{
-    ScreenOrientation, ScreenOrientationType, SetDeviceMetricsOverrideParams,
-    SetTouchEmulationEnabledParams,
+    SetDeviceMetricsOverrideParams, SetTouchEmulationEnabledParams,
 };
 use chromiumoxide_types::Method;
 
@@ -27,18 +26,12 @@ impl EmulationManager {
     }
 
     pub fn init_commands(&mut self, viewport: &Viewport) -> CommandChain {
-        let orientation = if viewport.is_landscape {
-            ScreenOrientation::new(ScreenOrientationType::LandscapePrimary, 90)
-        } else {
-            ScreenOrientation::new(ScreenOrientationType::PortraitPrimary, 0)
-        };
-
         let set_device = SetDeviceMetricsOverrideParams::builder()
             .mobile(viewport.emulating_mobile)
             .width(viewport.width)
             .height(viewport.height)
             .device_scale_factor(viewport.device_scale_factor.unwrap_or(1.))
-            .screen_orientation(orientation)
+            .screen_orientation(viewport.screen_orientation())
             .build()
             .unwrap();
 
@@ -140,6 +140,12 @@ impl Frame {
         self.lifecycle_events.contains("load")
     }
 
+    /// Whether the frame already received the given lifecycle event for its
+    /// current document
+    pub fn has_lifecycle_event(&self, event: LifecycleEvent) -> bool {
+        self.lifecycle_events.contains(event.as_ref())
+    }
+
     pub fn clear_contexts(&mut self) {
         self.main_world.take_context();
         self.secondary_world.take_context();
@@ -179,6 +185,19 @@ impl From<CdpFrame> for Frame {
     }
 }
 
+/// A frame and all its child frames, see `Page::frame_tree`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameNode {
+    /// The identifier of the frame
+    pub id: FrameId,
+    /// The url of the document loaded in the frame, if any
+    pub url: Option<String>,
+    /// The name of the frame, e.g. the `name` attribute of an `<iframe>`
+    pub name: Option<String>,
+    /// The frames embedded in this frame
+    pub children: Vec<FrameNode>,
+}
+
 /// Maintains the state of the pages frame and listens to events produced by
 /// chromium targeting the `Target`. Also listens for events that indicate that
 /// a navigation was completed
@@ -189,9 +208,15 @@ pub struct FrameManager {
     /// The contexts mapped with their frames
     context_ids: HashMap<ExecutionContextId, FrameId>,
     isolated_worlds: HashSet<String>,
+    /// The name of the isolated world that backs the secondary `DOMWorld` of
+    /// every frame
+    utility_world_name: String,
     /// Timeout after which an anticipated event (related to navigation) doesn't
     /// arrive results in an error
     request_timeout: Duration,
+    /// Timeout after which a navigation that didn't complete results in an
+    /// error
+    navigation_timeout: Duration,
     /// Track currently in progress navigation
     pending_navigations: VecDeque<(FrameNavigationRequest, NavigationWatcher)>,
     /// The currently ongoing navigation
@@ -199,18 +224,30 @@ pub struct FrameManager {
 }
 
 impl FrameManager {
-    pub fn new(request_timeout: Duration) -> Self {
+    pub fn new(request_timeout: Duration, navigation_timeout: Duration) -> Self {
         FrameManager {
             main_frame: None,
             frames: Default::default(),
             context_ids: Default::default(),
             isolated_worlds: Default::default(),
+            utility_world_name: UTILITY_WORLD_NAME.to_string(),
             request_timeout,
+            navigation_timeout,
             pending_navigations: Default::default(),
             navigation: None,
         }
     }
 
+    /// The name of the isolated world that backs the secondary `DOMWorld` of
+    /// every frame, `UTILITY_WORLD_NAME` by default
+    pub fn utility_world_name(&self) -> &str {
+        &self.utility_world_name
+    }
+
+    pub fn set_utility_world_name(&mut self, name: impl Into<String>) {
+        self.utility_world_name = name.into();
+    }
+
     /// The commands to execute in order to initialize this frame manager
     pub fn init_commands(timeout: Duration) -> CommandChain {
         let enable = page::EnableParams::default();
@@ -257,6 +294,25 @@ impl FrameManager {
         self.frames.get(id)
     }
 
+    /// The hierarchy of all frames, starting at the main frame
+    pub fn frame_tree(&self) -> Option<FrameNode> {
+        self.main_frame().map(|frame| self.frame_node(frame))
+    }
+
+    fn frame_node(&self, frame: &Frame) -> FrameNode {
+        FrameNode {
+            id: frame.id.clone(),
+            url: frame.url.clone(),
+            name: frame.name.clone(),
+            children: frame
+                .child_frames
+                .iter()
+                .filter_map(|id| self.frames.get(id))
+                .map(|child| self.frame_node(child))
+                .collect(),
+        }
+    }
+
     fn check_lifecycle(&self, watcher: &NavigationWatcher, frame: &Frame) -> bool {
         watcher.expected_lifecycle.iter().all(|ev| {
             frame.lifecycle_events.contains(ev)
@@ -288,8 +344,11 @@ impl FrameManager {
         None
     }
 
-    /// Track the request in the frame
+    /// Track the navigation request in the frame
     pub fn on_http_request_finished(&mut self, request: HttpRequest) {
+        if !request.is_navigation_request {
+            return;
+        }
         if let Some(id) = request.frame.as_ref() {
             if let Some(frame) = self.frames.get_mut(id) {
                 frame.set_request(request);
@@ -342,12 +401,18 @@ impl FrameManager {
         }
     }
 
+    /// Set the timeout of all following navigations
+    pub fn set_navigation_timeout(&mut self, timeout: Duration) {
+        self.navigation_timeout = timeout;
+    }
+
     /// Navigate a specific frame
     pub fn navigate_frame(&mut self, frame_id: FrameId, mut req: FrameNavigationRequest) {
         let loader_id = self.frames.get(&frame_id).and_then(|f| f.loader_id.clone());
         let watcher = NavigationWatcher::until_page_load(req.id, frame_id.clone(), loader_id);
         // insert the frame_id in the request if not present
         req.set_frame_id(frame_id);
+        req.timeout = self.navigation_timeout;
         self.pending_navigations.push_back((req, watcher))
     }
 
@@ -405,7 +470,15 @@ impl FrameManager {
                 }
                 // this is necessary since we can't borrow mut and then remove recursively
                 main_frame.child_frames.clear();
-                main_frame.id = frame.id.clone();
+                if main_frame.id != frame.id {
+                    // keep the contexts of the main frame tracked under its new id
+                    for id in self.context_ids.values_mut() {
+                        if *id == main_frame.id {
+                            *id = frame.id.clone();
+                        }
+                    }
+                    main_frame.id = frame.id.clone();
+                }
                 main_frame
             } else {
                 // initial main frame navigation
@@ -426,6 +499,7 @@ impl FrameManager {
         }
     }
 
+    /// Fired when frame has stopped loading, marks the frame as loaded.
     pub fn on_frame_stopped_loading(&mut self, event: &EventFrameStoppedLoading) {
         if let Some(frame) = self.frames.get_mut(&event.frame_id) {
             frame.on_loading_stopped();
@@ -459,7 +533,7 @@ impl FrameManager {
                     .unwrap_or_default()
                 {
                     frame.main_world.set_context(event.context.id);
-                } else if event.context.name == UTILITY_WORLD_NAME
+                } else if event.context.name == self.utility_world_name
                     && frame.secondary_world.execution_context().is_none()
                 {
                     frame.secondary_world.set_context(event.context.id);
@@ -514,6 +588,7 @@ impl FrameManager {
             for child in &frame.child_frames {
                 self.remove_frames_recursively(child);
             }
+            self.context_ids.retain(|_, frame_id| frame_id != id);
             if let Some(parent_id) = frame.parent_frame.take() {
                 if let Some(parent) = self.frames.get_mut(&parent_id) {
                     parent.child_frames.remove(&frame.id);
@@ -525,6 +600,13 @@ impl FrameManager {
         }
     }
 
+    /// Creates the isolated world named after `FrameManager::utility_world_name`
+    /// in every frame, if not already done.
+    pub fn ensure_utility_world(&mut self) -> Option<CommandChain> {
+        let world_name = self.utility_world_name.clone();
+        self.ensure_isolated_world(&world_name)
+    }
+
     pub fn ensure_isolated_world(&mut self, world_name: &str) -> Option<CommandChain> {
         if self.isolated_worlds.contains(world_name) {
             return None;
@@ -670,13 +752,30 @@ impl FrameNavigationRequest {
     }
 }
 
+/// The lifecycle events of a frame's document that can be waited for
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum LifecycleEvent {
+    /// The `load` event was fired
     #[default]
     Load,
+    /// The `DOMContentLoaded` event was fired
     DomcontentLoaded,
+    /// There were no network connections for at least 500ms
     NetworkIdle,
+    /// There were no more than 2 network connections for at least 500ms
     NetworkAlmostIdle,
+    /// The first pixels were painted
+    FirstPaint,
+    /// The first text or image content was painted
+    FirstContentfulPaint,
+    /// The first image was painted
+    FirstImagePaint,
+    /// The paint that most likely contains the primary content of the page
+    /// was identified
+    FirstMeaningfulPaint,
+    /// The main thread was quiet long enough for the page to be considered
+    /// interactive
+    InteractiveTime,
 }
 
 impl AsRef<str> for LifecycleEvent {
@@ -686,6 +785,147 @@ impl AsRef<str> for LifecycleEvent {
             LifecycleEvent::DomcontentLoaded => "DOMContentLoaded",
             LifecycleEvent::NetworkIdle => "networkIdle",
             LifecycleEvent::NetworkAlmostIdle => "networkAlmostIdle",
+            LifecycleEvent::FirstPaint => "firstPaint",
+            LifecycleEvent::FirstContentfulPaint => "firstContentfulPaint",
+            LifecycleEvent::FirstImagePaint => "firstImagePaint",
+            LifecycleEvent::FirstMeaningfulPaint => "firstMeaningfulPaint",
+            LifecycleEvent::InteractiveTime => "InteractiveTime",
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn cdp_frame(id: &str, parent_id: Option<&str>) -> CdpFrame {
+        serde_json::from_value(json!({
+            "id": id,
+            "parentId": parent_id,
+            "loaderId": format!("loader-{id}"),
+            "url": "about:blank",
+            "domainAndRegistry": "",
+            "securityOrigin": "://",
+            "mimeType": "text/html",
+            "secureContextType": "Secure",
+            "crossOriginIsolatedContextType": "NotIsolated",
+            "gatedAPIFeatures": []
+        }))
+        .unwrap()
+    }
+
+    fn context_created(
+        id: i64,
+        frame_id: &str,
+        name: &str,
+        is_default: bool,
+    ) -> EventExecutionContextCreated {
+        serde_json::from_value(json!({
+            "context": {
+                "id": id,
+                "origin": "",
+                "name": name,
+                "uniqueId": format!("unique-{id}"),
+                "auxData": {
+                    "frameId": frame_id,
+                    "isDefault": is_default,
+                    "type": if is_default { "default" } else { "isolated" }
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    fn frame_manager() -> FrameManager {
+        let mut manager = FrameManager::new(Duration::from_secs(30), Duration::from_secs(30));
+        manager.on_frame_navigated(&cdp_frame("main", None));
+        manager.on_frame_attached("child".to_string().into(), Some("main".to_string().into()));
+        manager.on_frame_navigated(&cdp_frame("child", Some("main")));
+        manager.on_frame_execution_context_created(&context_created(1, "main", "", true));
+        manager.on_frame_execution_context_created(&context_created(
+            2,
+            "main",
+            UTILITY_WORLD_NAME,
+            false,
+        ));
+        manager.on_frame_execution_context_created(&context_created(3, "child", "", true));
+        manager
+    }
+
+    #[test]
+    fn swapped_main_frame_keeps_its_contexts() {
+        let mut manager = frame_manager();
+        manager.on_frame_navigated(&cdp_frame("swapped", None));
+
+        let main = manager.main_frame().unwrap();
+        assert_eq!(main.id().as_ref(), "swapped");
+        assert!(manager.frame(&"main".to_string().into()).is_none());
+        assert_eq!(
+            main.main_world().execution_context(),
+            Some(ExecutionContextId::new(1))
+        );
+        assert_eq!(
+            main.secondary_world().execution_context(),
+            Some(ExecutionContextId::new(2))
+        );
+
+        let swapped: FrameId = "swapped".to_string().into();
+        assert_eq!(
+            manager.context_ids.get(&ExecutionContextId::new(1)),
+            Some(&swapped)
+        );
+        assert_eq!(
+            manager.context_ids.get(&ExecutionContextId::new(2)),
+            Some(&swapped)
+        );
+        // the navigation of the main frame detached its child frame
+        assert!(!manager
+            .context_ids
+            .contains_key(&ExecutionContextId::new(3)));
+
+        let destroyed: EventExecutionContextDestroyed =
+            serde_json::from_value(json!({ "executionContextId": 1 })).unwrap();
+        manager.on_frame_execution_context_destroyed(&destroyed);
+        let main = manager.main_frame().unwrap();
+        assert_eq!(main.main_world().execution_context(), None);
+        assert_eq!(
+            main.secondary_world().execution_context(),
+            Some(ExecutionContextId::new(2))
+        );
+    }
+
+    #[test]
+    fn detached_frame_drops_its_contexts() {
+        let mut manager = frame_manager();
+        let detached: EventFrameDetached =
+            serde_json::from_value(json!({ "frameId": "child", "reason": "remove" })).unwrap();
+        manager.on_frame_detached(&detached);
+
+        assert!(manager.frame(&"child".to_string().into()).is_none());
+        assert!(manager.main_frame().unwrap().child_frames.is_empty());
+        assert!(!manager
+            .context_ids
+            .contains_key(&ExecutionContextId::new(3)));
+
+        let main: FrameId = "main".to_string().into();
+        assert_eq!(
+            manager.context_ids.get(&ExecutionContextId::new(1)),
+            Some(&main)
+        );
+        assert_eq!(
+            manager.context_ids.get(&ExecutionContextId::new(2)),
+            Some(&main)
+        );
+        let main = manager.main_frame().unwrap();
+        assert_eq!(
+            main.main_world().execution_context(),
+            Some(ExecutionContextId::new(1))
+        );
+        assert_eq!(
+            main.secondary_world().execution_context(),
+            Some(ExecutionContextId::new(2))
+        );
+    }
+}
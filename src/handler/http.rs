@@ -1,4 +1,6 @@
-use chromiumoxide_cdp::cdp::browser_protocol::network::{InterceptionId, RequestId, Response};
+use chromiumoxide_cdp::cdp::browser_protocol::network::{
+    InterceptionId, Request, RequestId, ResourceType, Response,
+};
 use chromiumoxide_cdp::cdp::browser_protocol::page::FrameId;
 use std::collections::HashMap;
 
@@ -17,7 +19,13 @@ pub struct HttpRequest {
     pub method: Option<String>,
     pub url: Option<String>,
     pub resource_type: Option<String>,
+    /// The body of the request, `None` if the request has no body or if the
+    /// body was too long to be included in the `Network.requestWillBeSent`
+    /// event, see `has_post_data`.
     pub post_data: Option<String>,
+    /// Whether the request has a body, even if `post_data` was omitted. Use
+    /// `Page::request_post_data` to retrieve an omitted body.
+    pub has_post_data: bool,
     pub redirect_chain: Vec<HttpRequest>,
 }
 
@@ -44,6 +52,7 @@ impl HttpRequest {
             url: None,
             resource_type: None,
             post_data: None,
+            has_post_data: false,
             redirect_chain,
         }
     }
@@ -52,6 +61,22 @@ impl HttpRequest {
         &self.request_id
     }
 
+    /// Whether the body of the request was omitted and needs to be fetched
+    /// with `Page::request_post_data`.
+    pub fn is_post_data_truncated(&self) -> bool {
+        self.has_post_data && self.post_data.is_none()
+    }
+
+    /// Copies the url, method, resource type and body of the `request` as it
+    /// was reported by `Network.requestWillBeSent`
+    pub(crate) fn set_request(&mut self, request: &Request, resource_type: Option<&ResourceType>) {
+        self.url = Some(request.url.clone());
+        self.method = Some(request.method.clone());
+        self.resource_type = resource_type.map(|ty| ty.as_ref().to_string());
+        self.post_data.clone_from(&request.post_data);
+        self.has_post_data = request.has_post_data.unwrap_or_default();
+    }
+
     pub(crate) fn set_response(&mut self, response: Response) {
         self.response = Some(response)
     }
@@ -3,7 +3,7 @@ use std::pin::Pin;
 use std::time::{Duration, Instant};
 
 use fnv::FnvHashMap;
-use futures::channel::mpsc::Receiver;
+use futures::channel::mpsc::{unbounded, Receiver, UnboundedReceiver};
 use futures::channel::oneshot::Sender as OneshotSender;
 use futures::stream::{Fuse, Stream, StreamExt};
 use futures::task::{Context, Poll};
@@ -17,6 +17,7 @@ use chromiumoxide_types::{CallId, Message, Method, Response};
 use chromiumoxide_types::{MethodId, Request as CdpRequest};
 pub(crate) use page::PageInner;
 
+use crate::browser::BrowserVersion;
 use crate::cmd::{to_command_response, CommandMessage};
 use crate::conn::Connection;
 use crate::error::{CdpError, Result};
@@ -26,13 +27,24 @@ use crate::handler::frame::{NavigationError, NavigationId, NavigationOk};
 use crate::handler::job::PeriodicJob;
 use crate::handler::session::Session;
 use crate::handler::target::TargetEvent;
-use crate::handler::target::{Target, TargetConfig};
+use crate::handler::target::{Target, TargetConfig, TargetFilter};
 use crate::handler::viewport::Viewport;
 use crate::page::Page;
+use crate::stealth::StealthPatch;
 
 /// Standard timeout in MS
 pub const REQUEST_TIMEOUT: u64 = 30_000;
 
+cfg_if::cfg_if! {
+    if #[cfg(feature = "async-std-runtime")] {
+        /// The handle of the task that drives a `Handler`, see `Handler::spawn`
+        pub type HandlerJoinHandle = async_std::task::JoinHandle<()>;
+    } else if #[cfg(feature = "tokio-runtime")] {
+        /// The handle of the task that drives a `Handler`, see `Handler::spawn`
+        pub type HandlerJoinHandle = tokio::task::JoinHandle<()>;
+    }
+}
+
 pub mod browser;
 pub mod commandfuture;
 pub mod domworld;
@@ -83,6 +95,12 @@ pub struct Handler {
     event_listeners: EventListeners,
     /// Keeps track is the browser is closing
     closing: bool,
+    /// Set if the browser's version is not supported, see
+    /// `HandlerConfig::strict_protocol_version`
+    protocol_error: Option<CdpError>,
+    /// The senders waiting for the session of a worker target that is being
+    /// attached to, see `Browser::service_workers`
+    worker_attachments: HashMap<TargetId, Vec<OneshotSender<Result<SessionId>>>>,
 }
 
 impl Handler {
@@ -93,6 +111,7 @@ impl Handler {
         rx: Receiver<HandlerMessage>,
         config: HandlerConfig,
     ) -> Self {
+        conn.set_log_protocol(config.log_protocol);
         let discover = SetDiscoverTargetsParams::new(true);
         let _ = conn.submit_command(
             discover.identifier(),
@@ -100,6 +119,24 @@ impl Handler {
             serde_json::to_value(discover).unwrap(),
         );
 
+        // check that the browser is the version this crate was generated from
+        let mut pending_commands = FnvHashMap::default();
+        let version = GetVersionParams::default();
+        if let Ok(call_id) = conn.submit_command(
+            version.identifier(),
+            None,
+            serde_json::to_value(&version).unwrap(),
+        ) {
+            pending_commands.insert(
+                call_id,
+                (
+                    PendingRequest::GetVersion,
+                    version.identifier(),
+                    Instant::now(),
+                ),
+            );
+        }
+
         let browser_contexts = config
             .context_ids
             .iter()
@@ -107,7 +144,7 @@ impl Handler {
             .collect();
 
         Self {
-            pending_commands: Default::default(),
+            pending_commands,
             from_browser: rx.fuse(),
             default_browser_context: Default::default(),
             browser_contexts,
@@ -121,6 +158,8 @@ impl Handler {
             config,
             event_listeners: Default::default(),
             closing: false,
+            protocol_error: None,
+            worker_attachments: Default::default(),
         }
     }
 
@@ -202,7 +241,7 @@ impl Handler {
                             if let Some(target) = self.targets.get_mut(&resp.target_id) {
                                 // move the sender to the target that sends its page once
                                 // initialized
-                                target.set_initiator(tx);
+                                target.add_initiator(tx);
                             } else {
                                 // TODO can this even happen?
                                 panic!("Created target not present")
@@ -252,10 +291,51 @@ impl Handler {
                     self.closing = true;
                     let _ = tx.send(Ok(CloseReturns {})).ok();
                 }
+                PendingRequest::GetVersion => {
+                    if let Ok(resp) = to_command_response::<GetVersionParams>(resp, method) {
+                        self.on_browser_version(resp.result);
+                    }
+                }
+                PendingRequest::AttachToWorker(target_id) => {
+                    let res = to_command_response::<AttachToTargetParams>(resp, method)
+                        .map(|resp| resp.result.session_id);
+                    if let Ok(session_id) = &res {
+                        self.on_attached_to_worker(target_id.clone(), session_id.clone());
+                    }
+                    self.on_worker_attachment(&target_id, res);
+                }
             }
         }
     }
 
+    /// Compares the major version of the connected browser with the chromium
+    /// version the protocol definitions were generated from.
+    ///
+    /// Only browsers older than that version are reported, since newer
+    /// browsers keep supporting the commands of older protocol definitions.
+    /// The devtools protocol version (`1.3`) is the same for every chromium
+    /// release and can't be used to detect incompatible browsers.
+    fn on_browser_version(&mut self, version: GetVersionReturns) {
+        let expected = chromiumoxide_cdp::CURRENT_MAJOR_VERSION;
+        let version = BrowserVersion::from(version);
+        if !version.major.is_some_and(|major| major < expected) {
+            return;
+        }
+        tracing::warn!(
+            "{} {} is older than chromium {} the devtools protocol was generated for (devtools-protocol {}), commands may fail",
+            version.product,
+            version.version,
+            expected,
+            chromiumoxide_cdp::CURRENT_REVISION
+        );
+        if self.config.strict_protocol_version {
+            self.protocol_error = Some(CdpError::ProtocolVersionMismatch {
+                expected: expected.to_string(),
+                actual: version.version,
+            });
+        }
+    }
+
     /// Submit a command initiated via channel
     pub(crate) fn submit_external_command(
         &mut self,
@@ -418,6 +498,106 @@ impl Handler {
         });
     }
 
+    /// Sends the `Page` of the target to `tx` once the target is initialized
+    /// and its main frame has finished loading.
+    ///
+    /// Targets that aren't tracked yet, e.g. because their `targetCreated`
+    /// event is still pending, are created and attached to.
+    fn attach_to_target(&mut self, info: TargetInfo, tx: OneshotSender<Result<Page>>) {
+        if !self.targets.contains_key(&info.target_id) {
+            self.on_target_created(EventTargetCreated {
+                target_info: info.clone(),
+            });
+        }
+        if let Some(target) = self.targets.get_mut(&info.target_id) {
+            target.add_initiator(tx);
+        }
+    }
+
+    /// Sends the session of the worker target to `tx`, attaches to the target
+    /// unless a session was already attached.
+    fn attach_to_worker(
+        &mut self,
+        info: TargetInfo,
+        tx: OneshotSender<Result<SessionId>>,
+        now: Instant,
+    ) {
+        let target_id = info.target_id.clone();
+        if !self.targets.contains_key(&target_id) {
+            self.on_target_created(EventTargetCreated { target_info: info });
+        }
+        if let Some(session_id) = self
+            .targets
+            .get(&target_id)
+            .and_then(|target| target.session_id())
+        {
+            let _ = tx.send(Ok(session_id.clone()));
+            return;
+        }
+        if let Some(waiting) = self.worker_attachments.get_mut(&target_id) {
+            // already attaching
+            waiting.push(tx);
+            return;
+        }
+        let attach = AttachToTargetParams::builder()
+            .target_id(target_id.clone())
+            .flatten(true)
+            .build()
+            .unwrap();
+        let method = attach.identifier();
+        match self
+            .conn
+            .submit_command(method.clone(), None, serde_json::to_value(attach).unwrap())
+        {
+            Ok(call_id) => {
+                self.worker_attachments.insert(target_id.clone(), vec![tx]);
+                self.pending_commands.insert(
+                    call_id,
+                    (PendingRequest::AttachToWorker(target_id), method, now),
+                );
+            }
+            Err(err) => {
+                let _ = tx.send(Err(err.into()));
+            }
+        }
+    }
+
+    /// Tracks the session attached to a worker target, so it's reused by
+    /// subsequent `Browser::service_workers` calls until it is detached.
+    fn on_attached_to_worker(&mut self, target_id: TargetId, session_id: SessionId) {
+        if let Some(target) = self.targets.get_mut(&target_id) {
+            target.set_session_id(session_id.clone());
+        }
+        self.sessions
+            .entry(session_id.clone())
+            .or_insert_with(|| Session::new(session_id, target_id));
+    }
+
+    /// Notifies all senders waiting for the session of the worker target
+    fn on_worker_attachment(&mut self, target_id: &TargetId, res: Result<SessionId>) {
+        let mut waiting = self
+            .worker_attachments
+            .remove(target_id)
+            .unwrap_or_default()
+            .into_iter();
+        match res {
+            Ok(session_id) => {
+                for tx in waiting {
+                    let _ = tx.send(Ok(session_id.clone()));
+                }
+            }
+            Err(err) => {
+                let msg = err.to_string();
+                if let Some(tx) = waiting.next() {
+                    let _ = tx.send(Err(err));
+                }
+                for tx in waiting {
+                    let _ = tx.send(Err(CdpError::msg(msg.clone())));
+                }
+            }
+        }
+    }
+
     /// Fired when a new target was created on the chromium instance
     ///
     /// Creates a new `Target` instance and keeps track of it
@@ -434,9 +614,16 @@ impl Handler {
             TargetConfig {
                 ignore_https_errors: self.config.ignore_https_errors,
                 request_timeout: self.config.request_timeout,
+                navigation_timeout: self.config.navigation_timeout,
                 viewport: self.config.viewport.clone(),
                 request_intercept: self.config.request_intercept,
                 cache_enabled: self.config.cache_enabled,
+                javascript_enabled: self.config.javascript_enabled,
+                stealth_patches: self.config.stealth_patches.clone(),
+                init_scripts: self.config.init_scripts.clone(),
+                utility_world_name: self.config.utility_world_name.clone(),
+                init_timeout: self.config.target_init_timeout,
+                init_retries: self.config.target_init_retries,
             },
             browser_ctx,
         );
@@ -460,15 +647,17 @@ impl Handler {
         // remove the session
         if let Some(session) = self.sessions.remove(&event.session_id) {
             if let Some(target) = self.targets.get_mut(session.target_id()) {
-                target.session_id().take();
+                if target.session_id() == Some(session.session_id()) {
+                    target.session_id_mut().take();
+                }
             }
         }
     }
 
     /// Fired when the target was destroyed in the browser
     fn on_target_destroyed(&mut self, event: EventTargetDestroyed) {
-        if let Some(target) = self.targets.remove(&event.target_id) {
-            // TODO shutdown?
+        if let Some(mut target) = self.targets.remove(&event.target_id) {
+            target.on_destroyed();
             if let Some(session) = target.session_id() {
                 self.sessions.remove(session);
             }
@@ -483,33 +672,49 @@ impl Handler {
         let timed_out = self
             .pending_commands
             .iter()
-            .filter(|(_, (_, _, timestamp))| now > (*timestamp + self.config.request_timeout))
+            .filter(|(_, (req, _, timestamp))| {
+                // navigations that are still in progress are timed out by the
+                // target with its navigation timeout instead
+                now > (*timestamp + self.config.request_timeout)
+                    && !matches!(req, PendingRequest::Navigate(id) if self.navigations.contains_key(id))
+            })
             .map(|(k, _)| *k)
             .collect::<Vec<_>>();
         for call in timed_out {
-            if let Some((req, _, _)) = self.pending_commands.remove(&call) {
+            if let Some((req, method, timestamp)) = self.pending_commands.remove(&call) {
+                let timeout = || CdpError::CommandTimeout {
+                    method: method.clone(),
+                    call_id: call,
+                    elapsed: now - timestamp,
+                };
                 match req {
                     PendingRequest::CreateTarget(tx) => {
-                        let _ = tx.send(Err(CdpError::Timeout));
+                        let _ = tx.send(Err(timeout()));
                     }
                     PendingRequest::GetTargets(tx) => {
-                        let _ = tx.send(Err(CdpError::Timeout));
+                        let _ = tx.send(Err(timeout()));
                     }
                     PendingRequest::Navigate(nav) => {
                         if let Some(nav) = self.navigations.remove(&nav) {
                             match nav {
                                 NavigationRequest::Navigate(nav) => {
-                                    let _ = nav.tx.send(Err(CdpError::Timeout));
+                                    let _ = nav.tx.send(Err(timeout()));
                                 }
                             }
                         }
                     }
                     PendingRequest::ExternalCommand(tx) => {
-                        let _ = tx.send(Err(CdpError::Timeout));
+                        let _ = tx.send(Err(timeout()));
                     }
-                    PendingRequest::InternalCommand(_) => {}
+                    PendingRequest::InternalCommand(_) => {
+                        tracing::warn!("{}", timeout());
+                    }
+                    PendingRequest::GetVersion => {}
                     PendingRequest::CloseBrowser(tx) => {
-                        let _ = tx.send(Err(CdpError::Timeout));
+                        let _ = tx.send(Err(timeout()));
+                    }
+                    PendingRequest::AttachToWorker(target_id) => {
+                        self.on_worker_attachment(&target_id, Err(timeout()));
                     }
                 }
             }
@@ -519,6 +724,48 @@ impl Handler {
     pub fn event_listeners_mut(&mut self) -> &mut EventListeners {
         &mut self.event_listeners
     }
+
+    /// Drives the handler on a background task until the connection to the
+    /// browser is closed.
+    ///
+    /// Nothing is sent to or received from the browser unless the handler is
+    /// polled, so this must be called (or the handler polled manually as a
+    /// `Stream`) before the `Browser` is used.
+    ///
+    /// All errors the handler yields are sent to the returned receiver, which
+    /// can be ignored or dropped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::{Browser, BrowserConfig};
+    /// # async fn demo() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let (browser, handler) = Browser::launch(BrowserConfig::builder().build()?).await?;
+    ///     let (handle, _errors) = handler.spawn();
+    ///
+    ///     let page = browser.new_page("https://en.wikipedia.org").await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(feature = "async-std-runtime", feature = "tokio-runtime"))]
+    pub fn spawn(mut self) -> (HandlerJoinHandle, UnboundedReceiver<CdpError>) {
+        let (tx, rx) = unbounded();
+        let fut = async move {
+            while let Some(res) = self.next().await {
+                if let Err(err) = res {
+                    let _ = tx.unbounded_send(err);
+                }
+            }
+        };
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "async-std-runtime")] {
+                let handle = async_std::task::spawn(fut);
+            } else if #[cfg(feature = "tokio-runtime")] {
+                let handle = tokio::spawn(fut);
+            }
+        }
+        (handle, rx)
+    }
 }
 
 impl Stream for Handler {
@@ -526,6 +773,9 @@ impl Stream for Handler {
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let pin = self.get_mut();
+        if pin.closing {
+            return Poll::Ready(None);
+        }
 
         loop {
             let now = Instant::now();
@@ -546,11 +796,11 @@ impl Stream for Handler {
                     HandlerMessage::CreatePage(params, tx) => {
                         pin.create_page(params, tx);
                     }
-                    HandlerMessage::GetPages(tx) => {
+                    HandlerMessage::GetPages(filter, tx) => {
                         let pages: Vec<_> = pin
                             .targets
                             .values_mut()
-                            .filter(|p| p.is_page())
+                            .filter(|target| filter.matches(target))
                             .filter_map(|target| target.get_or_create_page())
                             .map(|page| Page::from(page.clone()))
                             .collect();
@@ -570,6 +820,12 @@ impl Stream for Handler {
                             .map(|page| Page::from(page.clone()));
                         let _ = tx.send(page);
                     }
+                    HandlerMessage::AttachToTarget(info, tx) => {
+                        pin.attach_to_target(info, tx);
+                    }
+                    HandlerMessage::AttachToWorker(info, tx) => {
+                        pin.attach_to_worker(info, tx, now);
+                    }
                     HandlerMessage::AddEventListener(req) => {
                         pin.event_listeners.add_listener(req);
                     }
@@ -616,6 +872,11 @@ impl Stream for Handler {
                 match ev {
                     Ok(Message::Response(resp)) => {
                         pin.on_response(resp);
+                        if let Some(err) = pin.protocol_error.take() {
+                            // the handler stops after reporting an unsupported browser
+                            pin.closing = true;
+                            return Poll::Ready(Some(Err(err)));
+                        }
                         if pin.closing {
                             // handler should stop processing
                             return Poll::Ready(None);
@@ -656,10 +917,39 @@ pub struct HandlerConfig {
     pub context_ids: Vec<BrowserContextId>,
     /// default request timeout to use
     pub request_timeout: Duration,
+    /// default timeout of navigations
+    pub navigation_timeout: Duration,
     /// Whether to enable request interception
     pub request_intercept: bool,
     /// Whether to enable cache
     pub cache_enabled: bool,
+    /// Whether javascript is enabled on new pages
+    pub javascript_enabled: bool,
+    /// The `StealthPatch`es to apply to every page
+    pub stealth_patches: Vec<StealthPatch>,
+    /// Scripts to evaluate in every new document of every page
+    pub init_scripts: Vec<String>,
+    /// The name of the isolated world that is created in every frame to
+    /// evaluate scripts without interfering with the page's own scripts.
+    ///
+    /// Change this if a page or another automation layer sharing the browser
+    /// already uses a world with the default `frame::UTILITY_WORLD_NAME`.
+    pub utility_world_name: String,
+    /// Timeout after which the initialization of a new target fails with
+    /// `CdpError::Timeout`, which fails the `Browser::new_page` that created
+    /// it
+    pub target_init_timeout: Duration,
+    /// How often a command that initializes a new target is reissued if it
+    /// didn't receive a response within the `request_timeout`
+    pub target_init_retries: u32,
+    /// Whether the handler fails with `CdpError::ProtocolVersionMismatch` if
+    /// the major version of the browser is older than
+    /// `chromiumoxide_cdp::CURRENT_MAJOR_VERSION`, instead of only logging a
+    /// warning. The handler yields the error once and then ends.
+    pub strict_protocol_version: bool,
+    /// Whether every command sent to and every response and event received
+    /// from the browser is logged with `tracing::debug!`
+    pub log_protocol: bool,
 }
 
 impl Default for HandlerConfig {
@@ -669,8 +959,17 @@ impl Default for HandlerConfig {
             viewport: Default::default(),
             context_ids: Vec::new(),
             request_timeout: Duration::from_millis(REQUEST_TIMEOUT),
+            navigation_timeout: Duration::from_millis(REQUEST_TIMEOUT),
             request_intercept: false,
             cache_enabled: true,
+            javascript_enabled: true,
+            stealth_patches: Vec::new(),
+            init_scripts: Vec::new(),
+            utility_world_name: frame::UTILITY_WORLD_NAME.to_string(),
+            target_init_timeout: Duration::from_millis(REQUEST_TIMEOUT),
+            target_init_retries: 0,
+            strict_protocol_version: false,
+            log_protocol: false,
         }
     }
 }
@@ -737,6 +1036,10 @@ enum PendingRequest {
     InternalCommand(TargetId),
     // A Request to close the browser.
     CloseBrowser(OneshotSender<Result<CloseReturns>>),
+    /// A Request for the browser's version, submitted once connected.
+    GetVersion,
+    /// A Request to attach to a worker target, see `Browser::service_workers`
+    AttachToWorker(TargetId),
 }
 
 /// Events used internally to communicate with the handler, which are executed
@@ -748,9 +1051,11 @@ pub(crate) enum HandlerMessage {
     FetchTargets(OneshotSender<Result<Vec<TargetInfo>>>),
     InsertContext(BrowserContext),
     DisposeContext(BrowserContext),
-    GetPages(OneshotSender<Vec<Page>>),
+    GetPages(TargetFilter, OneshotSender<Vec<Page>>),
     Command(CommandMessage),
     GetPage(TargetId, OneshotSender<Option<Page>>),
+    AttachToTarget(TargetInfo, OneshotSender<Result<Page>>),
+    AttachToWorker(TargetInfo, OneshotSender<Result<SessionId>>),
     AddEventListener(EventListenerRequest),
     CloseBrowser(OneshotSender<Result<CloseReturns>>),
 }
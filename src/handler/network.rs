@@ -1,6 +1,7 @@
 use chromiumoxide_cdp::cdp::browser_protocol::fetch::{
     self, AuthChallengeResponse, AuthChallengeResponseResponse, ContinueRequestParams,
     ContinueWithAuthParams, DisableParams, EventAuthRequired, EventRequestPaused, RequestPattern,
+    RequestStage,
 };
 use chromiumoxide_cdp::cdp::browser_protocol::network::{
     EmulateNetworkConditionsParams, EventLoadingFailed, EventLoadingFinished,
@@ -31,7 +32,11 @@ pub struct NetworkManager {
     attempted_authentications: HashSet<RequestId>,
     credentials: Option<Credentials>,
     user_request_interception_enabled: bool,
+    /// The patterns of the requests to intercept, all requests if empty
+    user_request_patterns: Vec<RequestPattern>,
     protocol_request_interception_enabled: bool,
+    /// The patterns of the last `Fetch.enable`
+    protocol_request_patterns: Vec<RequestPattern>,
     offline: bool,
     request_timeout: Duration,
 }
@@ -49,7 +54,9 @@ impl NetworkManager {
             attempted_authentications: Default::default(),
             credentials: None,
             user_request_interception_enabled: false,
+            user_request_patterns: Vec::new(),
             protocol_request_interception_enabled: false,
+            protocol_request_patterns: Vec::new(),
             offline: false,
             request_timeout,
         }
@@ -92,6 +99,17 @@ impl NetworkManager {
     }
 
     pub fn set_request_interception(&mut self, enabled: bool) {
+        self.set_request_interception_patterns(enabled, Vec::new());
+    }
+
+    /// Intercepts only the requests that match any of the `patterns`, or all
+    /// requests if `patterns` is empty.
+    pub fn set_request_interception_patterns(
+        &mut self,
+        enabled: bool,
+        patterns: Vec<RequestPattern>,
+    ) {
+        self.user_request_patterns = patterns;
         self.user_request_interception_enabled = enabled;
         self.update_protocol_request_interception();
     }
@@ -114,24 +132,84 @@ impl NetworkManager {
 
     fn update_protocol_request_interception(&mut self) {
         let enabled = self.user_request_interception_enabled || self.credentials.is_some();
-        if enabled == self.protocol_request_interception_enabled {
+        if !enabled {
+            if self.protocol_request_interception_enabled {
+                self.protocol_request_interception_enabled = false;
+                self.protocol_request_patterns.clear();
+                self.update_protocol_cache_disabled();
+                self.push_cdp_request(DisableParams::default());
+            }
             return;
         }
-        self.update_protocol_cache_disabled();
-        if enabled {
-            self.push_cdp_request(
-                fetch::EnableParams::builder()
-                    .handle_auth_requests(true)
-                    .pattern(RequestPattern::builder().url_pattern("*").build())
-                    .build(),
-            )
+
+        let patterns = self.protocol_request_patterns();
+        if self.protocol_request_interception_enabled && patterns == self.protocol_request_patterns
+        {
+            return;
+        }
+        if !self.protocol_request_interception_enabled {
+            self.protocol_request_interception_enabled = true;
+            self.update_protocol_cache_disabled();
+        }
+        // `Fetch.enable` replaces the patterns of the previous call
+        self.protocol_request_patterns = patterns.clone();
+        self.push_cdp_request(
+            fetch::EnableParams::builder()
+                .handle_auth_requests(true)
+                .patterns(patterns)
+                .build(),
+        );
+    }
+
+    /// The patterns of the requests to pause: the user's patterns, and all
+    /// requests if there are none or if credentials are set, since auth
+    /// challenges are only reported for paused requests.
+    fn protocol_request_patterns(&self) -> Vec<RequestPattern> {
+        let mut patterns = if self.user_request_interception_enabled {
+            self.user_request_patterns.clone()
         } else {
-            self.push_cdp_request(DisableParams::default())
+            Vec::new()
+        };
+        if patterns.is_empty() || self.credentials.is_some() {
+            patterns.push(RequestPattern::builder().url_pattern("*").build());
         }
+        patterns
+    }
+
+    /// Whether the paused request was requested by the user's interception,
+    /// otherwise it was only paused to handle auth challenges.
+    fn is_user_intercepted(&self, event: &EventRequestPaused) -> bool {
+        if !self.user_request_interception_enabled {
+            return false;
+        }
+        if self.user_request_patterns.is_empty() {
+            return true;
+        }
+        let stage = if event.response_status_code.is_some() || event.response_error_reason.is_some()
+        {
+            RequestStage::Response
+        } else {
+            RequestStage::Request
+        };
+        self.user_request_patterns.iter().any(|pattern| {
+            pattern
+                .url_pattern
+                .as_deref()
+                .map_or(true, |url| url_pattern_matches(url, &event.request.url))
+                && pattern
+                    .resource_type
+                    .as_ref()
+                    .map_or(true, |ty| *ty == event.resource_type)
+                && *pattern
+                    .request_stage
+                    .as_ref()
+                    .unwrap_or(&RequestStage::Request)
+                    == stage
+        })
     }
 
     pub fn on_fetch_request_paused(&mut self, event: &EventRequestPaused) {
-        if !self.user_request_interception_enabled && self.protocol_request_interception_enabled {
+        if self.protocol_request_interception_enabled && !self.is_user_intercepted(event) {
             self.push_cdp_request(ContinueRequestParams::new(event.request_id.clone()))
         }
         if let Some(network_id) = event.network_id.as_ref() {
@@ -252,13 +330,15 @@ impl NetworkManager {
                 redirect_chain.push(request);
             }
         }
-        let request = HttpRequest::new(
+        let mut request = HttpRequest::new(
             event.request_id.clone(),
             event.frame_id.clone(),
             interception_id,
             self.user_request_interception_enabled,
             redirect_chain,
         );
+        request.is_navigation_request = is_navigation_request(event);
+        request.set_request(&event.request, event.r#type.as_ref());
 
         self.requests.insert(event.request_id.clone(), request);
         self.queued_events
@@ -282,3 +362,195 @@ pub enum NetworkEvent {
     RequestFailed(HttpRequest),
     RequestFinished(HttpRequest),
 }
+
+/// Whether the `url` matches the `pattern` of a `RequestPattern`, in which
+/// `*` matches zero or more and `?` exactly one character, escaped with `\`.
+fn url_pattern_matches(pattern: &str, url: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let url: Vec<char> = url.chars().collect();
+    // the position after the last `*` and the url position it was matched at
+    let mut star: Option<(usize, usize)> = None;
+    let (mut p, mut u) = (0, 0);
+    while u < url.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                p += 1;
+                star = Some((p, u));
+                continue;
+            }
+            Some('?') => {
+                p += 1;
+                u += 1;
+                continue;
+            }
+            Some('\\') if pattern.get(p + 1) == Some(&url[u]) => {
+                p += 2;
+                u += 1;
+                continue;
+            }
+            Some(c) if *c != '\\' && *c == url[u] => {
+                p += 1;
+                u += 1;
+                continue;
+            }
+            _ => {}
+        }
+        match star {
+            // let the last `*` match one more character
+            Some((star_p, star_u)) => {
+                p = star_p;
+                u = star_u + 1;
+                star = Some((star_p, star_u + 1));
+            }
+            None => return false,
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Whether the request loads a new document into its frame.
+///
+/// The request that loads a document shares its id with the document's
+/// loader, all other requests of the document (subresources, fetches, ...)
+/// have their own id.
+fn is_navigation_request(event: &EventRequestWillBeSent) -> bool {
+    event.request_id.as_ref() == event.loader_id.as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_url_patterns() {
+        assert!(url_pattern_matches("*", "https://example.com/"));
+        assert!(url_pattern_matches("*", ""));
+        assert!(url_pattern_matches(
+            "*.json",
+            "https://example.com/data.json"
+        ));
+        assert!(!url_pattern_matches(
+            "*.json",
+            "https://example.com/data.json?x"
+        ));
+        assert!(url_pattern_matches(
+            "*.json*",
+            "https://example.com/data.json?x"
+        ));
+        assert!(url_pattern_matches(
+            "https://*.example.com/*",
+            "https://api.example.com/v1"
+        ));
+        assert!(!url_pattern_matches(
+            "https://*.example.com/*",
+            "https://example.org/v1"
+        ));
+        assert!(url_pattern_matches(
+            "https://example.com/?",
+            "https://example.com/a"
+        ));
+        assert!(!url_pattern_matches(
+            "https://example.com/?",
+            "https://example.com/"
+        ));
+        assert!(url_pattern_matches("*\\?q=1", "https://example.com/?q=1"));
+        assert!(!url_pattern_matches("*\\?q=1", "https://example.com/xq=1"));
+        assert!(url_pattern_matches("*\\*", "https://example.com/*"));
+        assert!(!url_pattern_matches("*\\*", "https://example.com/a"));
+    }
+
+    /// The patterns of every `Fetch.enable` queued by the `manager`
+    fn fetch_enable_patterns(manager: &mut NetworkManager) -> Vec<Vec<String>> {
+        let mut enabled = Vec::new();
+        while let Some(event) = manager.poll() {
+            if let NetworkEvent::SendCdpRequest((method, params)) = event {
+                if method == fetch::EnableParams::IDENTIFIER {
+                    let params: fetch::EnableParams = serde_json::from_value(params).unwrap();
+                    enabled.push(
+                        params
+                            .patterns
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(|p| p.url_pattern.unwrap_or_default())
+                            .collect(),
+                    );
+                }
+            }
+        }
+        enabled
+    }
+
+    #[test]
+    fn credentials_keep_catch_all_pattern() {
+        let mut manager = NetworkManager::new(false, Duration::from_secs(1));
+        manager.authenticate(("user", "password").into());
+        assert_eq!(fetch_enable_patterns(&mut manager), vec![vec!["*"]]);
+
+        let json = RequestPattern::builder().url_pattern("*.json").build();
+        manager.set_request_interception_patterns(true, vec![json]);
+        assert_eq!(
+            fetch_enable_patterns(&mut manager),
+            vec![vec!["*.json", "*"]]
+        );
+
+        // unchanged patterns aren't sent again
+        manager.set_request_interception(true);
+        manager.set_request_interception(false);
+        assert_eq!(fetch_enable_patterns(&mut manager), vec![vec!["*"]]);
+    }
+
+    #[test]
+    fn changed_patterns_are_sent_again() {
+        let mut manager = NetworkManager::new(false, Duration::from_secs(1));
+        let json = RequestPattern::builder().url_pattern("*.json").build();
+        manager.set_request_interception_patterns(true, vec![json]);
+        assert_eq!(fetch_enable_patterns(&mut manager), vec![vec!["*.json"]]);
+
+        manager.set_request_interception(true);
+        assert_eq!(fetch_enable_patterns(&mut manager), vec![vec!["*"]]);
+
+        manager.set_request_interception(false);
+        assert!(fetch_enable_patterns(&mut manager).is_empty());
+    }
+
+    fn request_will_be_sent(request_id: &str, loader_id: &str) -> EventRequestWillBeSent {
+        serde_json::from_value(serde_json::json!({
+            "requestId": request_id,
+            "loaderId": loader_id,
+            "documentURL": "https://example.com/",
+            "request": {
+                "url": "https://example.com/",
+                "method": "GET",
+                "headers": {},
+                "initialPriority": "VeryHigh",
+                "referrerPolicy": "no-referrer"
+            },
+            "timestamp": 1.0,
+            "wallTime": 1.0,
+            "initiator": { "type": "other" },
+            "redirectHasExtraInfo": false,
+            "type": "Document",
+            "frameId": "frame"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn detects_navigation_requests() {
+        assert!(is_navigation_request(&request_will_be_sent(
+            "1D5B6F", "1D5B6F"
+        )));
+        assert!(!is_navigation_request(&request_will_be_sent(
+            "1000.12", "1D5B6F"
+        )));
+    }
+
+    #[test]
+    fn tracks_navigation_requests() {
+        let mut manager = NetworkManager::new(false, Duration::from_secs(30));
+        manager.on_request_will_be_sent(&request_will_be_sent("1D5B6F", "1D5B6F"));
+        manager.on_request_will_be_sent(&request_will_be_sent("1000.12", "1D5B6F"));
+        assert!(manager.requests[&RequestId::new("1D5B6F")].is_navigation_request);
+        assert!(!manager.requests[&RequestId::new("1000.12")].is_navigation_request);
+    }
+}
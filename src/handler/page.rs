@@ -1,10 +1,13 @@
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use futures::channel::mpsc::{channel, Receiver, Sender};
+use futures::channel::mpsc::{channel, unbounded, Receiver, Sender};
 use futures::channel::oneshot::channel as oneshot_channel;
 use futures::stream::Fuse;
 use futures::{SinkExt, StreamExt};
 
+use chromiumoxide_cdp::cdp::browser_protocol::accessibility;
 use chromiumoxide_cdp::cdp::browser_protocol::browser::{GetVersionParams, GetVersionReturns};
 use chromiumoxide_cdp::cdp::browser_protocol::dom::{
     DiscardSearchResultsParams, GetSearchResultsParams, NodeId, PerformSearchParams,
@@ -16,8 +19,9 @@ use chromiumoxide_cdp::cdp::browser_protocol::emulation::{
 };
 use chromiumoxide_cdp::cdp::browser_protocol::input::{
     DispatchKeyEventParams, DispatchKeyEventType, DispatchMouseEventParams, DispatchMouseEventType,
-    MouseButton,
+    InsertTextParams,
 };
+use chromiumoxide_cdp::cdp::browser_protocol::network::SetUserAgentOverrideParams;
 use chromiumoxide_cdp::cdp::browser_protocol::page::{
     FrameId, GetLayoutMetricsParams, GetLayoutMetricsReturns, Viewport,
 };
@@ -25,18 +29,22 @@ use chromiumoxide_cdp::cdp::browser_protocol::target::{ActivateTargetParams, Ses
 use chromiumoxide_cdp::cdp::js_protocol::runtime::{
     CallFunctionOnParams, CallFunctionOnReturns, EvaluateParams, ExecutionContextId, RemoteObjectId,
 };
+use chromiumoxide_cdp::cdp::IntoEventKind;
 use chromiumoxide_types::{Command, CommandResponse};
 
 use crate::cmd::{to_command_response, CommandMessage};
 use crate::error::{CdpError, Result};
 use crate::handler::commandfuture::CommandFuture;
 use crate::handler::domworld::DOMWorldKind;
+use crate::handler::frame::LifecycleEvent;
 use crate::handler::httpfuture::HttpFuture;
 use crate::handler::target::{GetExecutionContext, TargetMessage};
 use crate::handler::target_message_future::TargetMessageFuture;
-use crate::js::EvaluationResult;
+use crate::js::{self, EvaluationResult};
 use crate::layout::Point;
-use crate::page::ScreenshotParams;
+use crate::listeners::{EventListenerRequest, EventStream};
+use crate::page::{ClickOptions, KeyLocation, PressOptions, ScreenshotParams};
+use crate::recorder::ResponseRecorder;
 use crate::{keys, utils, ArcHttpRequest};
 
 #[derive(Debug)]
@@ -46,13 +54,23 @@ pub struct PageHandle {
 }
 
 impl PageHandle {
-    pub fn new(target_id: TargetId, session_id: SessionId, opener_id: Option<TargetId>) -> Self {
+    pub fn new(
+        target_id: TargetId,
+        session_id: SessionId,
+        opener_id: Option<TargetId>,
+        navigation_timeout: Duration,
+    ) -> Self {
         let (commands, rx) = channel(1);
         let page = PageInner {
             target_id,
             session_id,
             opener_id,
             sender: commands,
+            closed: AtomicBool::new(false),
+            navigation_timeout: AtomicU64::new(navigation_timeout.as_millis() as u64),
+            response_recorder: Mutex::new(None),
+            accessibility_enabled: AtomicBool::new(false),
+            user_agent_override: Mutex::new(None),
         };
         Self {
             rx: rx.fuse(),
@@ -71,6 +89,16 @@ pub(crate) struct PageInner {
     session_id: SessionId,
     opener_id: Option<TargetId>,
     sender: Sender<TargetMessage>,
+    /// Whether the page's target was destroyed
+    closed: AtomicBool,
+    /// The timeout of navigations in milliseconds
+    navigation_timeout: AtomicU64,
+    /// The currently running response recording
+    response_recorder: Mutex<Option<ResponseRecorder>>,
+    /// Whether the `Accessibility` domain was enabled
+    accessibility_enabled: AtomicBool,
+    /// The user agent override that was last sent for this page
+    user_agent_override: Mutex<Option<SetUserAgentOverrideParams>>,
 }
 
 impl PageInner {
@@ -79,16 +107,57 @@ impl PageInner {
         execute(cmd, self.sender.clone(), Some(self.session_id.clone())).await
     }
 
+    /// Execute a PDL command on the browser instead of this page's session,
+    /// e.g. for commands of the `Browser` domain
+    pub(crate) async fn execute_on_browser<T: Command>(
+        &self,
+        cmd: T,
+    ) -> Result<CommandResponse<T::Response>> {
+        execute(cmd, self.sender.clone(), None).await
+    }
+
     /// Create a PDL command future
     pub(crate) fn command_future<T: Command>(&self, cmd: T) -> Result<CommandFuture<T>> {
         CommandFuture::new(cmd, self.sender.clone(), Some(self.session_id.clone()))
     }
 
+    /// Create a PDL command future for a navigation that times out after the
+    /// page's navigation timeout
+    pub(crate) fn navigation_future<T: Command>(&self, cmd: T) -> Result<CommandFuture<T>> {
+        CommandFuture::with_timeout(
+            cmd,
+            self.sender.clone(),
+            Some(self.session_id.clone()),
+            self.navigation_timeout(),
+        )
+    }
+
+    /// Registers a listener for events of type `T` received by this page
+    pub(crate) async fn event_listener<T: IntoEventKind>(&self) -> Result<EventStream<T>> {
+        let (tx, rx) = unbounded();
+        self.sender
+            .clone()
+            .send(TargetMessage::AddEventListener(
+                EventListenerRequest::new::<T>(tx),
+            ))
+            .await?;
+        Ok(EventStream::new(rx))
+    }
+
     /// This creates navigation future with the final http response when the page is loaded
     pub(crate) fn wait_for_navigation(&self) -> TargetMessageFuture<ArcHttpRequest> {
         TargetMessageFuture::<ArcHttpRequest>::wait_for_navigation(self.sender.clone())
     }
 
+    /// This creates a future that resolves with the final http response once
+    /// the main frame received the lifecycle `event`
+    pub(crate) fn wait_for_lifecycle_event(
+        &self,
+        event: LifecycleEvent,
+    ) -> TargetMessageFuture<ArcHttpRequest> {
+        TargetMessageFuture::<ArcHttpRequest>::wait_for_lifecycle_event(self.sender.clone(), event)
+    }
+
     /// This creates HTTP future with navigation and responds with the final
     /// http response when the page is loaded
     pub(crate) fn http_future<T: Command>(&self, cmd: T) -> Result<HttpFuture<T>> {
@@ -113,6 +182,30 @@ impl PageInner {
         &self.opener_id
     }
 
+    /// The currently running response recording, see
+    /// `Page::start_response_recording`
+    pub(crate) fn response_recorder(&self) -> &Mutex<Option<ResponseRecorder>> {
+        &self.response_recorder
+    }
+
+    /// The user agent override that was last sent for this page, see
+    /// `Page::set_user_agent` and `Page::set_locale`
+    pub(crate) fn user_agent_override(&self) -> &Mutex<Option<SetUserAgentOverrideParams>> {
+        &self.user_agent_override
+    }
+
+    /// Enables the `Accessibility` domain, unless it was already enabled for
+    /// this page
+    pub(crate) async fn enable_accessibility(&self) -> Result<()> {
+        if !self.accessibility_enabled.swap(true, Ordering::Relaxed) {
+            if let Err(err) = self.execute(accessibility::EnableParams::default()).await {
+                self.accessibility_enabled.store(false, Ordering::Relaxed);
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn sender(&self) -> &Sender<TargetMessage> {
         &self.sender
     }
@@ -126,6 +219,43 @@ impl PageInner {
             .node_id)
     }
 
+    /// Whether the page's target was destroyed
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_closed(&self) {
+        self.closed.store(true, Ordering::Relaxed);
+    }
+
+    /// The timeout of navigations of this page
+    pub(crate) fn navigation_timeout(&self) -> Duration {
+        Duration::from_millis(self.navigation_timeout.load(Ordering::Relaxed))
+    }
+
+    pub(crate) fn set_navigation_timeout(&self, timeout: Duration) {
+        self.navigation_timeout
+            .store(timeout.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Resolves once the page's target was destroyed
+    pub async fn closed(&self) {
+        if self.is_closed() {
+            return;
+        }
+        let (tx, rx) = oneshot_channel();
+        if self
+            .sender
+            .clone()
+            .send(TargetMessage::WaitForClose(tx))
+            .await
+            .is_ok()
+        {
+            // the sender is dropped together with the target
+            let _ = rx.await;
+        }
+    }
+
     /// Activates (focuses) the target.
     pub async fn activate(&self) -> Result<&Self> {
         self.execute(ActivateTargetParams::new(self.target_id().clone()))
@@ -191,28 +321,45 @@ impl PageInner {
 
     /// Performs a mouse click event at the point's location
     pub async fn click(&self, point: Point) -> Result<&Self> {
+        self.click_with_options(point, &ClickOptions::default())
+            .await
+    }
+
+    /// Performs a mouse click event at the point's location with the given
+    /// `ClickOptions`.
+    ///
+    /// For a `click_count` greater than one, a press/release pair is
+    /// dispatched for every click with an increasing click count, like a
+    /// real double or triple click.
+    pub async fn click_with_options(&self, point: Point, options: &ClickOptions) -> Result<&Self> {
         let cmd = DispatchMouseEventParams::builder()
             .x(point.x)
             .y(point.y)
-            .button(MouseButton::Left)
-            .click_count(1);
+            .button(options.button.clone())
+            .modifiers(options.modifiers);
 
-        self.move_mouse(point)
-            .await?
-            .execute(
+        self.move_mouse(point).await?;
+        for click_count in 1..=options.click_count.max(1) {
+            self.execute(
                 cmd.clone()
                     .r#type(DispatchMouseEventType::MousePressed)
+                    .click_count(click_count)
                     .build()
                     .unwrap(),
             )
             .await?;
-
-        self.execute(
-            cmd.r#type(DispatchMouseEventType::MouseReleased)
-                .build()
-                .unwrap(),
-        )
-        .await?;
+            if let Some(delay) = options.delay {
+                futures_timer::Delay::new(delay).await;
+            }
+            self.execute(
+                cmd.clone()
+                    .r#type(DispatchMouseEventType::MouseReleased)
+                    .click_count(click_count)
+                    .build()
+                    .unwrap(),
+            )
+            .await?;
+        }
         Ok(self)
     }
 
@@ -231,12 +378,34 @@ impl PageInner {
         Ok(self)
     }
 
+    /// Inserts the `text` at once, as if it was pasted or entered by an IME.
+    ///
+    /// Unlike `type_str` this doesn't emit any key events.
+    pub async fn insert_text(&self, text: impl Into<String>) -> Result<&Self> {
+        self.execute(InsertTextParams::new(text)).await?;
+        Ok(self)
+    }
+
     /// Uses the `DispatchKeyEvent` mechanism to simulate pressing keyboard
     /// keys.
     pub async fn press_key(&self, key: impl AsRef<str>) -> Result<&Self> {
+        self.press_key_with_options(key, &PressOptions::default())
+            .await
+    }
+
+    /// Uses the `DispatchKeyEvent` mechanism to simulate pressing keyboard
+    /// keys with the given `PressOptions`.
+    pub async fn press_key_with_options(
+        &self,
+        key: impl AsRef<str>,
+        options: &PressOptions,
+    ) -> Result<&Self> {
         let key = key.as_ref();
-        let key_definition = keys::get_key_definition(key)
-            .ok_or_else(|| CdpError::msg(format!("Key not found: {key}")))?;
+        let key_definition = match options.location {
+            Some(location) => keys::get_key_definition_at(key, location),
+            None => keys::get_key_definition(key),
+        }
+        .ok_or_else(|| CdpError::msg(format!("Key not found: {key}")))?;
         let mut cmd = DispatchKeyEventParams::builder();
 
         // See https://github.com/GoogleChrome/puppeteer/blob/62da2366c65b335751896afbb0206f23c61436f1/lib/Input.js#L114-L115
@@ -251,6 +420,12 @@ impl PageInner {
             DispatchKeyEventType::RawKeyDown
         };
 
+        if let Some(location) = options.location {
+            cmd = cmd
+                .location(location as i64)
+                .is_keypad(location == KeyLocation::Numpad);
+        }
+
         cmd = cmd
             .r#type(DispatchKeyEventType::KeyDown)
             .key(key_definition.key)
@@ -258,8 +433,26 @@ impl PageInner {
             .windows_virtual_key_code(key_definition.key_code)
             .native_virtual_key_code(key_definition.key_code);
 
-        self.execute(cmd.clone().r#type(key_down_event_type).build().unwrap())
+        self.execute(
+            cmd.clone()
+                .r#type(key_down_event_type.clone())
+                .build()
+                .unwrap(),
+        )
+        .await?;
+        for _ in 0..options.repeat {
+            self.execute(
+                cmd.clone()
+                    .r#type(key_down_event_type.clone())
+                    .auto_repeat(true)
+                    .build()
+                    .unwrap(),
+            )
             .await?;
+        }
+        if let Some(delay) = options.delay {
+            futures_timer::Delay::new(delay).await;
+        }
         self.execute(cmd.r#type(DispatchKeyEventType::KeyUp).build().unwrap())
             .await?;
         Ok(self)
@@ -291,45 +484,24 @@ impl PageInner {
         &self,
         evaluate: impl Into<EvaluateParams>,
     ) -> Result<EvaluationResult> {
-        let mut evaluate = evaluate.into();
+        let mut evaluate = js::evaluate_defaults(evaluate.into());
         if evaluate.context_id.is_none() {
             evaluate.context_id = self.execution_context().await?;
         }
-        if evaluate.await_promise.is_none() {
-            evaluate.await_promise = Some(true);
-        }
-        if evaluate.return_by_value.is_none() {
-            evaluate.return_by_value = Some(true);
-        }
-
         let resp = self.execute(evaluate).await?.result;
-        if let Some(exception) = resp.exception_details {
-            return Err(CdpError::JavascriptException(Box::new(exception)));
-        }
-
-        Ok(EvaluationResult::new(resp.result))
+        EvaluationResult::from_response(resp.result, resp.exception_details)
     }
 
     pub async fn evaluate_function(
         &self,
         evaluate: impl Into<CallFunctionOnParams>,
     ) -> Result<EvaluationResult> {
-        let mut evaluate = evaluate.into();
+        let mut evaluate = js::call_function_defaults(evaluate.into());
         if evaluate.execution_context_id.is_none() {
             evaluate.execution_context_id = self.execution_context().await?;
         }
-        if evaluate.await_promise.is_none() {
-            evaluate.await_promise = Some(true);
-        }
-        if evaluate.return_by_value.is_none() {
-            evaluate.return_by_value = Some(true);
-        }
-
         let resp = self.execute(evaluate).await?.result;
-        if let Some(exception) = resp.exception_details {
-            return Err(CdpError::JavascriptException(Box::new(exception)));
-        }
-        Ok(EvaluationResult::new(resp.result))
+        EvaluationResult::from_response(resp.result, resp.exception_details)
     }
 
     pub async fn execution_context(&self) -> Result<Option<ExecutionContextId>> {
@@ -8,9 +8,13 @@ use futures::channel::oneshot::Sender;
 use futures::stream::Stream;
 use futures::task::{Context, Poll};
 
-use chromiumoxide_cdp::cdp::browser_protocol::page::{FrameId, GetFrameTreeParams};
+use chromiumoxide_cdp::cdp::browser_protocol::fetch::RequestPattern;
+use chromiumoxide_cdp::cdp::browser_protocol::page::{
+    AddScriptToEvaluateOnNewDocumentParams, CreateIsolatedWorldParams, FrameId, GetFrameTreeParams,
+};
 use chromiumoxide_cdp::cdp::browser_protocol::{
     browser::BrowserContextId,
+    emulation::SetScriptExecutionDisabledParams,
     log as cdplog, performance,
     target::{AttachToTargetParams, SessionId, SetAutoAttachParams, TargetId, TargetInfo},
 };
@@ -27,14 +31,15 @@ use crate::handler::browser::BrowserContext;
 use crate::handler::domworld::DOMWorldKind;
 use crate::handler::emulation::EmulationManager;
 use crate::handler::frame::{
-    FrameEvent, FrameManager, NavigationError, NavigationId, NavigationOk,
+    FrameEvent, FrameManager, LifecycleEvent, NavigationError, NavigationId, NavigationOk,
 };
-use crate::handler::frame::{FrameNavigationRequest, UTILITY_WORLD_NAME};
+use crate::handler::frame::{FrameNavigationRequest, FrameNode, UTILITY_WORLD_NAME};
 use crate::handler::network::{NetworkEvent, NetworkManager};
 use crate::handler::page::PageHandle;
 use crate::handler::viewport::Viewport;
 use crate::handler::{PageInner, REQUEST_TIMEOUT};
 use crate::listeners::{EventListenerRequest, EventListeners};
+use crate::stealth::StealthPatch;
 use crate::{page::Page, ArcHttpRequest};
 use chromiumoxide_cdp::cdp::js_protocol::runtime::{
     ExecutionContextId, RunIfWaitingForDebuggerParams,
@@ -54,7 +59,7 @@ macro_rules! advance_state {
                     session_id: $s.session_id.clone().map(Into::into),
                     params,
                 })),
-                Some(Err(_)) => Some($s.on_initialization_failed()),
+                Some(Err(_)) => Some($s.on_initialization_failed(CdpError::Timeout)),
             };
         } else {
             return None;
@@ -89,9 +94,19 @@ pub struct Target {
     /// All registered event subscriptions
     event_listeners: EventListeners,
     /// Senders that need to be notified once the main frame has loaded
-    wait_for_frame_navigation: Vec<Sender<ArcHttpRequest>>,
-    /// The sender who requested the page.
-    initiator: Option<Sender<Result<Page>>>,
+    wait_for_frame_navigation: Vec<(LifecycleEvent, Sender<ArcHttpRequest>)>,
+    /// The senders who requested the page.
+    initiators: Vec<Sender<Result<Page>>>,
+    /// Senders that need to be notified once the target was destroyed
+    wait_for_close: Vec<Sender<()>>,
+    /// The error of a failed init command, fails the initialization on the
+    /// next poll
+    init_error: Option<CdpError>,
+    /// The instant after which the initialization fails if it didn't complete
+    init_deadline: Option<Instant>,
+    /// The instant after which the initiators stop waiting for the main frame
+    /// of the initialized target to finish loading
+    load_deadline: Option<Instant>,
 }
 
 impl Target {
@@ -100,16 +115,20 @@ impl Target {
     pub fn new(info: TargetInfo, config: TargetConfig, browser_context: BrowserContext) -> Self {
         let ty = TargetType::new(&info.r#type);
         let request_timeout = config.request_timeout;
+        let navigation_timeout = config.navigation_timeout;
         let mut network_manager = NetworkManager::new(config.ignore_https_errors, request_timeout);
 
         network_manager.set_cache_enabled(config.cache_enabled);
         network_manager.set_request_interception(config.request_intercept);
 
+        let mut frame_manager = FrameManager::new(request_timeout, navigation_timeout);
+        frame_manager.set_utility_world_name(config.utility_world_name.clone());
+
         Self {
             info,
             r#type: ty,
             config,
-            frame_manager: FrameManager::new(request_timeout),
+            frame_manager,
             network_manager,
             emulation_manager: EmulationManager::new(request_timeout),
             session_id: None,
@@ -118,7 +137,11 @@ impl Target {
             wait_for_frame_navigation: Default::default(),
             queued_events: Default::default(),
             event_listeners: Default::default(),
-            initiator: None,
+            initiators: Vec::new(),
+            wait_for_close: Vec::new(),
+            init_error: None,
+            init_deadline: None,
+            load_deadline: None,
             browser_context,
         }
     }
@@ -162,8 +185,12 @@ impl Target {
     fn create_page(&mut self) {
         if self.page.is_none() {
             if let Some(session) = self.session_id.clone() {
-                let handle =
-                    PageHandle::new(self.target_id().clone(), session, self.opener_id().cloned());
+                let handle = PageHandle::new(
+                    self.target_id().clone(),
+                    session,
+                    self.opener_id().cloned(),
+                    self.config.navigation_timeout,
+                );
                 self.page = Some(handle);
             }
         }
@@ -207,7 +234,18 @@ impl Target {
     /// Received a response to a command issued by this target
     pub fn on_response(&mut self, resp: Response, method: &str) {
         if let Some(cmds) = self.init_state.commands_mut() {
-            cmds.received_response(method);
+            if cmds.received_response(method) {
+                // isolated worlds can't be created in frames that were
+                // detached in the meantime, which is not fatal
+                if let Some(error) = resp.error.clone() {
+                    if method != CreateIsolatedWorldParams::IDENTIFIER {
+                        self.init_error = Some(CdpError::TargetInitialization {
+                            method: method.to_string().into(),
+                            error,
+                        });
+                    }
+                }
+            }
         }
         #[allow(clippy::single_match)] // allow for now
         match method {
@@ -254,6 +292,9 @@ impl Target {
             CdpEvent::PageFrameStartedLoading(ev) => {
                 self.frame_manager.on_frame_started_loading(ev);
             }
+            CdpEvent::PageFrameStoppedLoading(ev) => {
+                self.frame_manager.on_frame_stopped_loading(ev);
+            }
 
             // `Target` events
             CdpEvent::TargetAttachedToTarget(ev) => {
@@ -304,11 +345,14 @@ impl Target {
         });
     }
 
-    /// Called when a init command timed out
-    fn on_initialization_failed(&mut self) -> TargetEvent {
-        if let Some(initiator) = self.initiator.take() {
-            let _ = initiator.send(Err(CdpError::Timeout));
-        }
+    /// Called when a init command timed out or failed
+    fn on_initialization_failed(&mut self, err: CdpError) -> TargetEvent {
+        tracing::error!(
+            "Failed to initialize target {:?}: {}",
+            self.target_id(),
+            err
+        );
+        self.on_initiators_failed(err);
         self.init_state = TargetInit::Closing;
         let close_target = CloseTargetParams::new(self.info.target_id.clone());
         TargetEvent::Request(Request {
@@ -318,14 +362,39 @@ impl Target {
         })
     }
 
+    /// Resolves `tx` once the main frame received the lifecycle `event`
+    fn wait_for_lifecycle_event(
+        frame_manager: &FrameManager,
+        waiting: &mut Vec<(LifecycleEvent, Sender<ArcHttpRequest>)>,
+        event: LifecycleEvent,
+        tx: Sender<ArcHttpRequest>,
+    ) {
+        match frame_manager.main_frame() {
+            Some(frame) if frame.has_lifecycle_event(event) => {
+                let _ = tx.send(frame.http_request().cloned());
+            }
+            _ => waiting.push((event, tx)),
+        }
+    }
+
     /// Advance that target's state
     pub(crate) fn poll(&mut self, cx: &mut Context<'_>, now: Instant) -> Option<TargetEvent> {
         if !self.is_page() {
             // can only poll pages
             return None;
         }
+        if let Some(cmds) = self.init_state.commands_mut() {
+            cmds.set_retries(self.config.init_retries);
+            if let Some(err) = self.init_error.take() {
+                return Some(self.on_initialization_failed(err));
+            }
+            if self.init_deadline.map(|d| now > d).unwrap_or_default() {
+                return Some(self.on_initialization_failed(CdpError::Timeout));
+            }
+        }
         match &mut self.init_state {
             TargetInit::AttachToTarget => {
+                self.init_deadline = Some(now + self.config.init_timeout);
                 self.init_state = TargetInit::InitializingFrame(FrameManager::init_commands(
                     self.config.request_timeout,
                 ));
@@ -346,7 +415,7 @@ impl Target {
                     return match poll {
                         None => {
                             if let Some(isolated_world_cmds) =
-                                self.frame_manager.ensure_isolated_world(UTILITY_WORLD_NAME)
+                                self.frame_manager.ensure_utility_world()
                             {
                                 *cmds = isolated_world_cmds;
                             } else {
@@ -361,7 +430,7 @@ impl Target {
                             session_id: self.session_id.clone().map(Into::into),
                             params,
                         })),
-                        Some(Err(_)) => Some(self.on_initialization_failed()),
+                        Some(Err(_)) => Some(self.on_initialization_failed(CdpError::Timeout)),
                     };
                 } else {
                     return None;
@@ -373,9 +442,7 @@ impl Target {
                     cx,
                     now,
                     cmds,
-                    TargetInit::InitializingPage(Self::page_init_commands(
-                        self.config.request_timeout
-                    ))
+                    TargetInit::InitializingPage(Self::page_init_commands(&self.config))
                 );
             }
             TargetInit::InitializingPage(cmds) => {
@@ -396,21 +463,26 @@ impl Target {
                 advance_state!(self, cx, now, cmds, TargetInit::Initialized);
             }
             TargetInit::Initialized => {
-                if let Some(initiator) = self.initiator.take() {
+                if !self.initiators.is_empty() {
                     // make sure that the main frame of the page has finished loading
-                    if self
+                    let loaded = self
                         .frame_manager
                         .main_frame()
                         .map(|frame| frame.is_loaded())
-                        .unwrap_or_default()
-                    {
+                        .unwrap_or_default();
+                    let deadline = *self
+                        .load_deadline
+                        .get_or_insert(now + self.config.navigation_timeout);
+                    if loaded {
                         if let Some(page) = self.get_or_create_page() {
-                            let _ = initiator.send(Ok(page.clone().into()));
-                        } else {
-                            self.initiator = Some(initiator);
+                            let page = Page::from(page.clone());
+                            for initiator in self.initiators.drain(..) {
+                                let _ = initiator.send(Ok(page.clone()));
+                            }
+                            self.load_deadline = None;
                         }
-                    } else {
-                        self.initiator = Some(initiator);
+                    } else if now > deadline {
+                        self.on_initiators_failed(CdpError::Timeout);
                     }
                 }
             }
@@ -418,9 +490,13 @@ impl Target {
         };
         loop {
             if let Some(frame) = self.frame_manager.main_frame() {
-                if frame.is_loaded() {
-                    while let Some(tx) = self.wait_for_frame_navigation.pop() {
-                        let _ = tx.send(frame.http_request().cloned());
+                if !self.wait_for_frame_navigation.is_empty() {
+                    for (event, tx) in std::mem::take(&mut self.wait_for_frame_navigation) {
+                        if frame.has_lifecycle_event(event) {
+                            let _ = tx.send(frame.http_request().cloned());
+                        } else {
+                            self.wait_for_frame_navigation.push((event, tx));
+                        }
                     }
                 }
             }
@@ -448,6 +524,15 @@ impl Target {
                                     .collect(),
                             );
                         }
+                        TargetMessage::FrameTree(tx) => {
+                            let _ = tx.send(self.frame_manager.frame_tree());
+                        }
+                        TargetMessage::Type(tx) => {
+                            let _ = tx.send(self.r#type.clone());
+                        }
+                        TargetMessage::Opener(tx) => {
+                            let _ = tx.send(self.info.opener_id.clone());
+                        }
                         TargetMessage::Url(req) => {
                             let GetUrl { frame_id, tx } = req;
                             let frame = if let Some(frame_id) = frame_id {
@@ -472,18 +557,20 @@ impl Target {
                             let _ = tx.send(frame.and_then(|f| f.parent_id().cloned()));
                         }
                         TargetMessage::WaitForNavigation(tx) => {
-                            if let Some(frame) = self.frame_manager.main_frame() {
-                                // TODO submit a navigation watcher: waitForFrameNavigation
-
-                                // TODO return the watchers navigationResponse
-                                if frame.is_loaded() {
-                                    let _ = tx.send(frame.http_request().cloned());
-                                } else {
-                                    self.wait_for_frame_navigation.push(tx);
-                                }
-                            } else {
-                                self.wait_for_frame_navigation.push(tx);
-                            }
+                            Self::wait_for_lifecycle_event(
+                                &self.frame_manager,
+                                &mut self.wait_for_frame_navigation,
+                                LifecycleEvent::Load,
+                                tx,
+                            );
+                        }
+                        TargetMessage::WaitForLifecycleEvent(event, tx) => {
+                            Self::wait_for_lifecycle_event(
+                                &self.frame_manager,
+                                &mut self.wait_for_frame_navigation,
+                                event,
+                                tx,
+                            );
                         }
                         TargetMessage::AddEventListener(req) => {
                             // register a new listener
@@ -518,6 +605,22 @@ impl Target {
                         TargetMessage::Authenticate(credentials) => {
                             self.network_manager.authenticate(credentials);
                         }
+                        TargetMessage::SetCacheEnabled(enabled) => {
+                            self.network_manager.set_cache_enabled(enabled);
+                        }
+                        TargetMessage::SetRequestInterception(enabled) => {
+                            self.network_manager.set_request_interception(enabled);
+                        }
+                        TargetMessage::SetRequestInterceptionPatterns(patterns) => {
+                            self.network_manager
+                                .set_request_interception_patterns(true, patterns);
+                        }
+                        TargetMessage::SetNavigationTimeout(timeout) => {
+                            self.frame_manager.set_navigation_timeout(timeout);
+                        }
+                        TargetMessage::WaitForClose(tx) => {
+                            self.wait_for_close.push(tx);
+                        }
                     }
                 }
             }
@@ -562,13 +665,37 @@ impl Target {
         }
     }
 
-    /// Set the sender half of the channel who requested the creation of this
-    /// target
-    pub fn set_initiator(&mut self, tx: Sender<Result<Page>>) {
-        self.initiator = Some(tx);
+    /// Add the sender half of a channel who requested the creation of or the
+    /// attachment to this target, which receives the page once initialized
+    pub fn add_initiator(&mut self, tx: Sender<Result<Page>>) {
+        self.initiators.push(tx);
+    }
+
+    /// Sends the `err` to all initiators waiting for the page
+    fn on_initiators_failed(&mut self, err: CdpError) {
+        self.load_deadline = None;
+        let mut initiators = self.initiators.drain(..);
+        if let Some(initiator) = initiators.next() {
+            let msg = err.to_string();
+            let _ = initiator.send(Err(err));
+            for initiator in initiators {
+                let _ = initiator.send(Err(CdpError::msg(msg.clone())));
+            }
+        }
+    }
+
+    /// Marks the target's page as closed and notifies all senders waiting for
+    /// it, called once the target was destroyed.
+    pub(crate) fn on_destroyed(&mut self) {
+        if let Some(page) = self.page.as_ref() {
+            page.inner().set_closed();
+        }
+        for tx in self.wait_for_close.drain(..) {
+            let _ = tx.send(());
+        }
     }
 
-    pub(crate) fn page_init_commands(timeout: Duration) -> CommandChain {
+    pub(crate) fn page_init_commands(config: &TargetConfig) -> CommandChain {
         let attach = SetAutoAttachParams::builder()
             .flatten(true)
             .auto_attach(true)
@@ -577,20 +704,33 @@ impl Target {
             .unwrap();
         let enable_performance = performance::EnableParams::default();
         let enable_log = cdplog::EnableParams::default();
-        CommandChain::new(
-            vec![
-                (attach.identifier(), serde_json::to_value(attach).unwrap()),
-                (
-                    enable_performance.identifier(),
-                    serde_json::to_value(enable_performance).unwrap(),
-                ),
-                (
-                    enable_log.identifier(),
-                    serde_json::to_value(enable_log).unwrap(),
-                ),
-            ],
-            timeout,
-        )
+        let mut cmds = vec![
+            (attach.identifier(), serde_json::to_value(attach).unwrap()),
+            (
+                enable_performance.identifier(),
+                serde_json::to_value(enable_performance).unwrap(),
+            ),
+            (
+                enable_log.identifier(),
+                serde_json::to_value(enable_log).unwrap(),
+            ),
+        ];
+        for patch in &config.stealth_patches {
+            let script = AddScriptToEvaluateOnNewDocumentParams::new(patch.script());
+            cmds.push((script.identifier(), serde_json::to_value(script).unwrap()));
+        }
+        for script in &config.init_scripts {
+            let script = AddScriptToEvaluateOnNewDocumentParams::new(script.clone());
+            cmds.push((script.identifier(), serde_json::to_value(script).unwrap()));
+        }
+        if !config.javascript_enabled {
+            let disable_js = SetScriptExecutionDisabledParams::new(true);
+            cmds.push((
+                disable_js.identifier(),
+                serde_json::to_value(disable_js).unwrap(),
+            ));
+        }
+        CommandChain::new(cmds, config.request_timeout)
     }
 }
 
@@ -599,9 +739,22 @@ pub struct TargetConfig {
     pub ignore_https_errors: bool,
     ///  Request timeout to use
     pub request_timeout: Duration,
+    /// Timeout of navigations
+    pub navigation_timeout: Duration,
     pub viewport: Option<Viewport>,
     pub request_intercept: bool,
     pub cache_enabled: bool,
+    pub javascript_enabled: bool,
+    /// Scripts to evaluate in every new document of the page
+    pub stealth_patches: Vec<StealthPatch>,
+    /// Additional scripts to evaluate in every new document of the page
+    pub init_scripts: Vec<String>,
+    /// The name of the isolated world used for the secondary `DOMWorld`
+    pub utility_world_name: String,
+    /// Timeout of the whole initialization of the target
+    pub init_timeout: Duration,
+    /// How often an init command without a response is reissued
+    pub init_retries: u32,
 }
 
 impl Default for TargetConfig {
@@ -609,13 +762,52 @@ impl Default for TargetConfig {
         Self {
             ignore_https_errors: true,
             request_timeout: Duration::from_secs(REQUEST_TIMEOUT),
+            navigation_timeout: Duration::from_millis(REQUEST_TIMEOUT),
             viewport: Default::default(),
             request_intercept: false,
             cache_enabled: true,
+            javascript_enabled: true,
+            stealth_patches: Vec::new(),
+            init_scripts: Vec::new(),
+            utility_world_name: UTILITY_WORLD_NAME.to_string(),
+            init_timeout: Duration::from_millis(REQUEST_TIMEOUT),
+            init_retries: 0,
         }
     }
 }
 
+/// Selects page targets by their browser context, see
+/// `Browser::pages_filtered`.
+///
+/// Only targets of type `TargetType::Page` are attached and initialized as
+/// pages, so other targets like service workers or extension background
+/// pages never match. Service workers are available with
+/// `Browser::service_workers`.
+///
+/// The default filter matches the pages of any browser context.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TargetFilter {
+    /// The browser context the targets must belong to, any if `None`
+    browser_context_id: Option<BrowserContextId>,
+}
+
+impl TargetFilter {
+    /// Only match targets that belong to the given browser context
+    pub fn browser_context(mut self, id: impl Into<BrowserContextId>) -> Self {
+        self.browser_context_id = Some(id.into());
+        self
+    }
+
+    /// Whether the target satisfies this filter
+    pub fn matches(&self, target: &Target) -> bool {
+        target.is_page()
+            && self
+                .browser_context_id
+                .as_ref()
+                .map_or(true, |id| target.browser_context_id() == Some(id))
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum TargetType {
     Page,
@@ -767,6 +959,12 @@ pub enum TargetMessage {
     MainFrame(Sender<Option<FrameId>>),
     /// Return all the frames of this target's page
     AllFrames(Sender<Vec<FrameId>>),
+    /// Return the hierarchy of the frames of this target's page
+    FrameTree(Sender<Option<FrameNode>>),
+    /// Return the type of this target
+    Type(Sender<TargetType>),
+    /// Return the id of the target that opened this target, if any
+    Opener(Sender<Option<TargetId>>),
     /// Return the url if available
     Url(GetUrl),
     /// Return the name if available
@@ -775,10 +973,122 @@ pub enum TargetMessage {
     Parent(GetParent),
     /// A Message that resolves when the frame finished loading a new url
     WaitForNavigation(Sender<ArcHttpRequest>),
+    /// A Message that resolves when the main frame received the lifecycle
+    /// event
+    WaitForLifecycleEvent(LifecycleEvent, Sender<ArcHttpRequest>),
     /// A request to submit a new listener that gets notified with every
     /// received event
     AddEventListener(EventListenerRequest),
     /// Get the `ExecutionContext` if available
     GetExecutionContext(GetExecutionContext),
     Authenticate(Credentials),
+    /// Enable or disable the browser cache for this target's page
+    SetCacheEnabled(bool),
+    /// Enable or disable request interception for this target's page
+    SetRequestInterception(bool),
+    /// Enable the request interception for requests matching the patterns
+    SetRequestInterceptionPatterns(Vec<RequestPattern>),
+    /// Set the timeout of all following navigations of this target's page
+    SetNavigationTimeout(Duration),
+    /// A Message that resolves when the target was destroyed
+    WaitForClose(Sender<()>),
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::channel::oneshot;
+    use futures::task::noop_waker_ref;
+    use serde_json::json;
+
+    use super::*;
+
+    fn target(ty: &str, context: &str) -> Target {
+        let info = TargetInfo::builder()
+            .target_id(format!("{ty}-{context}"))
+            .r#type(ty)
+            .title("")
+            .url("about:blank")
+            .attached(false)
+            .can_access_opener(false)
+            .browser_context_id(context.to_string())
+            .build()
+            .unwrap();
+        Target::new(info, TargetConfig::default(), BrowserContext::default())
+    }
+
+    #[test]
+    fn filter_matches_only_pages() {
+        let filter = TargetFilter::default();
+        assert!(filter.matches(&target("page", "a")));
+        assert!(filter.matches(&target("page", "b")));
+        assert!(!filter.matches(&target("service_worker", "a")));
+        assert!(!filter.matches(&target("shared_worker", "a")));
+        assert!(!filter.matches(&target("background_page", "a")));
+        assert!(!filter.matches(&target("other", "a")));
+    }
+
+    #[test]
+    fn filter_matches_browser_context() {
+        let filter = TargetFilter::default().browser_context("a".to_string());
+        assert!(filter.matches(&target("page", "a")));
+        assert!(!filter.matches(&target("page", "b")));
+        assert!(!filter.matches(&target("service_worker", "a")));
+    }
+
+    fn event(params: CdpEvent) -> CdpEventMessage {
+        let mut event = CdpEventMessage {
+            method: Default::default(),
+            session_id: None,
+            params,
+        };
+        event.method = event.identifier();
+        event
+    }
+
+    #[test]
+    fn stopped_loading_resolves_navigation() {
+        let mut target = target("page", "a");
+        target.init_state = TargetInit::Initialized;
+        let navigated = serde_json::from_value(json!({
+            "frame": {
+                "id": "main",
+                "loaderId": "loader",
+                "url": "about:blank",
+                "domainAndRegistry": "",
+                "securityOrigin": "://",
+                "mimeType": "text/html",
+                "secureContextType": "Secure",
+                "crossOriginIsolatedContextType": "NotIsolated",
+                "gatedAPIFeatures": []
+            },
+            "type": "Navigation"
+        }))
+        .unwrap();
+        target.on_event(event(CdpEvent::PageFrameNavigated(Box::new(navigated))));
+        target.on_event(event(CdpEvent::PageFrameStartedLoading(
+            serde_json::from_value(json!({ "frameId": "main" })).unwrap(),
+        )));
+
+        let (tx, mut rx) = oneshot::channel();
+        Target::wait_for_lifecycle_event(
+            &target.frame_manager,
+            &mut target.wait_for_frame_navigation,
+            LifecycleEvent::Load,
+            tx,
+        );
+        assert!(!target.frame_manager.main_frame().unwrap().is_loaded());
+        assert!(matches!(rx.try_recv(), Ok(None)));
+
+        target.on_event(event(CdpEvent::PageFrameStoppedLoading(
+            serde_json::from_value(json!({ "frameId": "main" })).unwrap(),
+        )));
+        let frame = target.frame_manager.main_frame().unwrap();
+        assert!(frame.is_loaded());
+        assert!(frame.has_lifecycle_event(LifecycleEvent::DomcontentLoaded));
+
+        let mut cx = Context::from_waker(noop_waker_ref());
+        target.poll(&mut cx, Instant::now());
+        assert!(target.wait_for_frame_navigation.is_empty());
+        assert!(matches!(rx.try_recv(), Ok(Some(None))));
+    }
 }
@@ -7,6 +7,7 @@ use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+use crate::handler::frame::LifecycleEvent;
 use crate::handler::target::TargetMessage;
 use crate::{error::Result, ArcHttpRequest};
 
@@ -43,6 +44,17 @@ impl<T> TargetMessageFuture<T> {
 
         TargetMessageFuture::new(target_sender, message, rx_request)
     }
+
+    pub fn wait_for_lifecycle_event(
+        target_sender: TargetSender,
+        event: LifecycleEvent,
+    ) -> TargetMessageFuture<ArcHttpRequest> {
+        let (tx, rx_request) = oneshot_channel();
+
+        let message = TargetMessage::WaitForLifecycleEvent(event, tx);
+
+        TargetMessageFuture::new(target_sender, message, rx_request)
+    }
 }
 
 impl<T> Future for TargetMessageFuture<T> {
@@ -1,3 +1,7 @@
+use chromiumoxide_cdp::cdp::browser_protocol::emulation::{
+    ScreenOrientation, ScreenOrientationType,
+};
+
 #[derive(Debug, Clone)]
 pub struct Viewport {
     pub width: u32,
@@ -8,6 +12,17 @@ pub struct Viewport {
     pub has_touch: bool,
 }
 
+impl Viewport {
+    /// The primary screen orientation of `is_landscape` to emulate
+    pub fn screen_orientation(&self) -> ScreenOrientation {
+        if self.is_landscape {
+            ScreenOrientation::new(ScreenOrientationType::LandscapePrimary, 90)
+        } else {
+            ScreenOrientation::new(ScreenOrientationType::PortraitPrimary, 0)
+        }
+    }
+}
+
 impl Default for Viewport {
     fn default() -> Self {
         Viewport {
@@ -0,0 +1,525 @@
+//! The [HAR 1.2](http://www.softwareishard.com/blog/har-12-spec/) format to
+//! export recorded network traffic, see `Page::export_har`.
+
+use serde::{Deserialize, Serialize};
+
+use chromiumoxide_cdp::cdp::browser_protocol::network::{Headers, ResourceTiming};
+
+use crate::recorder::RecordedResponse;
+
+/// The root of a HAR document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Har {
+    /// The exported traffic
+    pub log: HarLog,
+}
+
+impl Har {
+    /// Creates a HAR document with an entry for every recorded response whose
+    /// request was recorded as well.
+    pub fn from_recorded(responses: &[RecordedResponse]) -> Self {
+        Self {
+            log: HarLog {
+                version: "1.2".to_string(),
+                creator: HarCreator {
+                    name: env!("CARGO_PKG_NAME").to_string(),
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                },
+                entries: responses.iter().filter_map(HarEntry::new).collect(),
+            },
+        }
+    }
+}
+
+/// The exported traffic and the application that exported it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HarLog {
+    /// The version of the HAR format, `1.2`
+    pub version: String,
+    pub creator: HarCreator,
+    /// The exported requests, in the order they were recorded
+    pub entries: Vec<HarEntry>,
+}
+
+/// The name and version of the application that created the HAR document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HarCreator {
+    pub name: String,
+    pub version: String,
+}
+
+/// A single request and its response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarEntry {
+    /// When the request was sent, in ISO 8601 format
+    pub started_date_time: String,
+    /// The total time of the request in milliseconds
+    pub time: f64,
+    pub request: HarRequest,
+    pub response: HarResponse,
+    pub cache: HarCache,
+    pub timings: HarTimings,
+    #[serde(rename = "serverIPAddress", skip_serializing_if = "Option::is_none")]
+    pub server_ip_address: Option<String>,
+}
+
+impl HarEntry {
+    fn new(recorded: &RecordedResponse) -> Option<Self> {
+        let request = recorded.request.as_ref()?;
+        let response = &recorded.response.response;
+        let http_version = http_version(response.protocol.as_deref());
+
+        let timings = HarTimings::new(
+            response.timing.as_ref(),
+            *request.timestamp.inner(),
+            *recorded.response.timestamp.inner(),
+            *recorded.finished.timestamp.inner(),
+        );
+        let time = [
+            timings.blocked,
+            timings.dns,
+            timings.connect,
+            timings.send,
+            timings.wait,
+            timings.receive,
+        ]
+        .iter()
+        .filter(|t| **t > 0.)
+        .sum();
+
+        let query_string = url::Url::parse(&request.request.url)
+            .map(|url| {
+                url.query_pairs()
+                    .map(|(name, value)| HarNameValue {
+                        name: name.into_owned(),
+                        value: value.into_owned(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let post_data = request.request.post_data.as_ref().map(|text| HarPostData {
+            mime_type: header(&request.request.headers, "content-type").unwrap_or_default(),
+            text: text.clone(),
+        });
+
+        let content = match recorded.body.as_deref() {
+            Some(body) => match std::str::from_utf8(body) {
+                Ok(text) => HarContent {
+                    size: body.len() as i64,
+                    mime_type: response.mime_type.clone(),
+                    text: Some(text.to_string()),
+                    encoding: None,
+                },
+                Err(_) => HarContent {
+                    size: body.len() as i64,
+                    mime_type: response.mime_type.clone(),
+                    text: Some(crate::utils::base64::encode(body)),
+                    encoding: Some("base64".to_string()),
+                },
+            },
+            None => HarContent {
+                size: 0,
+                mime_type: response.mime_type.clone(),
+                text: None,
+                encoding: None,
+            },
+        };
+
+        Some(Self {
+            started_date_time: iso_8601(*request.wall_time.inner()),
+            time,
+            request: HarRequest {
+                method: request.request.method.clone(),
+                url: request.request.url.clone(),
+                http_version: http_version.clone(),
+                cookies: Vec::new(),
+                headers: headers(
+                    response
+                        .request_headers
+                        .as_ref()
+                        .unwrap_or(&request.request.headers),
+                ),
+                query_string,
+                body_size: post_data
+                    .as_ref()
+                    .map(|data| data.text.len() as i64)
+                    .unwrap_or_default(),
+                post_data,
+                headers_size: -1,
+            },
+            response: HarResponse {
+                status: response.status,
+                status_text: response.status_text.clone(),
+                http_version,
+                cookies: Vec::new(),
+                headers: headers(&response.headers),
+                content,
+                redirect_url: header(&response.headers, "location").unwrap_or_default(),
+                headers_size: -1,
+                body_size: -1,
+            },
+            cache: HarCache {},
+            timings,
+            server_ip_address: response.remote_ip_address.clone(),
+        })
+    }
+}
+
+/// The request of an entry, as it was sent
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarRequest {
+    pub method: String,
+    pub url: String,
+    pub http_version: String,
+    pub cookies: Vec<HarNameValue>,
+    pub headers: Vec<HarNameValue>,
+    pub query_string: Vec<HarNameValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_data: Option<HarPostData>,
+    pub headers_size: i64,
+    pub body_size: i64,
+}
+
+/// The response of an entry, sizes are `-1` if unknown
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarResponse {
+    pub status: i64,
+    pub status_text: String,
+    pub http_version: String,
+    pub cookies: Vec<HarNameValue>,
+    pub headers: Vec<HarNameValue>,
+    pub content: HarContent,
+    #[serde(rename = "redirectURL")]
+    pub redirect_url: String,
+    pub headers_size: i64,
+    pub body_size: i64,
+}
+
+/// A header, query parameter or cookie
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HarNameValue {
+    pub name: String,
+    pub value: String,
+}
+
+/// The body of a request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarPostData {
+    pub mime_type: String,
+    pub text: String,
+}
+
+/// The body of a response, binary bodies are base64 encoded
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarContent {
+    /// The length of the body in bytes, `0` if it wasn't retained
+    pub size: i64,
+    pub mime_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+}
+
+/// Information about the cache usage of an entry, which is not recorded
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HarCache {}
+
+/// The phases of a request in milliseconds, `-1` if a phase doesn't apply
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HarTimings {
+    pub blocked: f64,
+    pub dns: f64,
+    pub connect: f64,
+    pub send: f64,
+    pub wait: f64,
+    pub receive: f64,
+    pub ssl: f64,
+}
+
+impl HarTimings {
+    /// Derives the timings from the `ResourceTiming` of the response and the
+    /// monotonic timestamps, in seconds, of the request, the response and
+    /// the finished loading.
+    fn new(timing: Option<&ResourceTiming>, sent: f64, received: f64, finished: f64) -> Self {
+        match timing {
+            Some(timing) => {
+                let phase = |start: f64, end: f64| if start < 0. { -1. } else { end - start };
+                let blocked = [timing.dns_start, timing.connect_start, timing.send_start]
+                    .into_iter()
+                    .find(|t| *t >= 0.)
+                    .unwrap_or(-1.);
+                Self {
+                    blocked,
+                    dns: phase(timing.dns_start, timing.dns_end),
+                    connect: phase(timing.connect_start, timing.connect_end),
+                    send: timing.send_end - timing.send_start,
+                    wait: timing.receive_headers_end - timing.send_end,
+                    receive: ((finished - timing.request_time) * 1000.
+                        - timing.receive_headers_end)
+                        .max(0.),
+                    ssl: phase(timing.ssl_start, timing.ssl_end),
+                }
+            }
+            // e.g. responses served from the memory cache
+            None => Self {
+                blocked: -1.,
+                dns: -1.,
+                connect: -1.,
+                send: 0.,
+                wait: ((received - sent) * 1000.).max(0.),
+                receive: ((finished - received) * 1000.).max(0.),
+                ssl: -1.,
+            },
+        }
+    }
+}
+
+fn headers(headers: &Headers) -> Vec<HarNameValue> {
+    headers
+        .inner()
+        .as_object()
+        .map(|headers| {
+            headers
+                .iter()
+                .map(|(name, value)| HarNameValue {
+                    name: name.clone(),
+                    value: value.as_str().unwrap_or_default().to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn header(headers: &Headers, name: &str) -> Option<String> {
+    headers.inner().as_object().and_then(|headers| {
+        headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .and_then(|(_, value)| value.as_str())
+            .map(str::to_string)
+    })
+}
+
+/// The HAR http version of the protocol reported by chromium
+fn http_version(protocol: Option<&str>) -> String {
+    match protocol {
+        Some("h2") => "HTTP/2".to_string(),
+        Some("h3") | Some("h3-29") => "HTTP/3".to_string(),
+        Some(protocol) => protocol.to_uppercase(),
+        None => String::new(),
+    }
+}
+
+/// Formats the seconds since the unix epoch as ISO 8601 date time in UTC
+fn iso_8601(secs: f64) -> String {
+    let millis = (secs * 1000.).round() as i64;
+    let days = millis.div_euclid(86_400_000);
+    let millis_of_day = millis.rem_euclid(86_400_000);
+
+    // civil date from days since epoch, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        millis_of_day / 3_600_000,
+        millis_of_day / 60_000 % 60,
+        millis_of_day / 1000 % 60,
+        millis_of_day % 1000
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn iso_8601_dates() {
+        assert_eq!(iso_8601(0.), "1970-01-01T00:00:00.000Z");
+        assert_eq!(iso_8601(1_582_977_600.25), "2020-02-29T12:00:00.250Z");
+        assert_eq!(iso_8601(951_782_400.), "2000-02-29T00:00:00.000Z");
+        assert_eq!(iso_8601(-1.5), "1969-12-31T23:59:58.500Z");
+        assert_eq!(iso_8601(-86_400. * 366.), "1968-12-31T00:00:00.000Z");
+    }
+
+    #[test]
+    fn timings_without_resource_timing() {
+        let timings = HarTimings::new(None, 10., 10.25, 10.5);
+        assert_eq!(
+            timings,
+            HarTimings {
+                blocked: -1.,
+                dns: -1.,
+                connect: -1.,
+                send: 0.,
+                wait: 250.,
+                receive: 250.,
+                ssl: -1.,
+            }
+        );
+    }
+
+    fn recorded(timing: Option<serde_json::Value>) -> RecordedResponse {
+        let request = json!({
+            "requestId": "1",
+            "loaderId": "1",
+            "documentURL": "https://example.com/",
+            "request": {
+                "url": "https://example.com/search?q=rust+cdp&page=2",
+                "method": "POST",
+                "headers": { "Content-Type": "application/x-www-form-urlencoded" },
+                "postData": "a=1",
+                "initialPriority": "High",
+                "referrerPolicy": "no-referrer"
+            },
+            "timestamp": 10.0,
+            "wallTime": 1_582_977_600.0,
+            "initiator": { "type": "other" },
+            "redirectHasExtraInfo": false
+        });
+        let mut response = json!({
+            "url": "https://example.com/search?q=rust+cdp&page=2",
+            "status": 302,
+            "statusText": "Found",
+            "headers": { "Location": "/results", "Content-Type": "text/plain" },
+            "mimeType": "text/plain",
+            "connectionReused": false,
+            "connectionId": 1.0,
+            "encodedDataLength": 2.0,
+            "securityState": "secure",
+            "protocol": "h2"
+        });
+        if let Some(timing) = timing {
+            response["timing"] = timing;
+        }
+        let response = json!({
+            "requestId": "1",
+            "loaderId": "1",
+            "timestamp": 10.25,
+            "type": "Document",
+            "response": response,
+            "hasExtraInfo": false
+        });
+        let finished = json!({
+            "requestId": "1",
+            "timestamp": 10.5,
+            "encodedDataLength": 2.0,
+            "shouldReportCorbBlocking": false
+        });
+        RecordedResponse {
+            request: Some(Arc::new(serde_json::from_value(request).unwrap())),
+            response: Arc::new(serde_json::from_value(response).unwrap()),
+            finished: Arc::new(serde_json::from_value(finished).unwrap()),
+            body: Some(b"ok".to_vec()),
+        }
+    }
+
+    fn name_value(name: &str, value: &str) -> HarNameValue {
+        HarNameValue {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn entry_from_recorded_response() {
+        let entry = HarEntry::new(&recorded(None)).unwrap();
+        assert_eq!(entry.started_date_time, "2020-02-29T12:00:00.000Z");
+        assert_eq!(entry.time, 500.);
+
+        assert_eq!(entry.request.http_version, "HTTP/2");
+        assert_eq!(
+            entry.request.query_string,
+            vec![name_value("q", "rust cdp"), name_value("page", "2")]
+        );
+        assert_eq!(
+            entry.request.headers,
+            vec![name_value(
+                "Content-Type",
+                "application/x-www-form-urlencoded"
+            )]
+        );
+        assert_eq!(
+            entry.request.post_data,
+            Some(HarPostData {
+                mime_type: "application/x-www-form-urlencoded".to_string(),
+                text: "a=1".to_string(),
+            })
+        );
+        assert_eq!(entry.request.body_size, 3);
+
+        assert_eq!(entry.response.redirect_url, "/results");
+        assert!(entry
+            .response
+            .headers
+            .contains(&name_value("Location", "/results")));
+        assert_eq!(entry.response.content.text.as_deref(), Some("ok"));
+        assert_eq!(entry.response.content.size, 2);
+    }
+
+    #[test]
+    fn entry_with_resource_timing() {
+        let timing = json!({
+            "requestTime": 10.0,
+            "proxyStart": -1.0,
+            "proxyEnd": -1.0,
+            "dnsStart": 1.0,
+            "dnsEnd": 3.0,
+            "connectStart": 3.0,
+            "connectEnd": 13.0,
+            "sslStart": 5.0,
+            "sslEnd": 13.0,
+            "workerStart": -1.0,
+            "workerReady": -1.0,
+            "workerFetchStart": -1.0,
+            "workerRespondWithSettled": -1.0,
+            "sendStart": 14.0,
+            "sendEnd": 15.0,
+            "pushStart": 0.0,
+            "pushEnd": 0.0,
+            "receiveHeadersEnd": 115.0
+        });
+        let entry = HarEntry::new(&recorded(Some(timing))).unwrap();
+        assert_eq!(
+            entry.timings,
+            HarTimings {
+                blocked: 1.,
+                dns: 2.,
+                connect: 10.,
+                send: 1.,
+                wait: 100.,
+                receive: 385.,
+                ssl: 8.,
+            }
+        );
+        assert_eq!(entry.time, 499.);
+    }
+
+    #[test]
+    fn entry_requires_request() {
+        let mut recorded = recorded(None);
+        recorded.request = None;
+        assert!(HarEntry::new(&recorded).is_none());
+    }
+}
@@ -1,9 +1,14 @@
-use serde::de::DeserializeOwned;
+use std::sync::Arc;
+
+use serde::de::{DeserializeOwned, IntoDeserializer};
 
 use chromiumoxide_cdp::cdp::js_protocol::runtime::{
-    CallFunctionOnParams, EvaluateParams, RemoteObject,
+    CallFunctionOnParams, CallFunctionOnReturns, EvaluateParams, ExceptionDetails,
+    ReleaseObjectParams, RemoteObject, RemoteObjectId, RemoteObjectSubtype, RemoteObjectType,
 };
 
+use crate::error::{CdpError, Result};
+use crate::handler::PageInner;
 use crate::utils::is_likely_js_function;
 
 #[derive(Debug, Clone)]
@@ -17,6 +22,18 @@ impl EvaluationResult {
         Self { inner }
     }
 
+    /// The result of a `Runtime.evaluate` or `Runtime.callFunctionOn`, or
+    /// `CdpError::JavascriptException` if the evaluation threw
+    pub(crate) fn from_response(
+        result: RemoteObject,
+        exception: Option<ExceptionDetails>,
+    ) -> Result<Self> {
+        match exception {
+            Some(exception) => Err(CdpError::JavascriptException(Box::new(exception))),
+            None => Ok(Self::new(result)),
+        }
+    }
+
     pub fn object(&self) -> &RemoteObject {
         &self.inner
     }
@@ -25,13 +42,150 @@ impl EvaluationResult {
         self.object().value.as_ref()
     }
 
+    /// Whether the evaluation returned `undefined`
+    pub fn is_undefined(&self) -> bool {
+        self.inner.r#type == RemoteObjectType::Undefined
+    }
+
+    /// Whether the evaluation returned `null`
+    pub fn is_null(&self) -> bool {
+        self.inner.r#type == RemoteObjectType::Object
+            && self.inner.subtype == Some(RemoteObjectSubtype::Null)
+    }
+
+    /// The value of a returned `BigInt`, if it fits into an `i128`.
+    ///
+    /// The textual form of larger values is available as the object's
+    /// `unserializable_value`.
+    pub fn as_bigint(&self) -> Option<i128> {
+        self.inner
+            .unserializable_value
+            .as_ref()?
+            .as_ref()
+            .strip_suffix('n')?
+            .parse()
+            .ok()
+    }
+
     /// Attempts to deserialize the value into the given type
+    ///
+    /// A `null` value is deserialized as JSON `null`, so it can be
+    /// deserialized into an `Option`. Values that can't be represented as
+    /// JSON (`BigInt`, `NaN`, `Infinity`, `-Infinity` and `-0`) are
+    /// deserialized as numbers.
     pub fn into_value<T: DeserializeOwned>(self) -> serde_json::Result<T> {
-        let value = self
-            .inner
-            .value
-            .ok_or_else(|| serde::de::Error::custom("No value found"))?;
-        serde_json::from_value(value)
+        if self.is_null() {
+            return serde_json::from_value(self.inner.value.unwrap_or_default());
+        }
+        if let Some(value) = self.inner.value {
+            return serde_json::from_value(value);
+        }
+        match self.inner.unserializable_value {
+            Some(value) => deserialize_unserializable(value.as_ref()),
+            None => Err(serde::de::Error::custom("No value found")),
+        }
+    }
+
+    /// Like [`EvaluationResult::into_value`], but returns `None` if the
+    /// evaluation returned `undefined`.
+    pub fn into_value_opt<T: DeserializeOwned>(self) -> serde_json::Result<Option<T>> {
+        if self.is_undefined() {
+            return Ok(None);
+        }
+        self.into_value().map(Some)
+    }
+}
+
+/// Deserializes the textual form of a javascript value that has no JSON
+/// representation, see `RemoteObject::unserializable_value`
+fn deserialize_unserializable<T: DeserializeOwned>(value: &str) -> serde_json::Result<T> {
+    let number = match value {
+        "NaN" => f64::NAN,
+        "Infinity" => f64::INFINITY,
+        "-Infinity" => f64::NEG_INFINITY,
+        "-0" => -0.0,
+        _ => {
+            // BigInt literals, like `42n`
+            return match value.strip_suffix('n') {
+                Some(digits) => serde_json::from_str(digits),
+                None => Err(serde::de::Error::custom(format!(
+                    "Unsupported unserializable value `{value}`"
+                ))),
+            };
+        }
+    };
+    T::deserialize(IntoDeserializer::<serde_json::Error>::into_deserializer(
+        number,
+    ))
+}
+
+/// A handle to a javascript value that lives in the page, like a DOM
+/// collection or a large object that is expensive to serialize.
+///
+/// See `Page::evaluate_function_handle`.
+#[derive(Debug)]
+pub struct JsHandle {
+    object: RemoteObject,
+    tab: Arc<PageInner>,
+}
+
+impl JsHandle {
+    pub(crate) fn new(tab: Arc<PageInner>, object: RemoteObject) -> Self {
+        Self { object, tab }
+    }
+
+    /// The remote object this handle refers to
+    pub fn object(&self) -> &RemoteObject {
+        &self.object
+    }
+
+    /// The identifier of the remote object, `None` for primitive values
+    pub fn object_id(&self) -> Option<&RemoteObjectId> {
+        self.object.object_id.as_ref()
+    }
+
+    /// Calls the function with `this` bound to the value of this handle
+    pub async fn call_js_fn(
+        &self,
+        function_declaration: impl Into<String>,
+        await_promise: bool,
+    ) -> Result<CallFunctionOnReturns> {
+        let object_id = self.object_id().cloned().ok_or(CdpError::NotFound)?;
+        self.tab
+            .call_js_fn(function_declaration, await_promise, object_id)
+            .await
+    }
+
+    /// Serializes the value of this handle and deserializes it into `T`
+    pub async fn json_value<T: DeserializeOwned>(&self) -> Result<T> {
+        let object_id = match self.object_id() {
+            Some(id) => id.clone(),
+            // primitive values are always returned by value
+            None => return Ok(EvaluationResult::new(self.object.clone()).into_value()?),
+        };
+        let resp = self
+            .tab
+            .execute(
+                CallFunctionOnParams::builder()
+                    .object_id(object_id)
+                    .function_declaration("function() { return this; }")
+                    .return_by_value(true)
+                    .build()
+                    .unwrap(),
+            )
+            .await?
+            .result;
+        Ok(EvaluationResult::from_response(resp.result, resp.exception_details)?.into_value()?)
+    }
+
+    /// Releases the remote object, so it can be garbage collected by the page
+    pub async fn release(self) -> Result<()> {
+        if let Some(object_id) = self.object.object_id {
+            self.tab
+                .execute(ReleaseObjectParams::new(object_id))
+                .await?;
+        }
+        Ok(())
     }
 }
 
@@ -41,6 +195,38 @@ pub enum Evaluation {
     Function(CallFunctionOnParams),
 }
 
+/// Awaits a returned promise and returns the result by value, unless the
+/// `params` configure otherwise
+pub(crate) fn evaluate_defaults(mut params: EvaluateParams) -> EvaluateParams {
+    if params.await_promise.is_none() {
+        params.await_promise = Some(true);
+    }
+    if params.return_by_value.is_none() {
+        params.return_by_value = Some(true);
+    }
+    params
+}
+
+/// Same as [`evaluate_defaults`] for a function call
+pub(crate) fn call_function_defaults(mut params: CallFunctionOnParams) -> CallFunctionOnParams {
+    if params.await_promise.is_none() {
+        params.await_promise = Some(true);
+    }
+    if params.return_by_value.is_none() {
+        params.return_by_value = Some(true);
+    }
+    params
+}
+
+/// The expression to call as function if evaluating `params` returned a
+/// function, see `EvaluateParams::eval_as_function_fallback`
+pub(crate) fn function_fallback(params: &EvaluateParams) -> Option<EvaluateParams> {
+    params
+        .eval_as_function_fallback
+        .filter(|fallback| *fallback)
+        .map(|_| params.clone())
+}
+
 impl From<&str> for Evaluation {
     fn from(expression: &str) -> Self {
         if is_likely_js_function(expression) {
@@ -68,3 +254,110 @@ impl From<CallFunctionOnParams> for Evaluation {
         Evaluation::Function(params)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn result(object: serde_json::Value) -> EvaluationResult {
+        EvaluationResult::new(serde_json::from_value(object).unwrap())
+    }
+
+    fn unserializable(ty: &str, value: &str) -> EvaluationResult {
+        result(json!({ "type": ty, "unserializableValue": value, "description": value }))
+    }
+
+    #[test]
+    fn deserializes_unserializable_numbers() {
+        assert!(deserialize_unserializable::<f64>("NaN").unwrap().is_nan());
+        assert_eq!(
+            deserialize_unserializable::<f64>("Infinity").unwrap(),
+            f64::INFINITY
+        );
+        assert_eq!(
+            deserialize_unserializable::<f64>("-Infinity").unwrap(),
+            f64::NEG_INFINITY
+        );
+        let zero = deserialize_unserializable::<f64>("-0").unwrap();
+        assert_eq!(zero, 0.);
+        assert!(zero.is_sign_negative());
+        assert_eq!(deserialize_unserializable::<i64>("42n").unwrap(), 42);
+        assert_eq!(
+            deserialize_unserializable::<u64>("18446744073709551615n").unwrap(),
+            u64::MAX
+        );
+        assert!(deserialize_unserializable::<f64>("undefined").is_err());
+        assert!(deserialize_unserializable::<i64>("NaN").is_err());
+    }
+
+    #[test]
+    fn unserializable_values_into_value() {
+        let nan: f64 = unserializable("number", "NaN").into_value().unwrap();
+        assert!(nan.is_nan());
+        let inf: f64 = unserializable("number", "-Infinity").into_value().unwrap();
+        assert_eq!(inf, f64::NEG_INFINITY);
+        let big: i64 = unserializable("bigint", "-9007199254740993n")
+            .into_value()
+            .unwrap();
+        assert_eq!(big, -9_007_199_254_740_993);
+    }
+
+    #[test]
+    fn bigint_values() {
+        assert_eq!(unserializable("bigint", "42n").as_bigint(), Some(42));
+        assert_eq!(unserializable("bigint", "-1n").as_bigint(), Some(-1));
+        assert_eq!(
+            unserializable("bigint", "170141183460469231731687303715884105727n").as_bigint(),
+            Some(i128::MAX)
+        );
+        // doesn't fit into an i128
+        assert_eq!(
+            unserializable("bigint", "170141183460469231731687303715884105728n").as_bigint(),
+            None
+        );
+        assert_eq!(unserializable("number", "Infinity").as_bigint(), None);
+        assert_eq!(
+            result(json!({ "type": "number", "value": 42 })).as_bigint(),
+            None
+        );
+    }
+
+    #[test]
+    fn undefined_and_null() {
+        let undefined = result(json!({ "type": "undefined" }));
+        assert!(undefined.is_undefined());
+        assert!(!undefined.is_null());
+        assert_eq!(undefined.into_value_opt::<String>().unwrap(), None);
+
+        let null = result(json!({ "type": "object", "subtype": "null", "value": null }));
+        assert!(null.is_null());
+        assert!(!null.is_undefined());
+        assert_eq!(
+            null.clone().into_value_opt::<Option<String>>().unwrap(),
+            Some(None)
+        );
+        assert!(null.into_value_opt::<String>().is_err());
+
+        let object = result(json!({ "type": "object", "value": { "a": 1 } }));
+        assert!(!object.is_null());
+        assert!(!object.is_undefined());
+    }
+
+    #[test]
+    fn into_value_opt_values() {
+        let string = result(json!({ "type": "string", "value": "chromium" }));
+        assert_eq!(
+            string.into_value_opt::<String>().unwrap(),
+            Some("chromium".to_string())
+        );
+        let number = result(json!({ "type": "number", "value": 1.5 }));
+        assert_eq!(number.into_value_opt::<f64>().unwrap(), Some(1.5));
+        let nan = unserializable("number", "NaN");
+        assert!(nan.into_value_opt::<f64>().unwrap().unwrap().is_nan());
+        // a function has no value
+        let function = result(json!({ "type": "function", "objectId": "1" }));
+        assert!(function.into_value_opt::<String>().is_err());
+    }
+}
@@ -68,7 +68,9 @@ use std::sync::Arc;
 pub use chromiumoxide_cdp::cdp;
 pub use chromiumoxide_types::{self as types, Binary, Command, Method, MethodType};
 
-pub use crate::browser::{Browser, BrowserConfig};
+pub use crate::browser::{
+    Browser, BrowserConfig, BrowserContextGuard, BrowserVersion, ConnectOptions,
+};
 pub use crate::conn::Connection;
 pub use crate::element::Element;
 pub use crate::error::Result;
@@ -76,6 +78,7 @@ pub use crate::error::Result;
 pub use crate::fetcher::{BrowserFetcher, BrowserFetcherOptions};
 pub use crate::handler::Handler;
 pub use crate::page::Page;
+pub use crate::worker::Worker;
 
 pub mod auth;
 pub mod browser;
@@ -90,11 +93,15 @@ pub mod fetcher {
 }
 pub mod async_process;
 pub mod handler;
+pub mod har;
 pub mod js;
 pub mod keys;
 pub mod layout;
 pub mod listeners;
 pub mod page;
+pub mod recorder;
+pub mod stealth;
 pub(crate) mod utils;
+pub mod worker;
 
 pub type ArcHttpRequest = Option<Arc<HttpRequest>>;
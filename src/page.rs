@@ -1,44 +1,96 @@
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
-use futures::channel::mpsc::unbounded;
-use futures::channel::oneshot::channel as oneshot_channel;
-use futures::{stream, SinkExt, StreamExt};
+use futures::channel::oneshot::{channel as oneshot_channel, Canceled};
+use futures::future::{self, Either};
+use futures::io::{AsyncWrite, AsyncWriteExt};
+use futures::{stream, Future, SinkExt, Stream, StreamExt};
+use serde::Deserialize;
 
+use chromiumoxide_cdp::cdp::browser_protocol::browser::{GrantPermissionsParams, PermissionType};
 use chromiumoxide_cdp::cdp::browser_protocol::dom::*;
 use chromiumoxide_cdp::cdp::browser_protocol::emulation::{
-    MediaFeature, SetEmulatedMediaParams, SetGeolocationOverrideParams, SetLocaleOverrideParams,
-    SetTimezoneOverrideParams,
+    MediaFeature, ScreenOrientation, SetDeviceMetricsOverrideParams, SetEmulatedMediaParams,
+    SetFocusEmulationEnabledParams, SetGeolocationOverrideParams, SetLocaleOverrideParams,
+    SetScriptExecutionDisabledParams, SetTimezoneOverrideParams, SetTouchEmulationEnabledParams,
+    UserAgentBrandVersion, UserAgentMetadata,
 };
+use chromiumoxide_cdp::cdp::browser_protocol::fetch::RequestPattern;
+use chromiumoxide_cdp::cdp::browser_protocol::input::{
+    DispatchDragEventParams, DispatchDragEventType, DispatchMouseEventParams,
+    DispatchMouseEventType, EventDragIntercepted, MouseButton, SetInterceptDragsParams,
+};
+use chromiumoxide_cdp::cdp::browser_protocol::io::{self as cdpio, ReadParams, StreamHandle};
+use chromiumoxide_cdp::cdp::browser_protocol::log::{EventEntryAdded, LogEntry};
 use chromiumoxide_cdp::cdp::browser_protocol::network::{
-    Cookie, CookieParam, DeleteCookiesParams, GetCookiesParams, SetCookiesParams,
-    SetUserAgentOverrideParams,
+    Cookie, CookieParam, CookieSameSite, DeleteCookiesParams, EmulateNetworkConditionsParams,
+    EventRequestWillBeSent, EventResponseReceived, GetCookiesParams, GetRequestPostDataParams,
+    GetResponseBodyParams, RequestId, ResourceType, SetCookiesParams, SetUserAgentOverrideParams,
 };
 use chromiumoxide_cdp::cdp::browser_protocol::page::*;
 use chromiumoxide_cdp::cdp::browser_protocol::performance::{GetMetricsParams, Metric};
-use chromiumoxide_cdp::cdp::browser_protocol::target::{SessionId, TargetId};
+use chromiumoxide_cdp::cdp::browser_protocol::security::{self, CertificateErrorAction};
+use chromiumoxide_cdp::cdp::browser_protocol::storage::{ClearDataForOriginParams, StorageType};
+use chromiumoxide_cdp::cdp::browser_protocol::target::{
+    CloseTargetParams, GetTargetInfoParams, SessionId, TargetId,
+};
 use chromiumoxide_cdp::cdp::js_protocol;
 use chromiumoxide_cdp::cdp::js_protocol::debugger::GetScriptSourceParams;
 use chromiumoxide_cdp::cdp::js_protocol::runtime::{
     AddBindingParams, CallArgument, CallFunctionOnParams, EvaluateParams, ExecutionContextId,
-    RemoteObjectType, ScriptId,
+    QueryObjectsParams, RemoteObjectType, ScriptId,
 };
-use chromiumoxide_cdp::cdp::{browser_protocol, IntoEventKind};
+use chromiumoxide_cdp::cdp::{browser_protocol, CustomEvent, IntoEventKind};
 use chromiumoxide_types::*;
 
 use crate::auth::Credentials;
+use crate::cmd::CommandMessage;
 use crate::element::Element;
-use crate::error::{CdpError, Result};
+use crate::error::{CdpError, NetError, Result};
 use crate::handler::commandfuture::CommandFuture;
-use crate::handler::domworld::DOMWorldKind;
+pub use crate::handler::domworld::DOMWorldKind;
+pub use crate::handler::frame::{FrameNode, LifecycleEvent};
 use crate::handler::httpfuture::HttpFuture;
-use crate::handler::target::{GetName, GetParent, GetUrl, TargetMessage};
+use crate::handler::target::{GetName, GetParent, GetUrl, TargetMessage, TargetType};
+use crate::handler::viewport::Viewport as EmulatedViewport;
 use crate::handler::PageInner;
-use crate::js::{Evaluation, EvaluationResult};
+use crate::har::Har;
+use crate::js::{self, Evaluation, EvaluationResult, JsHandle};
 use crate::layout::Point;
-use crate::listeners::{EventListenerRequest, EventStream};
+use crate::listeners::EventStream;
+use crate::recorder::{RecordedResponse, ResponseRecorder};
+use crate::stealth::StealthPatch;
 use crate::{utils, ArcHttpRequest};
 
+/// How many intermediate mouse moves `Page::drag_and_drop` dispatches
+const DRAG_STEPS: usize = 10;
+
+/// How long `Page::drag_and_drop` waits for the browser to start a drag
+const DRAG_INTERCEPT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The maximum number of bytes to read with a single `IO.read` command
+const IO_READ_CHUNK_SIZE: i64 = 1024 * 1024;
+
+/// Replaces the binding of `Page::expose_callback`, which is called with the
+/// binding's name, with a function that returns a promise for the result of
+/// the callback.
+const EXPOSE_CALLBACK_JS: &str = "function(name) {
+    const binding = window[name];
+    if (typeof binding !== 'function' || binding.callbacks) {
+        return;
+    }
+    const callbacks = new Map();
+    let lastId = 0;
+    window[name] = (...args) => new Promise(resolve => {
+        const id = ++lastId;
+        callbacks.set(id, resolve);
+        binding(JSON.stringify({ id, args }));
+    });
+    window[name].callbacks = callbacks;
+}";
+
 #[derive(Debug, Clone)]
 pub struct Page {
     inner: Arc<PageInner>,
@@ -49,11 +101,14 @@ impl Page {
     /// changes permissions, pluggins rendering contexts and the `window.chrome`
     /// property to make it harder to detect the scraper as a bot
     async fn _enable_stealth_mode(&self) -> Result<()> {
-        self.hide_webdriver().await?;
-        self.hide_permissions().await?;
-        self.hide_plugins().await?;
-        self.hide_webgl_vendor().await?;
-        self.hide_chrome().await?;
+        self.apply_stealth_patches([
+            StealthPatch::Webdriver,
+            StealthPatch::Permissions,
+            StealthPatch::Plugins,
+            StealthPatch::WebglVendor,
+            StealthPatch::Chrome,
+        ])
+        .await?;
 
         Ok(())
     }
@@ -79,107 +134,115 @@ impl Page {
         Ok(())
     }
 
-    /// Sets `window.chrome` on frame creation
-    async fn hide_chrome(&self) -> Result<(), CdpError> {
-        self.execute(AddScriptToEvaluateOnNewDocumentParams {
-            source: "window.chrome = { runtime: {} };".to_string(),
-            world_name: None,
-            include_command_line_api: None,
-        })
-        .await?;
-        Ok(())
-    }
-
-    /// Obfuscates WebGL vendor on frame creation
-    async fn hide_webgl_vendor(&self) -> Result<(), CdpError> {
-        self
-            .execute(AddScriptToEvaluateOnNewDocumentParams {
-                source: "
-                    const getParameter = WebGLRenderingContext.getParameter;
-                    WebGLRenderingContext.prototype.getParameter = function (parameter) {
-                        if (parameter === 37445) {
-                            return 'Google Inc. (NVIDIA)';
-                        }
-    
-                        if (parameter === 37446) {
-                            return 'ANGLE (NVIDIA, NVIDIA GeForce GTX 1050 Direct3D11 vs_5_0 ps_5_0, D3D11-27.21.14.5671)';
-                        }
-    
-                        return getParameter(parameter);
-                    };
-                "
-                .to_string(),
-                world_name: None,
-                include_command_line_api: None,
-            })
-            .await?;
-        Ok(())
-    }
-
-    /// Obfuscates browser plugins on frame creation
-    async fn hide_plugins(&self) -> Result<(), CdpError> {
-        self.execute(AddScriptToEvaluateOnNewDocumentParams {
-            source: "
-                    Object.defineProperty(
-                        navigator,
-                        'plugins',
-                        {
-                            get: () => [
-                                { filename: 'internal-pdf-viewer' },
-                                { filename: 'adsfkjlkjhalkh' },
-                                { filename: 'internal-nacl-plugin '}
-                            ],
-                        }
-                    );
-                "
-            .to_string(),
-            world_name: None,
-            include_command_line_api: None,
-        })
-        .await?;
-        Ok(())
+    /// Evaluates the scripts of the given `StealthPatch`es in every frame of
+    /// this page on creation.
+    ///
+    /// Returns the identifiers of the added scripts, which can be removed
+    /// again with [`Page::remove_script_to_evaluate_on_new_document`].
+    ///
+    /// # Example hide `navigator.webdriver` only
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::stealth::StealthPatch;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.apply_stealth_patches([StealthPatch::Webdriver]).await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn apply_stealth_patches(
+        &self,
+        patches: impl IntoIterator<Item = StealthPatch>,
+    ) -> Result<Vec<ScriptIdentifier>> {
+        let mut identifiers = Vec::new();
+        for patch in patches {
+            identifiers.push(self.evaluate_on_new_document(patch.script()).await?);
+        }
+        Ok(identifiers)
     }
 
-    /// Obfuscates browser permissions on frame creation
-    async fn hide_permissions(&self) -> Result<(), CdpError> {
-        self.execute(AddScriptToEvaluateOnNewDocumentParams {
-            source: "
-                    const originalQuery = window.navigator.permissions.query;
-                    window.navigator.permissions.__proto__.query = parameters => {
-                        return parameters.name === 'notifications'
-                            ? Promise.resolve({ state: Notification.permission })
-                            : originalQuery(parameters);
-                    }
-                "
-            .to_string(),
-            world_name: None,
-            include_command_line_api: None,
-        })
-        .await?;
-        Ok(())
+    /// Execute a command and return the `Command::Response`
+    pub async fn execute<T: Command>(&self, cmd: T) -> Result<CommandResponse<T::Response>> {
+        self.command_future(cmd)?.await
     }
 
-    /// Removes the `navigator.webdriver` property on frame creation
-    async fn hide_webdriver(&self) -> Result<(), CdpError> {
-        self.execute(AddScriptToEvaluateOnNewDocumentParams {
-            source: "
-                    Object.defineProperty(
-                        navigator,
-                        'webdriver',
-                        { get: () => undefined }
-                    );
-                "
-            .to_string(),
-            world_name: None,
-            include_command_line_api: None,
-        })
-        .await?;
-        Ok(())
+    /// Execute all commands at once and return their responses in the same
+    /// order.
+    ///
+    /// All commands are submitted before any response is awaited, so this only
+    /// takes a single round trip to the browser instead of one per command.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # use chromiumoxide::cdp::browser_protocol::dom::{BackendNodeId, DescribeNodeParams};
+    /// # async fn demo(page: Page, ids: Vec<BackendNodeId>) -> Result<()> {
+    ///     let nodes = page
+    ///         .execute_batch(ids.into_iter().map(|id| {
+    ///             DescribeNodeParams::builder().backend_node_id(id).build()
+    ///         }))
+    ///         .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_batch<T: Command>(
+        &self,
+        cmds: impl IntoIterator<Item = T>,
+    ) -> Result<Vec<CommandResponse<T::Response>>> {
+        let futures = cmds
+            .into_iter()
+            .map(|cmd| self.command_future(cmd))
+            .collect::<Result<Vec<_>>>()?;
+        future::try_join_all(futures).await
     }
 
-    /// Execute a command and return the `Command::Response`
-    pub async fn execute<T: Command>(&self, cmd: T) -> Result<CommandResponse<T::Response>> {
-        self.command_future(cmd)?.await
+    /// Sends an untyped command to the page's session and returns the raw
+    /// `result` of the response.
+    ///
+    /// This is an escape hatch for methods that are not part of the protocol
+    /// version this crate was generated from, like experimental domains of a
+    /// newer chromium. Prefer `Page::execute` for everything else.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let result = page
+    ///         .send_raw("Page.newExperimentalMethod", serde_json::json!({ "enabled": true }))
+    ///         .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_raw(
+        &self,
+        method: impl Into<MethodId>,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let (tx, rx) = oneshot_channel();
+        let msg = CommandMessage {
+            method: method.into(),
+            session_id: Some(self.inner.session_id().clone()),
+            params,
+            sender: tx,
+        };
+        self.inner
+            .sender()
+            .clone()
+            .send(TargetMessage::Command(msg))
+            .await?;
+        let resp = rx.await??;
+        if let Some(res) = resp.result {
+            Ok(res)
+        } else if let Some(err) = resp.error {
+            Err(err.into())
+        } else {
+            Err(CdpError::NoResponse)
+        }
     }
 
     /// Execute a command and return the `Command::Response`
@@ -257,18 +320,218 @@ impl Page {
     /// # }
     /// ```
     pub async fn event_listener<T: IntoEventKind>(&self) -> Result<EventStream<T>> {
-        let (tx, rx) = unbounded();
+        self.inner.event_listener().await
+    }
+
+    /// Waits for the first request of this page that matches the `predicate`.
+    ///
+    /// Fails with [`CdpError::Timeout`] if no matching request is sent within
+    /// the `timeout`. Only requests that are sent after this was called are
+    /// considered. If the page or the connection is closed while waiting, the
+    /// error reports `CdpError::is_connection_closed`.
+    ///
+    /// # Example wait for an API call
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let request = page
+    ///         .wait_for_request(
+    ///             |event| event.request.url.contains("/api/results"),
+    ///             Duration::from_secs(10),
+    ///         )
+    ///         .await?;
+    ///     println!("{:?}", request.request.post_data);
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_request(
+        &self,
+        predicate: impl Fn(&EventRequestWillBeSent) -> bool,
+        timeout: Duration,
+    ) -> Result<Arc<EventRequestWillBeSent>> {
+        self.wait_for_event(predicate, timeout).await
+    }
+
+    /// Waits for the first response received by this page that matches the
+    /// `predicate`.
+    ///
+    /// Fails with [`CdpError::Timeout`] if no matching response is received
+    /// within the `timeout`, like `Page::wait_for_request`. The body of the
+    /// response is not fetched, use [`Page::response_body`] with the
+    /// response's `request_id` to retrieve it.
+    ///
+    /// # Example read the body of an API response
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let response = page
+    ///         .wait_for_response(
+    ///             |event| event.response.url.contains("/api/results"),
+    ///             Duration::from_secs(10),
+    ///         )
+    ///         .await?;
+    ///     let body = page.response_body(response.request_id.clone()).await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_response(
+        &self,
+        predicate: impl Fn(&EventResponseReceived) -> bool,
+        timeout: Duration,
+    ) -> Result<Arc<EventResponseReceived>> {
+        self.wait_for_event(predicate, timeout).await
+    }
+
+    /// Waits for the first event of type `T` that matches the `predicate`.
+    async fn wait_for_event<T: IntoEventKind + Unpin>(
+        &self,
+        predicate: impl Fn(&T) -> bool,
+        timeout: Duration,
+    ) -> Result<Arc<T>> {
+        let mut events = self.event_listener::<T>().await?;
+        let matched = async {
+            while let Some(event) = events.next().await {
+                if predicate(&event) {
+                    return Some(event);
+                }
+            }
+            None
+        };
+        let delay = futures_timer::Delay::new(timeout);
+        futures::pin_mut!(matched);
+        match future::select(matched, delay).await {
+            Either::Left((Some(event), _)) => Ok(event),
+            // the listener is dropped once the page or the connection is closed
+            Either::Left((None, _)) => Err(Canceled.into()),
+            Either::Right(_) => Err(CdpError::Timeout),
+        }
+    }
+
+    /// Returns the body of the request with the given id.
+    ///
+    /// This is required for requests with long bodies, which are omitted from
+    /// the `Network.requestWillBeSent` event (see
+    /// `HttpRequest::is_post_data_truncated`). Files of multipart requests
+    /// are not included.
+    pub async fn request_post_data(&self, request_id: impl Into<RequestId>) -> Result<String> {
+        Ok(self
+            .execute(GetRequestPostDataParams::new(request_id))
+            .await?
+            .result
+            .post_data)
+    }
+
+    /// Returns the body of the response to the request with the given id.
+    ///
+    /// The body is only available as long as the browser keeps it, which is
+    /// usually until the page navigates.
+    pub async fn response_body(&self, request_id: impl Into<RequestId>) -> Result<Vec<u8>> {
+        let res = self
+            .execute(GetResponseBodyParams::new(request_id))
+            .await?
+            .result;
+        if res.base64_encoded {
+            Ok(utils::base64::decode(&res.body)?)
+        } else {
+            Ok(res.body.into_bytes())
+        }
+    }
+
+    /// Starts recording the responses of this page that match the `filter`,
+    /// including their bodies, on a background task.
+    ///
+    /// At most `max_entries` responses are kept until they are taken with
+    /// [`Page::take_recorded`], further responses are dropped. A previously
+    /// started recording of this page is stopped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # use chromiumoxide::cdp::browser_protocol::network::ResourceType;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.start_response_recording(|ev| ev.r#type == ResourceType::Xhr, 1000)
+    ///         .await?;
+    ///     page.goto("https://example.com").await?;
+    ///     for response in page.take_recorded() {
+    ///         println!("{} {:?}", response.url(), response.body.as_ref().map(Vec::len));
+    ///     }
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn start_response_recording(
+        &self,
+        filter: impl Fn(&EventResponseReceived) -> bool + Send + 'static,
+        max_entries: usize,
+    ) -> Result<ResponseRecorder> {
+        self.stop_response_recording();
+        let recorder = ResponseRecorder::start(self.clone(), filter, max_entries).await?;
+        *self.inner.response_recorder().lock().unwrap() = Some(recorder.clone());
+        Ok(recorder)
+    }
+
+    /// Removes and returns all responses recorded so far by the recording
+    /// started with [`Page::start_response_recording`].
+    pub fn take_recorded(&self) -> Vec<RecordedResponse> {
         self.inner
-            .sender()
-            .clone()
-            .send(TargetMessage::AddEventListener(
-                EventListenerRequest::new::<T>(tx),
-            ))
-            .await?;
+            .response_recorder()
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|recorder| recorder.take_recorded())
+            .unwrap_or_default()
+    }
+
+    /// Creates a HAR document of the responses recorded so far by the
+    /// recording started with [`Page::start_response_recording`], without
+    /// removing them.
+    ///
+    /// Responses whose request was sent before the recording started are not
+    /// included.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.start_response_recording(|_| true, 1000).await?;
+    ///     page.goto("https://example.com").await?;
+    ///     let har = serde_json::to_string_pretty(&page.export_har())?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub fn export_har(&self) -> Har {
+        let recorded = self
+            .inner
+            .response_recorder()
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|recorder| recorder.recorded())
+            .unwrap_or_default();
+        Har::from_recorded(&recorded)
+    }
 
-        Ok(EventStream::new(rx))
+    /// Stops the recording started with [`Page::start_response_recording`].
+    ///
+    /// Responses that were recorded but not yet taken are discarded.
+    pub fn stop_response_recording(&self) {
+        if let Some(recorder) = self.inner.response_recorder().lock().unwrap().take() {
+            recorder.stop();
+        }
     }
 
+    /// Adds the binding `name` to all frames of the page and evaluates the
+    /// `function`, called with `"exposedFun"` and the `name`, in all current
+    /// and future frames.
     pub async fn expose_function(
         &self,
         name: impl Into<String>,
@@ -282,10 +545,117 @@ impl Page {
             expression.clone(),
         ))
         .await?;
+        self.evaluate_in_all_frames(expression).await
+    }
+
+    /// Evaluates the `expression` in the main world of every frame of the
+    /// page.
+    async fn evaluate_in_all_frames(&self, expression: String) -> Result<()> {
+        for frame_id in self.frames().await? {
+            if let Some(context_id) = self.frame_execution_context(frame_id).await? {
+                // the frame may have navigated or detached in the meantime, in
+                // which case the script runs once its new document is created
+                let _ = self
+                    .evaluate_expression(
+                        EvaluateParams::builder()
+                            .expression(expression.clone())
+                            .context_id(context_id)
+                            .build()
+                            .unwrap(),
+                    )
+                    .await;
+            }
+        }
+        Ok(())
+    }
+
+    /// Exposes a function `name` on the `window` of the page that invokes the
+    /// `callback` and returns a promise for its result.
+    ///
+    /// The function is exposed in all frames of the page, and the result is
+    /// returned to the frame that made the call.
+    ///
+    /// The `callback` receives the arguments of the call as JSON array. This
+    /// enables the page to call into Rust and receive the result, e.g. to
+    /// provide data from outside of the browser.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.expose_callback("add", |args| {
+    ///         let sum: i64 = args
+    ///             .as_array()
+    ///             .map(|args| args.iter().filter_map(|arg| arg.as_i64()).sum())
+    ///             .unwrap_or_default();
+    ///         serde_json::json!(sum)
+    ///     })
+    ///     .await?;
+    ///     let sum: i64 = page.evaluate("add(1, 2)").await?.into_value()?;
+    ///     assert_eq!(sum, 3);
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn expose_callback(
+        &self,
+        name: impl Into<String>,
+        mut callback: impl FnMut(serde_json::Value) -> serde_json::Value + Send + 'static,
+    ) -> Result<()> {
+        #[derive(Deserialize)]
+        struct Payload {
+            id: u64,
+            args: serde_json::Value,
+        }
 
-        // TODO add execution context tracking for frames
-        //let frames = self.frames().await?;
+        let name = name.into();
+        let mut calls = self
+            .event_listener::<js_protocol::runtime::EventBindingCalled>()
+            .await?;
+        self.execute(AddBindingParams::new(name.clone())).await?;
+        let expression = utils::evaluation_string(EXPOSE_CALLBACK_JS, &[name.as_str()]);
+        self.execute(AddScriptToEvaluateOnNewDocumentParams::new(
+            expression.clone(),
+        ))
+        .await?;
+        self.evaluate_in_all_frames(expression).await?;
 
+        let page = self.clone();
+        let fut = async move {
+            while let Some(call) = calls.next().await {
+                if call.name != name {
+                    continue;
+                }
+                let payload = match serde_json::from_str::<Payload>(&call.payload) {
+                    Ok(payload) => payload,
+                    Err(_) => continue,
+                };
+                let result = callback(payload.args);
+                let resolve = format!(
+                    "window[{}].callbacks.get({id})({result}); window[{}].callbacks.delete({id});",
+                    serde_json::Value::from(name.as_str()),
+                    serde_json::Value::from(name.as_str()),
+                    id = payload.id,
+                );
+                let _ = page
+                    .evaluate_expression(
+                        EvaluateParams::builder()
+                            .expression(resolve)
+                            .context_id(call.execution_context_id)
+                            .build()
+                            .unwrap(),
+                    )
+                    .await;
+            }
+        };
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "async-std-runtime")] {
+                async_std::task::spawn(fut);
+            } else if #[cfg(feature = "tokio-runtime")] {
+                tokio::spawn(fut);
+            }
+        }
         Ok(())
     }
 
@@ -304,26 +674,215 @@ impl Page {
         Ok(self)
     }
 
-    /// Navigate directly to the given URL.
+    /// Waits until the page's main frame recorded the `event` for its current
+    /// document, resolves immediately if it already did.
     ///
-    /// This resolves directly after the requested URL is fully loaded.
-    pub async fn goto(&self, params: impl Into<NavigateParams>) -> Result<&Self> {
-        let res = self.execute(params.into()).await?;
-        if let Some(err) = res.result.error_text {
-            return Err(CdpError::ChromeMessage(err));
+    /// Fails with [`CdpError::Timeout`] if the event isn't recorded within
+    /// `timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::{LifecycleEvent, Page};
+    /// # use chromiumoxide::error::Result;
+    /// # use std::time::Duration;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.goto("https://example.com").await?;
+    ///     page.wait_for_lifecycle_event(LifecycleEvent::FirstMeaningfulPaint, Duration::from_secs(5))
+    ///         .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_lifecycle_event(
+        &self,
+        event: LifecycleEvent,
+        timeout: Duration,
+    ) -> Result<&Self> {
+        let recorded = self.inner.wait_for_lifecycle_event(event);
+        let delay = futures_timer::Delay::new(timeout);
+        match future::select(recorded, delay).await {
+            Either::Left((res, _)) => {
+                res?;
+                Ok(self)
+            }
+            Either::Right(_) => Err(CdpError::Timeout),
         }
+    }
 
-        Ok(self)
+    /// Resolves after `timeout` has elapsed.
+    ///
+    /// This is independent of the configured async runtime, so it can be used
+    /// instead of `tokio::time::sleep` or `async_std::task::sleep`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # use std::time::Duration;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.reload().await?;
+    ///     page.wait_for_timeout(Duration::from_millis(500)).await;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_timeout(&self, timeout: Duration) -> &Self {
+        futures_timer::Delay::new(timeout).await;
+        self
     }
 
-    /// The identifier of the `Target` this page belongs to
-    pub fn target_id(&self) -> &TargetId {
-        self.inner.target_id()
+    /// Navigate directly to the given URL.
+    ///
+    /// This resolves directly after the requested URL is fully loaded. If
+    /// the navigation fails with a network error, like an unknown host, this
+    /// returns a `CdpError::Navigation` with the [`NetError`].
+    ///
+    /// `data:` URLs are loaded as is, use [`Page::goto_file`] to load a local
+    /// file.
+    pub async fn goto(&self, params: impl Into<NavigateParams>) -> Result<&Self> {
+        self.navigate(params.into()).await?;
+        Ok(self)
     }
 
-    /// The identifier of the `Session` target of this page is attached to
-    pub fn session_id(&self) -> &SessionId {
-        self.inner.session_id()
+    /// Navigate to a local file.
+    ///
+    /// The `path` is canonicalized and converted into a percent-encoded
+    /// `file://` URL, this fails if the file does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.goto_file("tests/fixtures/form.html").await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn goto_file(&self, path: impl AsRef<Path>) -> Result<&Self> {
+        let path = dunce::canonicalize(path.as_ref())?;
+        let url = url::Url::from_file_path(&path)
+            .map_err(|_| CdpError::msg(format!("Invalid file path {}", path.display())))?;
+        self.goto(url.as_str()).await
+    }
+
+    /// Same as [`Page::goto`], but retries the navigation with an increasing
+    /// delay if it fails with one of the transient network errors of the
+    /// [`RetryPolicy`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::{Page, RetryPolicy};
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.goto_with_retry("https://example.com", RetryPolicy::default())
+    ///         .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn goto_with_retry(
+        &self,
+        params: impl Into<NavigateParams>,
+        policy: RetryPolicy,
+    ) -> Result<&Self> {
+        let params = params.into();
+        let mut backoff = policy.backoff;
+        let mut retries = 0;
+        loop {
+            match self.navigate(params.clone()).await {
+                Ok(_) => return Ok(self),
+                Err(err) if retries < policy.max_retries && policy.is_retryable(&err) => {
+                    tracing::debug!("Retrying navigation to {} after {:?}", params.url, err);
+                    futures_timer::Delay::new(backoff).await;
+                    backoff *= 2;
+                    retries += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Navigate directly to the given URL and return the [`NavigationResponse`]
+    /// of the main frame.
+    ///
+    /// This resolves directly after the requested URL is fully loaded.
+    ///
+    /// # Example detect a missing page
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let response = page.goto_with_response("https://example.com/missing").await?;
+    ///     if response.status == Some(404) {
+    ///         println!("{:?} not found", response.url);
+    ///     }
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn goto_with_response(
+        &self,
+        params: impl Into<NavigateParams>,
+    ) -> Result<NavigationResponse> {
+        let res = self.navigate(params.into()).await?;
+        // the loader only stays the same for same-document navigations
+        let same_document = res.loader_id.is_none();
+        let request = if same_document {
+            None
+        } else {
+            self.inner.wait_for_navigation().await?
+        };
+        let response = request.as_ref().and_then(|req| req.response.as_ref());
+        let url = match response {
+            Some(response) => Some(response.url.clone()),
+            None => self.url().await?,
+        };
+
+        Ok(NavigationResponse {
+            url,
+            status: response.map(|response| response.status),
+            same_document,
+            request,
+        })
+    }
+
+    /// Same as [`Page::goto_with_response`] but fails with
+    /// [`CdpError::HttpStatus`] if the main document was served with a 4xx or
+    /// 5xx HTTP status.
+    ///
+    /// `goto` resolves successfully for error pages as long as the document
+    /// loads.
+    pub async fn goto_checked(
+        &self,
+        params: impl Into<NavigateParams>,
+    ) -> Result<NavigationResponse> {
+        let response = self.goto_with_response(params).await?;
+        match response.status {
+            Some(status) if status >= 400 => Err(CdpError::HttpStatus(status)),
+            _ => Ok(response),
+        }
+    }
+
+    async fn navigate(&self, params: NavigateParams) -> Result<NavigateReturns> {
+        let res = self.inner.navigation_future(params)?.await?.result;
+        if let Some(err) = res.error_text {
+            return Err(match NetError::parse(&err) {
+                Some(err) => CdpError::Navigation(err),
+                None => CdpError::ChromeMessage(err),
+            });
+        }
+        Ok(res)
+    }
+
+    /// The identifier of the `Target` this page belongs to
+    pub fn target_id(&self) -> &TargetId {
+        self.inner.target_id()
+    }
+
+    /// The identifier of the `Session` target of this page is attached to
+    pub fn session_id(&self) -> &SessionId {
+        self.inner.session_id()
     }
 
     /// The identifier of the `Session` target of this page is attached to
@@ -345,16 +904,322 @@ impl Page {
         Ok(rx.await?)
     }
 
-    pub async fn authenticate(&self, credentials: Credentials) -> Result<()> {
+    /// Answers HTTP authentication challenges of this page, like HTTP Basic
+    /// auth, with the given credentials.
+    ///
+    /// This enables request interception for the page, so that
+    /// `Fetch.authRequired` events are answered with the credentials instead
+    /// of showing the auth dialog.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.authenticate(("user", "password")).await?;
+    ///     page.goto("https://intranet.example.com").await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn authenticate(&self, credentials: impl Into<Credentials>) -> Result<()> {
         self.inner
             .sender()
             .clone()
-            .send(TargetMessage::Authenticate(credentials))
+            .send(TargetMessage::Authenticate(credentials.into()))
             .await?;
 
         Ok(())
     }
 
+    /// Intercepts the file chooser dialogs of this page and returns a stream
+    /// of the [`FileChooser`]s that were opened, which can be answered with
+    /// [`FileChooser::accept`].
+    ///
+    /// This also works for custom upload buttons that open the dialog of a
+    /// hidden `<input type="file">` programmatically.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # use futures::StreamExt;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let mut choosers = page.on_file_chooser().await?;
+    ///     page.find_element("#upload").await?.click().await?;
+    ///     if let Some(chooser) = choosers.next().await {
+    ///         chooser.accept(["/tmp/avatar.png"]).await?;
+    ///     }
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn on_file_chooser(&self) -> Result<impl Stream<Item = FileChooser>> {
+        let events = self.event_listener::<EventFileChooserOpened>().await?;
+        self.execute(SetInterceptFileChooserDialogParams::new(true))
+            .await?;
+        let page = self.clone();
+        Ok(events.map(move |event| FileChooser {
+            page: page.clone(),
+            event,
+        }))
+    }
+
+    /// Overrides the certificate error handling of this page and returns a
+    /// stream of the [`CertificateError`]s that occur, each of which must be
+    /// answered with [`CertificateError::proceed`] or
+    /// [`CertificateError::cancel`].
+    ///
+    /// This has no effect if the browser ignores https errors, which is the
+    /// default, see `BrowserConfigBuilder::respect_https_errors`.
+    ///
+    /// The `Security.certificateError` event is deprecated and not part of the
+    /// protocol version this crate was generated from, so this relies on
+    /// the browser still supporting it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # use futures::StreamExt;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let mut errors = page.on_certificate_error().await?;
+    ///     while let Some(error) = errors.next().await {
+    ///         if error.request_url().starts_with("https://staging.example.com") {
+    ///             error.proceed().await?;
+    ///         } else {
+    ///             error.cancel().await?;
+    ///         }
+    ///     }
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn on_certificate_error(&self) -> Result<impl Stream<Item = CertificateError>> {
+        let events = self.event_listener::<EventCertificateError>().await?;
+        self.execute(security::EnableParams::default()).await?;
+        self.send_raw(
+            "Security.setOverrideCertificateErrors",
+            serde_json::json!({ "override": true }),
+        )
+        .await?;
+        let page = self.clone();
+        Ok(events.map(move |event| CertificateError {
+            page: page.clone(),
+            event,
+        }))
+    }
+
+    /// Enables or disables javascript execution on this page.
+    ///
+    /// This only affects documents that are loaded afterwards, so call this
+    /// before `Page::goto`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.set_javascript_enabled(false).await?;
+    ///     page.goto("https://example.com").await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_javascript_enabled(&self, enabled: bool) -> Result<&Self> {
+        self.execute(SetScriptExecutionDisabledParams::new(!enabled))
+            .await?;
+        Ok(self)
+    }
+
+    /// Enables or disables emulating a focused and active page.
+    ///
+    /// Background and headless pages are unfocused, so e.g.
+    /// `document.hasFocus()` returns `false`, which changes the behavior of
+    /// some apps. With focus emulation enabled the page always believes it's
+    /// focused.
+    pub async fn set_focus_emulation_enabled(&self, enabled: bool) -> Result<&Self> {
+        self.execute(SetFocusEmulationEnabledParams::new(enabled))
+            .await?;
+        Ok(self)
+    }
+
+    /// Disables or re-enables the browser cache for the requests of this
+    /// page, e.g. to force fresh loads for specific navigations.
+    ///
+    /// Note that the cache always stays disabled while requests are
+    /// intercepted.
+    pub async fn set_cache_disabled(&self, disabled: bool) -> Result<&Self> {
+        self.inner
+            .sender()
+            .clone()
+            .send(TargetMessage::SetCacheEnabled(!disabled))
+            .await?;
+        Ok(self)
+    }
+
+    /// Enables or disables request interception for this page.
+    ///
+    /// While enabled, every request of the page is paused and has to be
+    /// continued, fulfilled or failed by the listener of
+    /// `EventRequestPaused` events via the `Fetch` domain. Overrides the
+    /// `request_intercept` setting of the browser for this page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # use chromiumoxide::cdp::browser_protocol::fetch::EventRequestPaused;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let mut paused = page.event_listener::<EventRequestPaused>().await?;
+    ///     page.set_request_interception(true).await?;
+    ///     // handle the paused requests
+    ///     page.set_request_interception(false).await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_request_interception(&self, enabled: bool) -> Result<&Self> {
+        self.inner
+            .sender()
+            .clone()
+            .send(TargetMessage::SetRequestInterception(enabled))
+            .await?;
+        Ok(self)
+    }
+
+    /// Enables request interception only for the requests that match any of
+    /// the `patterns`, which is a lot cheaper than intercepting every request
+    /// with `Page::set_request_interception`.
+    ///
+    /// Each pattern can restrict the url, the resource type and the stage,
+    /// request or response, at which a request is paused. Intercepting all
+    /// requests again or disabling the interception is done with
+    /// `Page::set_request_interception`.
+    ///
+    /// If credentials are set with `Page::authenticate`, all requests are
+    /// paused to handle their auth challenges. The ones that don't match any
+    /// of the `patterns` are continued automatically, but their
+    /// `EventRequestPaused` is still emitted to listeners.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # use chromiumoxide::cdp::browser_protocol::fetch::{RequestPattern, RequestStage};
+    /// # use chromiumoxide::cdp::browser_protocol::network::ResourceType;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.enable_fetch_for(vec![RequestPattern::builder()
+    ///         .url_pattern("*.json")
+    ///         .resource_type(ResourceType::Xhr)
+    ///         .request_stage(RequestStage::Response)
+    ///         .build()])
+    ///         .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn enable_fetch_for(
+        &self,
+        patterns: impl IntoIterator<Item = RequestPattern>,
+    ) -> Result<&Self> {
+        self.inner
+            .sender()
+            .clone()
+            .send(TargetMessage::SetRequestInterceptionPatterns(
+                patterns.into_iter().collect(),
+            ))
+            .await?;
+        Ok(self)
+    }
+
+    /// Clears the given types of storage, like `StorageType::LocalStorage` or
+    /// `StorageType::All`, for the `origin`, e.g. `https://example.com`.
+    pub async fn clear_storage_for_origin(
+        &self,
+        origin: impl Into<String>,
+        storage_types: impl IntoIterator<Item = StorageType>,
+    ) -> Result<&Self> {
+        let storage_types = storage_types
+            .into_iter()
+            .map(|ty| ty.as_ref().to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        self.execute(ClearDataForOriginParams::new(origin, storage_types))
+            .await?;
+        Ok(self)
+    }
+
+    /// Returns all items of the `localStorage` of the page's origin.
+    ///
+    /// The map is empty if the page has an opaque origin, like `about:blank`,
+    /// which has no storage.
+    pub async fn local_storage(&self) -> Result<HashMap<String, String>> {
+        self.storage_items("localStorage").await
+    }
+
+    /// Sets the item with the `key` in the `localStorage` of the page's
+    /// origin.
+    ///
+    /// Fails if the page has an opaque origin, like `about:blank`.
+    pub async fn set_local_storage_item(
+        &self,
+        key: impl AsRef<str>,
+        value: impl AsRef<str>,
+    ) -> Result<&Self> {
+        self.set_storage_item("localStorage", key.as_ref(), value.as_ref())
+            .await?;
+        Ok(self)
+    }
+
+    /// Returns all items of the `sessionStorage` of the page's origin.
+    ///
+    /// The map is empty if the page has an opaque origin, like `about:blank`,
+    /// which has no storage.
+    pub async fn session_storage(&self) -> Result<HashMap<String, String>> {
+        self.storage_items("sessionStorage").await
+    }
+
+    /// Sets the item with the `key` in the `sessionStorage` of the page's
+    /// origin.
+    ///
+    /// Fails if the page has an opaque origin, like `about:blank`.
+    pub async fn set_session_storage_item(
+        &self,
+        key: impl AsRef<str>,
+        value: impl AsRef<str>,
+    ) -> Result<&Self> {
+        self.set_storage_item("sessionStorage", key.as_ref(), value.as_ref())
+            .await?;
+        Ok(self)
+    }
+
+    async fn storage_items(&self, storage: &str) -> Result<HashMap<String, String>> {
+        // accessing the storage of an opaque origin throws a `SecurityError`
+        let expression = format!(
+            "(() => {{ try {{ return Object.fromEntries(Object.entries(window.{storage})); }} catch (e) {{ return null; }} }})()"
+        );
+        let items: Option<HashMap<String, String>> =
+            self.evaluate_expression(expression).await?.into_value()?;
+        Ok(items.unwrap_or_default())
+    }
+
+    async fn set_storage_item(&self, storage: &str, key: &str, value: &str) -> Result<()> {
+        let expression = format!(
+            "(() => {{ try {{ window.{storage}.setItem({}, {}); return true; }} catch (e) {{ return false; }} }})()",
+            serde_json::to_string(key)?,
+            serde_json::to_string(value)?
+        );
+        let stored: bool = self.evaluate_expression(expression).await?.into_value()?;
+        if stored {
+            Ok(())
+        } else {
+            Err(CdpError::msg(format!(
+                "The {storage} of the page's origin is not accessible"
+            )))
+        }
+    }
+
     /// Returns the current url of the page
     pub async fn url(&self) -> Result<Option<String>> {
         let (tx, rx) = oneshot_channel();
@@ -391,6 +1256,30 @@ impl Page {
         Ok(rx.await?)
     }
 
+    /// Return the type of the page's target, e.g. to distinguish a regular
+    /// page from a background page
+    pub async fn target_type(&self) -> Result<TargetType> {
+        let (tx, rx) = oneshot_channel();
+        self.inner
+            .sender()
+            .clone()
+            .send(TargetMessage::Type(tx))
+            .await?;
+        Ok(rx.await?)
+    }
+
+    /// Return the id of the target that opened this page, e.g. the page that
+    /// opened this page as a popup
+    pub async fn opener(&self) -> Result<Option<TargetId>> {
+        let (tx, rx) = oneshot_channel();
+        self.inner
+            .sender()
+            .clone()
+            .send(TargetMessage::Opener(tx))
+            .await?;
+        Ok(rx.await?)
+    }
+
     /// Return the main frame of the page
     pub async fn mainframe(&self) -> Result<Option<FrameId>> {
         let (tx, rx) = oneshot_channel();
@@ -413,12 +1302,60 @@ impl Page {
         Ok(rx.await?)
     }
 
+    /// Return the frames of the page as a tree, with the main frame as root.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::{FrameNode, Page};
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     fn print(node: &FrameNode, depth: usize) {
+    ///         println!("{:indent$}{:?} {:?}", "", node.id, node.url, indent = depth * 2);
+    ///         for child in &node.children {
+    ///             print(child, depth + 1);
+    ///         }
+    ///     }
+    ///     if let Some(root) = page.frame_tree().await? {
+    ///         print(&root, 0);
+    ///     }
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn frame_tree(&self) -> Result<Option<FrameNode>> {
+        let (tx, rx) = oneshot_channel();
+        self.inner
+            .sender()
+            .clone()
+            .send(TargetMessage::FrameTree(tx))
+            .await?;
+        Ok(rx.await?)
+    }
+
     /// Allows overriding user agent with the given string.
+    ///
+    /// Use a [`UserAgentOverride`] to override the platform, the accept
+    /// language and the client hints of `navigator.userAgentData`
+    /// consistently with the user agent string.
+    ///
+    /// If `params` doesn't set an accept language, the one of the locale set
+    /// with `Page::set_locale` is kept.
     pub async fn set_user_agent(
         &self,
         params: impl Into<SetUserAgentOverrideParams>,
     ) -> Result<&Self> {
-        self.execute(params.into()).await?;
+        let mut params = params.into();
+        if params.accept_language.is_none() {
+            params.accept_language = self
+                .inner
+                .user_agent_override()
+                .lock()
+                .unwrap()
+                .as_ref()
+                .and_then(|current| current.accept_language.clone());
+        }
+        self.execute(params.clone()).await?;
+        *self.inner.user_agent_override().lock().unwrap() = Some(params);
         Ok(self)
     }
 
@@ -490,34 +1427,111 @@ impl Page {
         Ok(())
     }
 
-    /// Performs a single mouse click event at the point's location.
-    ///
-    /// This scrolls the point into view first, then executes a
-    /// `DispatchMouseEventParams` command of type `MouseLeft` with
-    /// `MousePressed` as single click and then releases the mouse with an
-    /// additional `DispatchMouseEventParams` of type `MouseLeft` with
-    /// `MouseReleased`
+    /// Closes the page as configured by the [`CloseOptions`].
     ///
-    /// Bear in mind that if `click()` triggers a navigation the new page is not
-    /// immediately loaded when `click()` resolves. To wait until navigation is
-    /// finished an additional `wait_for_navigation()` is required:
+    /// If `run_before_unload` is set, this calls `Page.close` and accepts the
+    /// `beforeunload` dialog that the page's hooks may open, so this only
+    /// returns once the page's target is gone. If the page isn't closed
+    /// within the navigation timeout, e.g. because its hooks cancelled the
+    /// unload, this fails with `CdpError::Timeout`. Otherwise the target is
+    /// closed with `Target.closeTarget` which doesn't run any hooks.
     ///
     /// # Example
     ///
-    /// Trigger a navigation and wait until the triggered navigation is finished
-    ///
     /// ```no_run
-    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::page::{Page, CloseOptions};
     /// # use chromiumoxide::error::Result;
-    /// # use chromiumoxide::layout::Point;
-    /// # async fn demo(page: Page, point: Point) -> Result<()> {
-    ///     let html = page.click(point).await?.wait_for_navigation().await?.content();
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.close_with(CloseOptions { run_before_unload: true }).await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn close_with(self, options: CloseOptions) -> Result<()> {
+        if !options.run_before_unload {
+            self.execute(CloseTargetParams::new(self.target_id().clone()))
+                .await?;
+            return Ok(());
+        }
+
+        let mut dialogs = self
+            .event_listener::<EventJavascriptDialogOpening>()
+            .await?;
+        self.execute(CloseParams::default()).await?;
+        let accept_dialogs = async {
+            while let Some(dialog) = dialogs.next().await {
+                if dialog.r#type == DialogType::Beforeunload {
+                    self.execute(HandleJavaScriptDialogParams::new(true))
+                        .await?;
+                }
+            }
+            Ok(())
+        };
+        futures::pin_mut!(accept_dialogs);
+        let closed = self.closed();
+        futures::pin_mut!(closed);
+        with_timeout(
+            async {
+                match future::select(accept_dialogs, closed).await {
+                    Either::Left((res, _)) => res,
+                    Either::Right(_) => Ok(()),
+                }
+            },
+            self.inner.navigation_timeout(),
+        )
+        .await
+    }
+
+    /// Whether the page's target was destroyed, for example because the page
+    /// was closed or its tab was closed by the user.
+    ///
+    /// Once closed, all commands of this page fail.
+    pub fn is_closed(&self) -> bool {
+        self.inner.is_closed()
+    }
+
+    /// Resolves once the page's target was destroyed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # async fn demo(page: Page) {
+    ///     page.closed().await;
+    ///     assert!(page.is_closed());
+    /// # }
+    /// ```
+    pub async fn closed(&self) {
+        self.inner.closed().await
+    }
+
+    /// Performs a single mouse click event at the point's location.
+    ///
+    /// This scrolls the point into view first, then executes a
+    /// `DispatchMouseEventParams` command of type `MouseLeft` with
+    /// `MousePressed` as single click and then releases the mouse with an
+    /// additional `DispatchMouseEventParams` of type `MouseLeft` with
+    /// `MouseReleased`
+    ///
+    /// Bear in mind that if `click()` triggers a navigation the new page is not
+    /// immediately loaded when `click()` resolves. To wait until navigation is
+    /// finished an additional `wait_for_navigation()` is required:
+    ///
+    /// # Example
+    ///
+    /// Trigger a navigation and wait until the triggered navigation is finished
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # use chromiumoxide::layout::Point;
+    /// # async fn demo(page: Page, point: Point) -> Result<()> {
+    ///     let html = page.click(point).await?.wait_for_navigation().await?.content();
     ///     # Ok(())
     /// # }
     /// ```
     ///
-    /// # Example
-    ///
+    /// # Example
+    ///
     /// Perform custom click
     ///
     /// ```no_run
@@ -556,6 +1570,186 @@ impl Page {
         Ok(self)
     }
 
+    /// Presses the `key` with the given `PressOptions`.
+    ///
+    /// # Example press the `Enter` key of the numeric keypad
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::{KeyLocation, Page, PressOptions};
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.press_key(
+    ///         "Enter",
+    ///         PressOptions {
+    ///             location: Some(KeyLocation::Numpad),
+    ///             ..Default::default()
+    ///         },
+    ///     )
+    ///     .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn press_key(
+        &self,
+        key: impl AsRef<str>,
+        options: impl Into<PressOptions>,
+    ) -> Result<&Self> {
+        self.inner
+            .press_key_with_options(key, &options.into())
+            .await?;
+        Ok(self)
+    }
+
+    /// Performs a mouse click at the `point` with the given `ClickOptions`.
+    ///
+    /// # Example double click with the shift key pressed
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::{ClickOptions, Page};
+    /// # use chromiumoxide::error::Result;
+    /// # use chromiumoxide::layout::Point;
+    /// # async fn demo(page: Page, point: Point) -> Result<()> {
+    ///     page.click_with_options(
+    ///         point,
+    ///         ClickOptions {
+    ///             click_count: 2,
+    ///             modifiers: 8,
+    ///             ..Default::default()
+    ///         },
+    ///     )
+    ///     .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn click_with_options(
+        &self,
+        point: Point,
+        options: impl Into<ClickOptions>,
+    ) -> Result<&Self> {
+        self.inner
+            .click_with_options(point, &options.into())
+            .await?;
+        Ok(self)
+    }
+
+    /// Performs a right click at the `point`, which fires the `contextmenu`
+    /// event of the element at that position.
+    pub async fn right_click(&self, point: Point) -> Result<&Self> {
+        self.click_with_options(
+            point,
+            ClickOptions {
+                button: MouseButton::Right,
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// Drags the `source` element and drops it onto the `target` element.
+    ///
+    /// Both elements are scrolled into view first. With `DragMode::Mouse`
+    /// this only dispatches mouse events: the left button is pressed at the
+    /// center of `source`, the mouse is moved to the center of `target` in
+    /// several steps and released there. Pages that rely on the HTML5 drag and
+    /// drop API need `DragMode::DragEvents`, which intercepts the drag started
+    /// by the browser and dispatches the `dragenter`, `dragover` and `drop`
+    /// events at the target.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::{DragMode, Page};
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let card = page.find_element("#todo .card").await?;
+    ///     let column = page.find_element("#done").await?;
+    ///     page.drag_and_drop(&card, &column, DragMode::DragEvents).await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn drag_and_drop(
+        &self,
+        source: &Element,
+        target: &Element,
+        mode: DragMode,
+    ) -> Result<&Self> {
+        let from = source.scroll_into_view().await?.clickable_point().await?;
+        let to = target.scroll_into_view().await?.clickable_point().await?;
+
+        let mouse = |r#type, point: Point| {
+            DispatchMouseEventParams::builder()
+                .r#type(r#type)
+                .x(point.x)
+                .y(point.y)
+                .button(MouseButton::Left)
+                .buttons(1)
+                .click_count(1)
+                .build()
+                .unwrap()
+        };
+
+        match mode {
+            DragMode::Mouse => {
+                self.move_mouse(from).await?;
+                self.execute(mouse(DispatchMouseEventType::MousePressed, from))
+                    .await?;
+                for step in 1..=DRAG_STEPS {
+                    let progress = step as f64 / DRAG_STEPS as f64;
+                    let point = Point::new(
+                        from.x + (to.x - from.x) * progress,
+                        from.y + (to.y - from.y) * progress,
+                    );
+                    self.execute(mouse(DispatchMouseEventType::MouseMoved, point))
+                        .await?;
+                }
+                self.execute(mouse(DispatchMouseEventType::MouseReleased, to))
+                    .await?;
+            }
+            DragMode::DragEvents => {
+                let mut intercepted = self.event_listener::<EventDragIntercepted>().await?;
+                self.execute(SetInterceptDragsParams::new(true)).await?;
+
+                self.move_mouse(from).await?;
+                self.execute(mouse(DispatchMouseEventType::MousePressed, from))
+                    .await?;
+                self.execute(mouse(DispatchMouseEventType::MouseMoved, to))
+                    .await?;
+
+                let delay = futures_timer::Delay::new(DRAG_INTERCEPT_TIMEOUT);
+                let data = match future::select(intercepted.next(), delay).await {
+                    Either::Left((Some(event), _)) => Some(event.data.clone()),
+                    _ => None,
+                };
+                self.execute(SetInterceptDragsParams::new(false)).await?;
+
+                let data = match data {
+                    Some(data) => data,
+                    None => {
+                        self.execute(mouse(DispatchMouseEventType::MouseReleased, to))
+                            .await?;
+                        return Err(CdpError::msg("Source element can not be dragged"));
+                    }
+                };
+                for r#type in [
+                    DispatchDragEventType::DragEnter,
+                    DispatchDragEventType::DragOver,
+                    DispatchDragEventType::Drop,
+                ] {
+                    self.execute(DispatchDragEventParams::new(
+                        r#type,
+                        to.x,
+                        to.y,
+                        data.clone(),
+                    ))
+                    .await?;
+                }
+                self.execute(mouse(DispatchMouseEventType::MouseReleased, to))
+                    .await?;
+            }
+        }
+        Ok(self)
+    }
+
     /// Dispatches a `mousemove` event and moves the mouse to the position of
     /// the `point` where `Point.x` is the horizontal position of the mouse and
     /// `Point.y` the vertical position of the mouse.
@@ -569,6 +1763,23 @@ impl Page {
         self.inner.screenshot(params).await
     }
 
+    /// Brings the page to front and takes a screenshot once it rendered a new
+    /// frame.
+    ///
+    /// Tabs in the background of a headful browser don't paint, so a
+    /// screenshot taken directly after `Page::bring_to_front` can be blank or
+    /// stale.
+    pub async fn screenshot_focused(&self, params: impl Into<ScreenshotParams>) -> Result<Vec<u8>> {
+        self.bring_to_front().await?;
+        // the second animation frame callback only runs after the first frame
+        // was painted
+        self.evaluate_expression(
+            "new Promise(resolve => requestAnimationFrame(() => requestAnimationFrame(resolve)))",
+        )
+        .await?;
+        self.screenshot(params).await
+    }
+
     /// Save a screenshot of the page
     ///
     /// # Example save a png file of a website
@@ -604,21 +1815,96 @@ impl Page {
 
     /// Print the current page as pdf.
     ///
-    /// See [`PrintToPdfParams`]
+    /// See [`PrintToPdfParams`] and [`PdfBuilder`]
     ///
     /// # Note Generating a pdf is currently only supported in Chrome headless.
-    pub async fn pdf(&self, params: PrintToPdfParams) -> Result<Vec<u8>> {
-        let res = self.execute(params).await?;
+    pub async fn pdf(&self, params: impl Into<PrintToPdfParams>) -> Result<Vec<u8>> {
+        let res = self.execute(params.into()).await?;
         Ok(utils::base64::decode(&res.data)?)
     }
 
+    /// Print the current page as pdf and write it to the `writer` in chunks,
+    /// without holding the entire document in memory.
+    ///
+    /// See [`PrintToPdfParams`], the `transfer_mode` is always
+    /// `ReturnAsStream`.
+    ///
+    /// # Note Generating a pdf is currently only supported in Chrome headless.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # use chromiumoxide::cdp::browser_protocol::page::PrintToPdfParams;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let mut pdf = Vec::new();
+    ///     page.pdf_stream(PrintToPdfParams::default(), &mut pdf).await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn pdf_stream<W: AsyncWrite + Unpin>(
+        &self,
+        params: impl Into<PrintToPdfParams>,
+        writer: W,
+    ) -> Result<()> {
+        let mut params = params.into();
+        params.transfer_mode = Some(PrintToPdfTransferMode::ReturnAsStream);
+        let res = self.execute(params).await?;
+        let handle = res
+            .result
+            .stream
+            .ok_or_else(|| CdpError::msg("No stream handle returned"))?;
+        self.read_stream(handle, writer).await
+    }
+
+    /// Reads the `IO` stream with the `handle` into the `writer` and closes
+    /// the stream afterwards.
+    async fn read_stream<W: AsyncWrite + Unpin>(
+        &self,
+        handle: StreamHandle,
+        mut writer: W,
+    ) -> Result<()> {
+        let read = async {
+            loop {
+                let chunk = self
+                    .execute(
+                        ReadParams::builder()
+                            .handle(handle.clone())
+                            .size(IO_READ_CHUNK_SIZE)
+                            .build()
+                            .unwrap(),
+                    )
+                    .await?
+                    .result;
+                if chunk.base64_encoded.unwrap_or_default() {
+                    writer
+                        .write_all(&utils::base64::decode(&chunk.data)?)
+                        .await?;
+                } else {
+                    writer.write_all(chunk.data.as_bytes()).await?;
+                }
+                if chunk.eof {
+                    break;
+                }
+            }
+            writer.flush().await?;
+            Ok::<_, CdpError>(())
+        }
+        .await;
+        let close = self.execute(cdpio::CloseParams::new(handle)).await;
+        read?;
+        close?;
+        Ok(())
+    }
+
     /// Save the current page as pdf as file to the `output` path and return the
     /// pdf contents.
     ///
     /// # Note Generating a pdf is currently only supported in Chrome headless.
     pub async fn save_pdf(
         &self,
-        opts: PrintToPdfParams,
+        opts: impl Into<PrintToPdfParams>,
         output: impl AsRef<Path>,
     ) -> Result<Vec<u8>> {
         let pdf = self.pdf(opts).await?;
@@ -632,6 +1918,163 @@ impl Page {
         Ok(self)
     }
 
+    /// Scrolls the document to the absolute position `x`, `y` in CSS pixels
+    /// and resolves once the scroll position was rendered.
+    ///
+    /// The scroll is instant, even if the page uses `scroll-behavior: smooth`,
+    /// and triggers the page's `scroll` listeners.
+    ///
+    /// # Example screenshot at a known scroll offset
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::{Page, ScreenshotParams};
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.scroll_to(0., 1000.).await?;
+    ///     let png = page.screenshot(ScreenshotParams::builder().build()).await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn scroll_to(&self, x: f64, y: f64) -> Result<&Self> {
+        self.scroll("scrollTo", x, y).await
+    }
+
+    /// Scrolls the document by `dx`, `dy` CSS pixels relative to its current
+    /// scroll position, see [`Page::scroll_to`].
+    pub async fn scroll_by(&self, dx: f64, dy: f64) -> Result<&Self> {
+        self.scroll("scrollBy", dx, dy).await
+    }
+
+    /// Calls `window.scrollTo` or `window.scrollBy` and waits for the next
+    /// two animation frames, after which the new position was rendered.
+    async fn scroll(&self, function: &str, x: f64, y: f64) -> Result<&Self> {
+        self.evaluate_expression(
+            EvaluateParams::builder()
+                .expression(format!(
+                    "new Promise(resolve => {{
+                        window.{function}({{ left: {x}, top: {y}, behavior: 'instant' }});
+                        requestAnimationFrame(() => requestAnimationFrame(resolve));
+                    }})"
+                ))
+                .await_promise(true)
+                .build()
+                .unwrap(),
+        )
+        .await?;
+        Ok(self)
+    }
+
+    /// Selects all text of the focused `<input>`, `<textarea>` or
+    /// contenteditable element, or of the whole document if no such element
+    /// is focused, like pressing `Ctrl+A`.
+    pub async fn select_all_text(&self) -> Result<&Self> {
+        self.evaluate_expression("document.execCommand('selectAll')")
+            .await?;
+        Ok(self)
+    }
+
+    /// Grants the page's current origin the permissions to read and write
+    /// the clipboard.
+    ///
+    /// This is done implicitly by `Page::read_clipboard` and
+    /// `Page::write_clipboard`, but is useful to let the page's own
+    /// copy/paste buttons use `navigator.clipboard`.
+    ///
+    /// Fails for pages with an opaque origin, like `about:blank` or `data:`
+    /// urls, since the permissions can only be granted to those by granting
+    /// them to all origins.
+    pub async fn grant_clipboard_permissions(&self) -> Result<&Self> {
+        let info = self
+            .execute(
+                GetTargetInfoParams::builder()
+                    .target_id(self.target_id().clone())
+                    .build(),
+            )
+            .await?
+            .result
+            .target_info;
+        let url = url::Url::parse(&info.url)?;
+        let origin = match url.origin() {
+            origin if origin.is_tuple() => origin.ascii_serialization(),
+            // the origin of every `file://` url is opaque, but chromium treats
+            // them as the same origin
+            _ if url.scheme() == "file" => "file://".to_string(),
+            // granting the permissions without an origin would grant them to
+            // every origin of the browser context
+            _ => {
+                return Err(CdpError::msg(format!(
+                    "Can't grant clipboard permissions to the opaque origin of {}",
+                    info.url
+                )))
+            }
+        };
+        let mut params = GrantPermissionsParams::new(vec![
+            PermissionType::ClipboardReadWrite,
+            PermissionType::ClipboardSanitizedWrite,
+        ]);
+        params.origin = Some(origin);
+        params.browser_context_id = info.browser_context_id;
+        self.inner.execute_on_browser(params).await?;
+        Ok(self)
+    }
+
+    /// Returns the text content of the clipboard.
+    ///
+    /// This grants the page's origin the clipboard permissions and reads the
+    /// clipboard with `navigator.clipboard.readText()` as if triggered by a
+    /// user gesture. The Clipboard API is only available in secure contexts,
+    /// i.e. pages served over `https`, from `localhost` or a `file://` url,
+    /// and only while the page is focused, see `Page::bring_to_front` and
+    /// `Page::set_focus_emulation_enabled`.
+    ///
+    /// # Example test a copy to clipboard button
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.grant_clipboard_permissions().await?;
+    ///     page.find_element("button#copy").await?.click().await?;
+    ///     let text = page.read_clipboard().await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_clipboard(&self) -> Result<String> {
+        self.grant_clipboard_permissions().await?;
+        Ok(self
+            .evaluate_expression(
+                EvaluateParams::builder()
+                    .expression("navigator.clipboard.readText()")
+                    .await_promise(true)
+                    .user_gesture(true)
+                    .build()
+                    .map_err(CdpError::msg)?,
+            )
+            .await?
+            .into_value()?)
+    }
+
+    /// Writes the `text` to the clipboard.
+    ///
+    /// This has the same permission and secure context requirements as
+    /// `Page::read_clipboard`.
+    pub async fn write_clipboard(&self, text: impl AsRef<str>) -> Result<&Self> {
+        self.grant_clipboard_permissions().await?;
+        self.evaluate_expression(
+            EvaluateParams::builder()
+                .expression(format!(
+                    "navigator.clipboard.writeText({})",
+                    serde_json::to_string(text.as_ref())?
+                ))
+                .await_promise(true)
+                .user_gesture(true)
+                .build()
+                .map_err(CdpError::msg)?,
+        )
+        .await?;
+        Ok(self)
+    }
+
     /// Emulates the given media type or media feature for CSS media queries
     pub async fn emulate_media_features(&self, features: Vec<MediaFeature>) -> Result<&Self> {
         self.execute(SetEmulatedMediaParams::builder().features(features).build())
@@ -639,21 +2082,229 @@ impl Page {
         Ok(self)
     }
 
-    /// Overrides default host system timezone
+    /// Emulates the device metrics of the viewport, overriding the viewport
+    /// the browser was configured with.
+    ///
+    /// The screen is emulated with the given `orientation`, like
+    /// `ScreenOrientationType::LandscapeSecondary` at 270 degrees, or with the
+    /// primary orientation of `Viewport::is_landscape` if it is `None`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # use chromiumoxide::handler::viewport::Viewport;
+    /// # use chromiumoxide::cdp::browser_protocol::emulation::{ScreenOrientation, ScreenOrientationType};
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.emulate_viewport(
+    ///         &Viewport {
+    ///             width: 844,
+    ///             height: 390,
+    ///             emulating_mobile: true,
+    ///             is_landscape: true,
+    ///             has_touch: true,
+    ///             ..Default::default()
+    ///         },
+    ///         Some(ScreenOrientation::new(
+    ///             ScreenOrientationType::LandscapeSecondary,
+    ///             270,
+    ///         )),
+    ///     )
+    ///     .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn emulate_viewport(
+        &self,
+        viewport: &EmulatedViewport,
+        orientation: Option<ScreenOrientation>,
+    ) -> Result<&Self> {
+        let orientation = orientation.unwrap_or_else(|| viewport.screen_orientation());
+        self.execute(
+            SetDeviceMetricsOverrideParams::builder()
+                .mobile(viewport.emulating_mobile)
+                .width(viewport.width)
+                .height(viewport.height)
+                .device_scale_factor(viewport.device_scale_factor.unwrap_or(1.))
+                .screen_orientation(orientation)
+                .build()
+                .unwrap(),
+        )
+        .await?;
+        self.execute(SetTouchEmulationEnabledParams::new(viewport.has_touch))
+            .await?;
+        Ok(self)
+    }
+
+    /// Emulates the network conditions, like latency and throughput, for all
+    /// requests of this page.
+    ///
+    /// The `Network` domain this relies on is enabled for every page during
+    /// its initialization.
+    pub async fn emulate_network_conditions(
+        &self,
+        conditions: impl Into<EmulateNetworkConditionsParams>,
+    ) -> Result<&Self> {
+        self.execute(conditions.into()).await?;
+        Ok(self)
+    }
+
+    /// Emulates one of the network presets of Chrome DevTools, see
+    /// [`Page::emulate_network_conditions`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::{Bandwidth, Page};
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.emulate_bandwidth(Bandwidth::Slow3G).await?;
+    ///     page.goto("https://example.com").await?;
+    ///     page.emulate_bandwidth(Bandwidth::NoThrottling).await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn emulate_bandwidth(&self, bandwidth: Bandwidth) -> Result<&Self> {
+        self.emulate_network_conditions(bandwidth).await
+    }
+
+    /// Emulates the media type for CSS media queries, e.g. to render the page
+    /// with its print styles before taking a screenshot.
+    ///
+    /// This resets the media features set by
+    /// [`Page::emulate_media_features`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::{MediaType, Page};
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.set_media_type(MediaType::Print).await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_media_type(&self, media_type: MediaType) -> Result<&Self> {
+        self.execute(
+            SetEmulatedMediaParams::builder()
+                .media(media_type.as_ref())
+                .build(),
+        )
+        .await?;
+        Ok(self)
+    }
+
+    /// Overrides default host system timezone with the IANA timezone id, like
+    /// `"Asia/Tokyo"`. An empty id disables the override.
+    ///
+    /// Fails with [`CdpError::InvalidTimezone`] if the id is malformed or the
+    /// browser doesn't know the timezone.
     pub async fn emulate_timezone(
         &self,
         timezoune_id: impl Into<SetTimezoneOverrideParams>,
     ) -> Result<&Self> {
-        self.execute(timezoune_id.into()).await?;
+        let params = timezoune_id.into();
+        if !is_valid_timezone_id(&params.timezone_id) {
+            return Err(CdpError::InvalidTimezone(params.timezone_id));
+        }
+        let timezone_id = params.timezone_id.clone();
+        self.execute(params)
+            .await
+            .map_err(|err| timezone_error(err, timezone_id))?;
+        Ok(self)
+    }
+
+    /// Overrides default host system locale with the specified one.
+    ///
+    /// A [`LocaleConfig`] applies the timezone, locale and geolocation of an
+    /// emulated user consistently: the locale is set with `Page::set_locale`
+    /// and is used for the `Intl` APIs as well as `navigator.language(s)` and
+    /// the `Accept-Language` header.
+    ///
+    /// # Example emulate a user in Tokyo
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::{LocaleConfig, Page};
+    /// # use chromiumoxide::cdp::browser_protocol::emulation::SetGeolocationOverrideParams;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.emulate_locale(LocaleConfig {
+    ///         timezone: Some("Asia/Tokyo".to_string()),
+    ///         locale: Some("ja_JP".to_string()),
+    ///         geolocation: Some(
+    ///             SetGeolocationOverrideParams::builder()
+    ///                 .latitude(35.6762)
+    ///                 .longitude(139.6503)
+    ///                 .accuracy(100.)
+    ///                 .build(),
+    ///         ),
+    ///     })
+    ///     .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn emulate_locale(&self, locale: impl Into<LocaleConfig>) -> Result<&Self> {
+        let LocaleConfig {
+            timezone,
+            locale,
+            geolocation,
+        } = locale.into();
+
+        if let Some(timezone) = timezone {
+            self.emulate_timezone(timezone).await?;
+        }
+        if let Some(locale) = locale {
+            self.set_locale(locale).await?;
+        }
+        if let Some(geolocation) = geolocation {
+            self.emulate_geolocation(geolocation).await?;
+        }
         Ok(self)
     }
 
-    /// Overrides default host system locale with the specified one
-    pub async fn emulate_locale(
-        &self,
-        locale: impl Into<SetLocaleOverrideParams>,
-    ) -> Result<&Self> {
-        self.execute(locale.into()).await?;
+    /// Overrides the locale of the page with the ICU style `locale` (e.g.
+    /// `"de_DE"`), which is used for the `Intl` APIs, `navigator.language(s)`
+    /// and the `Accept-Language` header of all requests of this page. An empty
+    /// `locale` resets the override.
+    ///
+    /// The accept language is set with the user agent override of the page,
+    /// which keeps the user agent, platform and client hints previously set
+    /// with `Page::set_user_agent`.
+    ///
+    /// # Note
+    ///
+    /// The browser is launched with `--lang=en_US` by default, which
+    /// determines the locale of new pages. To launch the browser with a
+    /// different locale, pass a `--lang` argument via
+    /// `BrowserConfigBuilder::arg`, which replaces the default one.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.set_locale("de_DE").await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_locale(&self, locale: impl Into<String>) -> Result<&Self> {
+        let locale = locale.into();
+        let accept_language = (!locale.is_empty()).then(|| locale.replace('_', "-"));
+        self.execute(SetLocaleOverrideParams::builder().locale(locale).build())
+            .await?;
+
+        let current = self.inner.user_agent_override().lock().unwrap().clone();
+        let mut params = match current {
+            Some(params) => params,
+            // nothing to reset
+            None if accept_language.is_none() => return Ok(self),
+            None => SetUserAgentOverrideParams::new(self.user_agent().await?),
+        };
+        params.accept_language = accept_language;
+        self.execute(params.clone()).await?;
+        *self.inner.user_agent_override().lock().unwrap() = Some(params);
         Ok(self)
     }
 
@@ -668,20 +2319,93 @@ impl Page {
 
     /// Reloads given page
     ///
-    /// To reload ignoring cache run:
+    /// To reload ignoring cache or to wait for a different lifecycle event use
+    /// [`Page::reload_with`].
+    pub async fn reload(&self) -> Result<&Self> {
+        self.execute(ReloadParams::default()).await?;
+        self.wait_for_navigation().await
+    }
+
+    /// Reloads the page with the given [`ReloadOptions`] and waits until the
+    /// main frame received the configured lifecycle event.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::{LifecycleEvent, Page, ReloadOptions};
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let response = page
+    ///         .reload_with(ReloadOptions {
+    ///             ignore_cache: true,
+    ///             wait_until: LifecycleEvent::DomcontentLoaded,
+    ///             ..Default::default()
+    ///         })
+    ///         .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn reload_with(&self, options: ReloadOptions) -> Result<NavigationResponse> {
+        let ReloadOptions {
+            ignore_cache,
+            wait_until,
+            script_to_evaluate_on_load,
+        } = options;
+        let mut params = ReloadParams::builder().ignore_cache(ignore_cache);
+        if let Some(script) = script_to_evaluate_on_load {
+            params = params.script_to_evaluate_on_load(script);
+        }
+        self.execute(params.build()).await?;
+
+        let request = self.inner.wait_for_lifecycle_event(wait_until).await?;
+        let response = request.as_ref().and_then(|req| req.response.as_ref());
+        let url = match response {
+            Some(response) => Some(response.url.clone()),
+            None => self.url().await?,
+        };
+        Ok(NavigationResponse {
+            url,
+            status: response.map(|response| response.status),
+            same_document: false,
+            request,
+        })
+    }
+
+    /// Sets the timeout of all following navigations of this page, like
+    /// [`Page::goto`], overriding `BrowserConfigBuilder::navigation_timeout`.
+    pub async fn set_default_navigation_timeout(&self, timeout: Duration) -> Result<&Self> {
+        self.inner.set_navigation_timeout(timeout);
+        self.inner
+            .sender()
+            .clone()
+            .send(TargetMessage::SetNavigationTimeout(timeout))
+            .await?;
+        Ok(self)
+    }
+
+    /// Stops loading the page, like pressing the browser's stop button.
+    ///
+    /// Pending navigations resolve once the frame stopped loading, so this
+    /// can be used to abort a navigation that takes too long:
+    ///
     /// ```no_run
+    /// # use std::time::Duration;
+    /// # use futures::future::{self, Either};
     /// # use chromiumoxide::page::Page;
     /// # use chromiumoxide::error::Result;
-    /// # use chromiumoxide_cdp::cdp::browser_protocol::page::ReloadParams;
     /// # async fn demo(page: Page) -> Result<()> {
-    ///     page.execute(ReloadParams::builder().ignore_cache(true).build()).await?;
-    ///     page.wait_for_navigation().await?;
+    ///     let navigation = Box::pin(page.wait_for_navigation());
+    ///     let timeout = futures_timer::Delay::new(Duration::from_secs(5));
+    ///     if let Either::Right(_) = future::select(navigation, timeout).await {
+    ///         page.stop_loading().await?;
+    ///     }
+    ///     let html = page.content().await?;
     ///     # Ok(())
     /// # }
     /// ```
-    pub async fn reload(&self) -> Result<&Self> {
-        self.execute(ReloadParams::default()).await?;
-        self.wait_for_navigation().await
+    pub async fn stop_loading(&self) -> Result<&Self> {
+        self.execute(StopLoadingParams::default()).await?;
+        Ok(self)
     }
 
     /// Enables log domain. Enabled by default.
@@ -696,6 +2420,33 @@ impl Page {
         Ok(self)
     }
 
+    /// Returns a stream of the entries of the log domain, like CORS errors,
+    /// mixed content warnings, deprecations and failed requests, which
+    /// aren't reported to the page's `console`.
+    ///
+    /// This enables the log domain, so the entries collected before are
+    /// sent as well.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # use futures::StreamExt;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let mut entries = page.log_listener().await?;
+    ///     while let Some(entry) = entries.next().await {
+    ///         println!("{:?} {:?}: {}", entry.level, entry.source, entry.text);
+    ///     }
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn log_listener(&self) -> Result<impl Stream<Item = LogEntry>> {
+        let events = self.event_listener::<EventEntryAdded>().await?;
+        self.enable_log().await?;
+        Ok(events.map(|event| event.entry.clone()))
+    }
+
     /// Disables log domain
     ///
     /// Prevents further log entries from being reported to the client
@@ -781,20 +2532,36 @@ impl Page {
     /// Set a single cookie
     ///
     /// This fails if the cookie's url or if not provided, the page's url is
-    /// `about:blank` or a `data:` url.
+    /// `about:blank` or a `data:` url, or if the cookie is `SameSite=None`
+    /// without being `secure`.
     ///
     /// # Example
     /// ```no_run
     /// # use chromiumoxide::page::Page;
     /// # use chromiumoxide::error::Result;
-    /// # use chromiumoxide_cdp::cdp::browser_protocol::network::CookieParam;
+    /// # use chromiumoxide_cdp::cdp::browser_protocol::network::{CookieParam, CookiePriority, CookieSameSite};
     /// # async fn demo(page: Page) -> Result<()> {
     ///     page.set_cookie(CookieParam::new("Cookie-name", "Cookie-value")).await?;
+    ///
+    ///     let cookie = CookieParam::builder()
+    ///         .name("session")
+    ///         .value("secret")
+    ///         .domain("example.com")
+    ///         .path("/")
+    ///         .secure(true)
+    ///         .http_only(true)
+    ///         .same_site(CookieSameSite::None)
+    ///         .expires(1_900_000_000.)
+    ///         .priority(CookiePriority::High)
+    ///         .build()
+    ///         .unwrap();
+    ///     page.set_cookie(cookie).await?;
     ///     # Ok(())
     /// # }
     /// ```
     pub async fn set_cookie(&self, cookie: impl Into<CookieParam>) -> Result<&Self> {
         let mut cookie = cookie.into();
+        validate_cookie(&cookie)?;
         if let Some(url) = cookie.url.as_ref() {
             validate_cookie_url(url)?;
         } else {
@@ -825,6 +2592,7 @@ impl Page {
         }
 
         for cookie in &mut cookies {
+            validate_cookie(cookie)?;
             if let Some(url) = cookie.url.as_ref() {
                 validate_cookie_url(url)?;
             } else if is_http {
@@ -880,6 +2648,34 @@ impl Page {
         Ok(self)
     }
 
+    /// Deletes all cookies of the tab's current URL with the given name.
+    pub async fn delete_cookies_by_name(&self, name: impl AsRef<str>) -> Result<&Self> {
+        let name = name.as_ref();
+        let cookies = self
+            .get_cookies()
+            .await?
+            .into_iter()
+            .filter(|cookie| cookie.name == name)
+            .collect::<Vec<_>>();
+        self.delete_cookies_unchecked(
+            cookies
+                .iter()
+                .map(|cookie| DeleteCookiesParams::from_cookie(&cookie.into())),
+        )
+        .await
+    }
+
+    /// Deletes all cookies that match the tab's current URL.
+    pub async fn clear_cookies(&self) -> Result<&Self> {
+        let cookies = self.get_cookies().await?;
+        self.delete_cookies_unchecked(
+            cookies
+                .iter()
+                .map(|cookie| DeleteCookiesParams::from_cookie(&cookie.into())),
+        )
+        .await
+    }
+
     /// Convenience method that prevents another channel roundtrip to get the
     /// url and validate it
     async fn delete_cookies_unchecked(
@@ -917,6 +2713,37 @@ impl Page {
             .metrics)
     }
 
+    /// Retrieve current values of run-time metrics as [`PerformanceMetrics`].
+    ///
+    /// Metrics that are not reported by the browser are `None`, use
+    /// [`Page::metrics`] to access metrics that are not covered here.
+    pub async fn performance_metrics(&self) -> Result<PerformanceMetrics> {
+        Ok(PerformanceMetrics::from(self.metrics().await?.as_slice()))
+    }
+
+    /// Returns the navigation history of the page, i.e. all its entries and
+    /// the index of the current entry.
+    ///
+    /// # Example print the urls of all entries
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let history = page.navigation_history().await?;
+    ///     for entry in &history.entries {
+    ///         println!("{} {}", entry.id, entry.url);
+    ///     }
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn navigation_history(&self) -> Result<GetNavigationHistoryReturns> {
+        Ok(self
+            .execute(GetNavigationHistoryParams::default())
+            .await?
+            .result)
+    }
+
     /// Returns metrics relating to the layout of the page
     pub async fn layout_metrics(&self) -> Result<GetLayoutMetricsReturns> {
         self.inner.layout_metrics().await
@@ -1017,13 +2844,7 @@ impl Page {
                 if expr.context_id.is_none() {
                     expr.context_id = self.execution_context().await?;
                 }
-                let fallback = expr.eval_as_function_fallback.and_then(|p| {
-                    if p {
-                        Some(expr.clone())
-                    } else {
-                        None
-                    }
-                });
+                let fallback = js::function_fallback(&expr);
                 let res = self.evaluate_expression(expr).await?;
 
                 if res.object().r#type == RemoteObjectType::Function {
@@ -1097,6 +2918,152 @@ impl Page {
         self.inner.evaluate_function(evaluate).await
     }
 
+    /// Same as [`Page::evaluate`], but fails with [`CdpError::Timeout`] if
+    /// the evaluation, including the promise it returns, doesn't complete
+    /// within `timeout`.
+    ///
+    /// On timeout the evaluation is abandoned and its result discarded once
+    /// it arrives. A `timeout` longer than the request timeout of the
+    /// handler (`HandlerConfig::request_timeout`) has no effect, the
+    /// evaluation then fails with [`CdpError::CommandTimeout`] like every
+    /// other command.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::{CdpError, Result};
+    /// # use std::time::Duration;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let result = page
+    ///         .evaluate_with_timeout("new Promise(() => {})", Duration::from_secs(1))
+    ///         .await;
+    ///     assert!(matches!(result, Err(CdpError::Timeout)));
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn evaluate_with_timeout(
+        &self,
+        evaluate: impl Into<Evaluation>,
+        timeout: Duration,
+    ) -> Result<EvaluationResult> {
+        with_timeout(self.evaluate(evaluate), timeout).await
+    }
+
+    /// Same as [`Page::evaluate_function`], but fails with
+    /// [`CdpError::Timeout`] if the function, including the promise it
+    /// returns, doesn't complete within `timeout`, see
+    /// [`Page::evaluate_with_timeout`].
+    pub async fn evaluate_function_with_timeout(
+        &self,
+        evaluate: impl Into<CallFunctionOnParams>,
+        timeout: Duration,
+    ) -> Result<EvaluationResult> {
+        with_timeout(self.evaluate_function(evaluate), timeout).await
+    }
+
+    /// Same as [`Page::evaluate_function`], but returns a [`JsHandle`] to the
+    /// result instead of serializing it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let links = page
+    ///         .evaluate_function_handle("() => document.links")
+    ///         .await?;
+    ///     let count: usize = links
+    ///         .call_js_fn("function() { return this.length; }", false)
+    ///         .await?
+    ///         .result
+    ///         .value
+    ///         .and_then(|v| v.as_u64())
+    ///         .unwrap_or_default() as usize;
+    ///     links.release().await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn evaluate_function_handle(
+        &self,
+        evaluate: impl Into<CallFunctionOnParams>,
+    ) -> Result<JsHandle> {
+        let mut evaluate = evaluate.into();
+        evaluate.return_by_value = Some(false);
+        let res = self.inner.evaluate_function(evaluate).await?;
+        Ok(JsHandle::new(self.inner.clone(), res.object().clone()))
+    }
+
+    /// Returns a [`JsHandle`] to an array of all objects in the page's heap
+    /// with the prototype of the `prototype` handle.
+    ///
+    /// # Example count all instances of a class
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let prototype = page
+    ///         .evaluate_function_handle("() => Map.prototype")
+    ///         .await?;
+    ///     let maps = page.query_objects(&prototype).await?;
+    ///     let count = maps
+    ///         .call_js_fn("function() { return this.length; }", false)
+    ///         .await?
+    ///         .result
+    ///         .value
+    ///         .and_then(|v| v.as_u64())
+    ///         .unwrap_or_default();
+    ///     maps.release().await?;
+    ///     prototype.release().await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_objects(&self, prototype: &JsHandle) -> Result<JsHandle> {
+        let prototype_id = prototype
+            .object_id()
+            .cloned()
+            .ok_or_else(|| CdpError::msg("The prototype handle doesn't refer to an object"))?;
+        let objects = self
+            .execute(QueryObjectsParams::new(prototype_id))
+            .await?
+            .result
+            .objects;
+        Ok(JsHandle::new(self.inner.clone(), objects))
+    }
+
+    /// Evaluates an expression in the given world of the page's main frame.
+    ///
+    /// Scripts evaluated in [`DOMWorldKind::Secondary`] share the DOM with the
+    /// page but not its javascript globals, so they can neither be tampered
+    /// with by the page's scripts nor clobber them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::{DOMWorldKind, Page};
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let links: usize = page
+    ///         .evaluate_in_world("document.links.length", DOMWorldKind::Secondary)
+    ///         .await?
+    ///         .into_value()?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn evaluate_in_world(
+        &self,
+        evaluate: impl Into<EvaluateParams>,
+        world: DOMWorldKind,
+    ) -> Result<EvaluationResult> {
+        let mut evaluate = evaluate.into();
+        if evaluate.context_id.is_none() {
+            evaluate.context_id = self.inner.execution_context_for_world(None, world).await?;
+        }
+        self.inner.evaluate_expression(evaluate).await
+    }
+
     /// Returns the default execution context identifier of this page that
     /// represents the context for JavaScript execution.
     pub async fn execution_context(&self) -> Result<Option<ExecutionContextId>> {
@@ -1135,6 +3102,28 @@ impl Page {
         Ok(self.execute(script.into()).await?.result.identifier)
     }
 
+    /// Reads the javascript file at `path` and evaluates it in every frame
+    /// upon creation, see [`Page::evaluate_on_new_document`].
+    pub async fn add_init_script_file(
+        &self,
+        path: impl AsRef<Path> + Unpin,
+    ) -> Result<ScriptIdentifier> {
+        let script = utils::read_to_string(path).await?;
+        self.evaluate_on_new_document(script).await
+    }
+
+    /// Removes a script that was added with
+    /// [`Page::evaluate_on_new_document`], so it is no longer evaluated in new
+    /// documents.
+    pub async fn remove_script_to_evaluate_on_new_document(
+        &self,
+        identifier: impl Into<ScriptIdentifier>,
+    ) -> Result<&Self> {
+        self.execute(RemoveScriptToEvaluateOnNewDocumentParams::new(identifier))
+            .await?;
+        Ok(self)
+    }
+
     /// Set the content of the frame.
     ///
     /// # Example
@@ -1149,6 +3138,37 @@ impl Page {
     /// # }
     /// ```
     pub async fn set_content(&self, html: impl AsRef<str>) -> Result<&Self> {
+        self.set_content_with(html, SetContentOptions::default())
+            .await
+    }
+
+    /// Set the content of the frame with the given [`SetContentOptions`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use chromiumoxide::page::{LifecycleEvent, Page, SetContentOptions};
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     page.set_content_with(
+    ///         r#"<img src="logo.png">"#,
+    ///         SetContentOptions {
+    ///             base_url: Some("https://example.com/assets/".to_string()),
+    ///             wait_until: LifecycleEvent::NetworkIdle,
+    ///         },
+    ///     )
+    ///     .await?;
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_content_with(
+        &self,
+        html: impl AsRef<str>,
+        options: SetContentOptions,
+    ) -> Result<&Self> {
+        let html = match options.base_url.as_ref() {
+            Some(base_url) => with_base_url(html.as_ref(), base_url),
+            None => html.as_ref().to_string(),
+        };
         let mut call = CallFunctionOnParams::builder()
             .function_declaration(
                 "(html) => {
@@ -1159,41 +3179,201 @@ impl Page {
             )
             .argument(
                 CallArgument::builder()
-                    .value(serde_json::json!(html.as_ref()))
+                    .value(serde_json::json!(html))
+                    .build(),
+            )
+            .build()
+            .unwrap();
+
+        call.execution_context_id = self
+            .inner
+            .execution_context_for_world(None, DOMWorldKind::Secondary)
+            .await?;
+
+        self.evaluate_function(call).await?;
+        // relying that document.open() will reset frame lifecycle with "init"
+        // lifecycle event. @see https://crrev.com/608658
+        self.inner
+            .wait_for_lifecycle_event(options.wait_until)
+            .await?;
+        Ok(self)
+    }
+
+    /// Returns the HTML content of the page
+    pub async fn content(&self) -> Result<String> {
+        Ok(self
+            .evaluate(
+                "{
+          let retVal = '';
+          if (document.doctype) {
+            retVal = new XMLSerializer().serializeToString(document.doctype);
+          }
+          if (document.documentElement) {
+            retVal += document.documentElement.outerHTML;
+          }
+          retVal
+      }
+      ",
+            )
+            .await?
+            .into_value()?)
+    }
+
+    /// Returns the tree of the page's frames with the resources, like
+    /// scripts, images and stylesheets, each of them loaded.
+    pub async fn resource_tree(&self) -> Result<FrameResourceTree> {
+        Ok(self
+            .execute(GetResourceTreeParams::default())
+            .await?
+            .result
+            .frame_tree)
+    }
+
+    /// Returns the content of the resource with the `url` that was loaded by
+    /// the frame, exactly as the browser received it, without requesting it
+    /// again.
+    ///
+    /// # Example save all images of the main frame
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::page::Page;
+    /// # use chromiumoxide::error::Result;
+    /// # use chromiumoxide::cdp::browser_protocol::network::ResourceType;
+    /// # async fn demo(page: Page) -> Result<()> {
+    ///     let tree = page.resource_tree().await?;
+    ///     for resource in tree.resources {
+    ///         if resource.r#type == ResourceType::Image {
+    ///             let bytes = page
+    ///                 .resource_content(tree.frame.id.clone(), resource.url)
+    ///                 .await?;
+    ///         }
+    ///     }
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn resource_content(
+        &self,
+        frame_id: impl Into<FrameId>,
+        url: impl Into<String>,
+    ) -> Result<Vec<u8>> {
+        let res = self
+            .execute(GetResourceContentParams::new(frame_id, url))
+            .await?
+            .result;
+        if res.base64_encoded {
+            Ok(utils::base64::decode(&res.content)?)
+        } else {
+            Ok(res.content.into_bytes())
+        }
+    }
+
+    /// Returns the HTML content of the page as a self-contained document,
+    /// which is lighter than an MHTML snapshot.
+    ///
+    /// Images and fonts referenced by `<img src>` or CSS `url()` are
+    /// replaced with data URIs and linked stylesheets with `<style>`
+    /// elements. The resources are taken from the browser's cache, so
+    /// nothing is requested again. Resources of child frames and resources
+    /// the browser no longer retains are kept as references.
+    pub async fn content_inlined(&self) -> Result<String> {
+        let tree = self.resource_tree().await?;
+        let frame_id = tree.frame.id;
+
+        let mut data_uris = serde_json::Map::new();
+        let mut stylesheets = serde_json::Map::new();
+        for resource in tree.resources {
+            if !matches!(
+                resource.r#type,
+                ResourceType::Image | ResourceType::Font | ResourceType::Stylesheet
+            ) {
+                continue;
+            }
+            let content = match self
+                .resource_content(frame_id.clone(), resource.url.clone())
+                .await
+            {
+                Ok(content) => content,
+                Err(_) => continue,
+            };
+            if resource.r#type == ResourceType::Stylesheet {
+                let css = String::from_utf8_lossy(&content).into_owned();
+                stylesheets.insert(resource.url, css.into());
+            } else {
+                data_uris.insert(
+                    resource.url,
+                    format!(
+                        "data:{};base64,{}",
+                        resource.mime_type,
+                        utils::base64::encode(content)
+                    )
+                    .into(),
+                );
+            }
+        }
+
+        let call = CallFunctionOnParams::builder()
+            .function_declaration(
+                r#"(dataUris, stylesheets) => {
+            const resolve = (url, base) => {
+                try {
+                    return new URL(url, base).href;
+                } catch (e) {
+                    return url;
+                }
+            };
+            const inlineUrls = (css, base) => css.replace(
+                /url\(\s*(['"]?)([^'")]+)\1\s*\)/g,
+                (match, quote, url) => {
+                    const dataUri = dataUris[resolve(url, base)];
+                    return dataUri ? `url("${dataUri}")` : match;
+                });
+
+            const root = document.documentElement.cloneNode(true);
+            for (const img of root.querySelectorAll('img[src]')) {
+                const dataUri = dataUris[resolve(img.getAttribute('src'), document.baseURI)];
+                if (dataUri) {
+                    img.setAttribute('src', dataUri);
+                    img.removeAttribute('srcset');
+                }
+            }
+            for (const style of root.querySelectorAll('style')) {
+                style.textContent = inlineUrls(style.textContent, document.baseURI);
+            }
+            for (const el of root.querySelectorAll('[style]')) {
+                el.setAttribute('style', inlineUrls(el.getAttribute('style'), document.baseURI));
+            }
+            for (const link of root.querySelectorAll('link[rel~="stylesheet"][href]')) {
+                const href = resolve(link.getAttribute('href'), document.baseURI);
+                if (href in stylesheets) {
+                    const style = document.createElement('style');
+                    if (link.media) {
+                        style.media = link.media;
+                    }
+                    style.textContent = inlineUrls(stylesheets[href], href);
+                    link.replaceWith(style);
+                }
+            }
+
+            let html = '';
+            if (document.doctype) {
+                html = new XMLSerializer().serializeToString(document.doctype);
+            }
+            return html + root.outerHTML;
+        }"#,
+            )
+            .argument(
+                CallArgument::builder()
+                    .value(serde_json::Value::Object(data_uris))
+                    .build(),
+            )
+            .argument(
+                CallArgument::builder()
+                    .value(serde_json::Value::Object(stylesheets))
                     .build(),
             )
             .build()
             .unwrap();
-
-        call.execution_context_id = self
-            .inner
-            .execution_context_for_world(None, DOMWorldKind::Secondary)
-            .await?;
-
-        self.evaluate_function(call).await?;
-        // relying that document.open() will reset frame lifecycle with "init"
-        // lifecycle event. @see https://crrev.com/608658
-        self.wait_for_navigation().await
-    }
-
-    /// Returns the HTML content of the page
-    pub async fn content(&self) -> Result<String> {
-        Ok(self
-            .evaluate(
-                "{
-          let retVal = '';
-          if (document.doctype) {
-            retVal = new XMLSerializer().serializeToString(document.doctype);
-          }
-          if (document.documentElement) {
-            retVal += document.documentElement.outerHTML;
-          }
-          retVal
-      }
-      ",
-            )
-            .await?
-            .into_value()?)
+        Ok(self.evaluate_function(call).await?.into_value()?)
     }
 
     #[cfg(feature = "bytes")]
@@ -1235,6 +3415,42 @@ impl From<Arc<PageInner>> for Page {
     }
 }
 
+/// Browsers reject `SameSite=None` cookies that are not `secure`.
+fn validate_cookie(cookie: &CookieParam) -> Result<()> {
+    if cookie.same_site == Some(CookieSameSite::None) && cookie.secure != Some(true) {
+        Err(CdpError::msg(format!(
+            "Cookie `{}` with SameSite=None must be secure",
+            cookie.name
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Inserts a `<base>` element with the `base_url` into the `html`, after its
+/// doctype if present so the document doesn't switch to quirks mode.
+fn with_base_url(html: &str, base_url: &str) -> String {
+    let href = base_url
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;");
+    let base = format!(r#"<base href="{href}">"#);
+    let doctype_end = if html
+        .trim_start()
+        .get(..9)
+        .map(|s| s.eq_ignore_ascii_case("<!doctype"))
+        .unwrap_or_default()
+    {
+        html.find('>').map(|idx| idx + 1)
+    } else {
+        None
+    };
+    match doctype_end {
+        Some(idx) => format!("{}{base}{}", &html[..idx], &html[idx..]),
+        None => format!("{base}{html}"),
+    }
+}
+
 fn validate_cookie_url(url: &str) -> Result<()> {
     if url.starts_with("data:") {
         Err(CdpError::msg("Data URL page can not have cookie"))
@@ -1245,6 +3461,415 @@ fn validate_cookie_url(url: &str) -> Result<()> {
     }
 }
 
+/// Options for [`Page::set_content_with`].
+#[derive(Debug, Clone, Default)]
+pub struct SetContentOptions {
+    /// The URL relative resources of the content are resolved against,
+    /// inserted as a `<base>` element.
+    pub base_url: Option<String>,
+    /// The lifecycle event of the main frame to wait for.
+    pub wait_until: LifecycleEvent,
+}
+
+/// Options for [`Page::reload_with`].
+#[derive(Debug, Clone, Default)]
+pub struct ReloadOptions {
+    /// Reload the page ignoring the browser cache, like a shift refresh.
+    pub ignore_cache: bool,
+    /// The lifecycle event of the main frame to wait for.
+    pub wait_until: LifecycleEvent,
+    /// A script that is injected into all frames of the reloaded page before
+    /// any of its scripts run.
+    pub script_to_evaluate_on_load: Option<String>,
+}
+
+/// The outcome of a navigation of the main frame, see
+/// [`Page::goto_with_response`].
+#[derive(Debug, Clone)]
+pub struct NavigationResponse {
+    /// The final URL of the main frame, after all redirects.
+    pub url: Option<String>,
+    /// The HTTP status code of the main document's response, `None` for
+    /// same-document navigations or if no response was received.
+    pub status: Option<i64>,
+    /// Whether the navigation only changed the URL of the current document,
+    /// e.g. when navigating to a different fragment.
+    pub same_document: bool,
+    /// The request that loaded the main document, including its redirect
+    /// chain.
+    pub request: ArcHttpRequest,
+}
+
+/// When to retry a failed navigation, see [`Page::goto_with_retry`].
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// How often a failed navigation is retried.
+    pub max_retries: u32,
+    /// How long to wait before the first retry, doubled for every further
+    /// retry.
+    pub backoff: Duration,
+    /// The chromium network errors that are retried, like
+    /// `net::ERR_CONNECTION_RESET`.
+    pub retry_on: Vec<String>,
+}
+
+impl RetryPolicy {
+    /// The network errors that are usually caused by a temporary failure of
+    /// the network or the server, retried by default.
+    pub const TRANSIENT_NET_ERRORS: [&'static str; 10] = [
+        "net::ERR_CONNECTION_RESET",
+        "net::ERR_CONNECTION_CLOSED",
+        "net::ERR_CONNECTION_REFUSED",
+        "net::ERR_CONNECTION_TIMED_OUT",
+        "net::ERR_TIMED_OUT",
+        "net::ERR_EMPTY_RESPONSE",
+        "net::ERR_NETWORK_CHANGED",
+        "net::ERR_INTERNET_DISCONNECTED",
+        "net::ERR_HTTP2_PROTOCOL_ERROR",
+        "net::ERR_QUIC_PROTOCOL_ERROR",
+    ];
+
+    /// Whether the navigation that failed with `err` should be retried.
+    pub fn is_retryable(&self, err: &CdpError) -> bool {
+        match err {
+            CdpError::Navigation(err) => self.retry_on.iter().any(|code| code == err.as_str()),
+            _ => false,
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Retries the [`RetryPolicy::TRANSIENT_NET_ERRORS`] three times, after
+    /// 500ms, 1s and 2s.
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            backoff: Duration::from_millis(500),
+            retry_on: Self::TRANSIENT_NET_ERRORS
+                .iter()
+                .map(|code| code.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Options for [`Page::click_with_options`] and
+/// [`Element::click_with_options`].
+#[derive(Debug, Clone)]
+pub struct ClickOptions {
+    /// The mouse button to click, `MouseButton::Left` by default.
+    pub button: MouseButton,
+    /// How often to click, e.g. `2` for a double click.
+    pub click_count: i64,
+    /// Bit field of the modifier keys that are pressed while clicking.
+    /// Alt=1, Ctrl=2, Meta/Command=4, Shift=8 (default: 0).
+    pub modifiers: i64,
+    /// How long to wait between pressing and releasing the button.
+    pub delay: Option<Duration>,
+}
+
+impl Default for ClickOptions {
+    fn default() -> Self {
+        Self {
+            button: MouseButton::Left,
+            click_count: 1,
+            modifiers: 0,
+            delay: None,
+        }
+    }
+}
+
+/// The timezone, locale and geolocation of an emulated user, see
+/// [`Page::emulate_locale`]. Fields that are `None` are not changed.
+#[derive(Debug, Clone, Default)]
+pub struct LocaleConfig {
+    /// The IANA timezone id, like `"Asia/Tokyo"`.
+    pub timezone: Option<String>,
+    /// The ICU style locale, like `"ja_JP"`. An empty locale disables the
+    /// locale override.
+    pub locale: Option<String>,
+    /// The position reported by the geolocation API. Note that the page still
+    /// needs the `geolocation` permission to access it.
+    pub geolocation: Option<SetGeolocationOverrideParams>,
+}
+
+impl From<SetLocaleOverrideParams> for LocaleConfig {
+    fn from(params: SetLocaleOverrideParams) -> Self {
+        Self {
+            locale: Some(params.locale.unwrap_or_default()),
+            ..Default::default()
+        }
+    }
+}
+
+/// How [`Page::drag_and_drop`] drags an element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DragMode {
+    /// Only dispatch mouse events, for pages that implement dragging with
+    /// `mousedown`, `mousemove` and `mouseup` listeners.
+    #[default]
+    Mouse,
+    /// Dispatch the events of the HTML5 drag and drop API, for elements with
+    /// the `draggable` attribute.
+    DragEvents,
+}
+
+/// The CSS media type to emulate, see [`Page::set_media_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MediaType {
+    /// Disables the emulation, the page uses its actual media type.
+    #[default]
+    None,
+    /// The `screen` media type.
+    Screen,
+    /// The `print` media type.
+    Print,
+}
+
+impl AsRef<str> for MediaType {
+    fn as_ref(&self) -> &str {
+        match self {
+            MediaType::None => "",
+            MediaType::Screen => "screen",
+            MediaType::Print => "print",
+        }
+    }
+}
+
+/// Network presets for [`Page::emulate_bandwidth`], matching those of Chrome
+/// DevTools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bandwidth {
+    /// Disables any throttling and emulated disconnection
+    NoThrottling,
+    /// 2ms latency, 30 Mbit/s download and 15 Mbit/s upload
+    Wifi,
+    /// 562.5ms latency, 1.44 Mbit/s download and 675 kbit/s upload
+    Fast3G,
+    /// 2000ms latency, 400 kbit/s download and upload
+    Slow3G,
+    /// 500ms latency, 50 kbit/s download and 20 kbit/s upload
+    Gprs,
+    /// No network connection at all
+    Offline,
+}
+
+impl From<Bandwidth> for EmulateNetworkConditionsParams {
+    fn from(bandwidth: Bandwidth) -> Self {
+        // throughputs are in bytes per second
+        match bandwidth {
+            Bandwidth::NoThrottling => EmulateNetworkConditionsParams::new(false, 0., -1., -1.),
+            Bandwidth::Wifi => EmulateNetworkConditionsParams::new(
+                false,
+                2.,
+                30. * 1024. * 1024. / 8.,
+                15. * 1024. * 1024. / 8.,
+            ),
+            Bandwidth::Fast3G => EmulateNetworkConditionsParams::new(
+                false,
+                150. * 3.75,
+                1.6 * 1000. * 1000. / 8. * 0.9,
+                750. * 1000. / 8. * 0.9,
+            ),
+            Bandwidth::Slow3G => EmulateNetworkConditionsParams::new(
+                false,
+                400. * 5.,
+                500. * 1000. / 8. * 0.8,
+                500. * 1000. / 8. * 0.8,
+            ),
+            Bandwidth::Gprs => {
+                EmulateNetworkConditionsParams::new(false, 500., 50. * 1024. / 8., 20. * 1024. / 8.)
+            }
+            Bandwidth::Offline => EmulateNetworkConditionsParams::new(true, 0., 0., 0.),
+        }
+    }
+}
+
+/// A file chooser dialog that was opened by the page, see
+/// [`Page::on_file_chooser`].
+#[derive(Debug, Clone)]
+pub struct FileChooser {
+    page: Page,
+    event: Arc<EventFileChooserOpened>,
+}
+
+impl FileChooser {
+    /// The frame that opened the dialog
+    pub fn frame_id(&self) -> &FrameId {
+        &self.event.frame_id
+    }
+
+    /// Whether the dialog accepts multiple files
+    pub fn is_multiple(&self) -> bool {
+        self.event.mode == FileChooserOpenedMode::SelectMultiple
+    }
+
+    /// The `<input type="file">` element that opened the dialog, if any
+    pub fn backend_node_id(&self) -> Option<BackendNodeId> {
+        self.event.backend_node_id
+    }
+
+    /// Selects the `files` as if the user chose them in the dialog.
+    ///
+    /// The paths should be absolute. This fails with `CdpError::NotFound` if
+    /// the dialog wasn't opened by an `<input type="file">` element.
+    pub async fn accept<I, P>(&self, files: I) -> Result<()>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let backend_node_id = self.backend_node_id().ok_or(CdpError::NotFound)?;
+        let files = files
+            .into_iter()
+            .map(|file| file.as_ref().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        let params = SetFileInputFilesParams::builder()
+            .files(files)
+            .backend_node_id(backend_node_id)
+            .build()
+            .map_err(CdpError::msg)?;
+        self.page.execute(params).await?;
+        Ok(())
+    }
+}
+
+/// The `Security.certificateError` event, see [`Page::on_certificate_error`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventCertificateError {
+    /// The identifier to answer the error with
+    pub event_id: i64,
+    /// The type of the error, e.g. `net::ERR_CERT_AUTHORITY_INVALID`
+    pub error_type: String,
+    /// The url of the request that failed
+    #[serde(rename = "requestURL")]
+    pub request_url: String,
+}
+
+impl MethodType for EventCertificateError {
+    fn method_id() -> MethodId {
+        "Security.certificateError".into()
+    }
+}
+
+impl CustomEvent for EventCertificateError {}
+
+/// A certificate error that occurred for a request of the page, see
+/// [`Page::on_certificate_error`].
+///
+/// The request is blocked until the error is answered.
+#[derive(Debug, Clone)]
+pub struct CertificateError {
+    page: Page,
+    event: Arc<EventCertificateError>,
+}
+
+impl CertificateError {
+    /// The type of the error, e.g. `net::ERR_CERT_AUTHORITY_INVALID`
+    pub fn error_type(&self) -> &str {
+        &self.event.error_type
+    }
+
+    /// The url of the request that failed
+    pub fn request_url(&self) -> &str {
+        &self.event.request_url
+    }
+
+    /// Continues the request despite the error
+    pub async fn proceed(&self) -> Result<()> {
+        self.handle(CertificateErrorAction::Continue).await
+    }
+
+    /// Cancels the request
+    pub async fn cancel(&self) -> Result<()> {
+        self.handle(CertificateErrorAction::Cancel).await
+    }
+
+    /// Answers the error with the `action`
+    pub async fn handle(&self, action: CertificateErrorAction) -> Result<()> {
+        self.page
+            .send_raw(
+                "Security.handleCertificateError",
+                serde_json::json!({
+                    "eventId": self.event.event_id,
+                    "action": action.as_ref(),
+                }),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+/// Options for [`Page::close_with`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CloseOptions {
+    /// Whether to run the page's `beforeunload` hooks before closing it.
+    pub run_before_unload: bool,
+}
+
+/// Options for [`Page::press_key`].
+#[derive(Debug, Clone, Default)]
+pub struct PressOptions {
+    /// How many additional `keyDown` events with `autoRepeat` set are
+    /// dispatched before the key is released, as if the key was held down.
+    pub repeat: u32,
+    /// Where the key is located on the keyboard, e.g. `KeyLocation::Numpad`
+    /// to press the `Enter` key of the numeric keypad.
+    pub location: Option<KeyLocation>,
+    /// How long to wait between pressing and releasing the key.
+    pub delay: Option<Duration>,
+}
+
+/// The location of a key on the keyboard, see
+/// [KeyboardEvent.location](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/location).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyLocation {
+    /// The key has only one version or the location can't be distinguished.
+    Standard = 0,
+    /// The left version of the key, e.g. the left `Shift` key.
+    Left = 1,
+    /// The right version of the key, e.g. the right `Shift` key.
+    Right = 2,
+    /// The key is on the numeric keypad.
+    Numpad = 3,
+}
+
+/// Whether the `timezone_id` looks like an IANA timezone id, e.g.
+/// `America/New_York`, `Etc/GMT+9` or `UTC`, or is empty.
+fn is_valid_timezone_id(timezone_id: &str) -> bool {
+    timezone_id.is_empty()
+        || timezone_id.split('/').all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+'))
+        })
+}
+
+/// Maps the error chromium responds with for a timezone it doesn't know to
+/// `CdpError::InvalidTimezone`.
+fn timezone_error(err: CdpError, timezone_id: String) -> CdpError {
+    match err {
+        CdpError::Chrome(err) if err.message.contains("Invalid timezone") => {
+            CdpError::InvalidTimezone(timezone_id)
+        }
+        err => err,
+    }
+}
+
+/// Fails with `CdpError::Timeout` if the `fut` doesn't complete within
+/// `timeout`.
+pub(crate) async fn with_timeout<T>(
+    fut: impl Future<Output = Result<T>>,
+    timeout: Duration,
+) -> Result<T> {
+    futures::pin_mut!(fut);
+    match future::select(fut, futures_timer::Delay::new(timeout)).await {
+        Either::Left((res, _)) => res,
+        Either::Right(_) => Err(CdpError::Timeout),
+    }
+}
+
 /// Page screenshot parameters with extra options.
 #[derive(Debug, Default)]
 pub struct ScreenshotParams {
@@ -1343,3 +3968,492 @@ impl From<CaptureScreenshotParams> for ScreenshotParams {
         }
     }
 }
+
+/// Builds the [`PrintToPdfParams`] for [`Page::pdf`] and takes care of the
+/// options that depend on each other.
+///
+/// # Example pdf with page numbers in the footer
+///
+/// ```no_run
+/// # use chromiumoxide::page::{Page, PdfBuilder};
+/// # use chromiumoxide::error::Result;
+/// # async fn demo(page: Page) -> Result<()> {
+///     let pdf = page
+///         .pdf(
+///             PdfBuilder::default()
+///                 .footer_html(
+///                     r#"<div style="font-size: 10px; margin: auto">
+///                         <span class="pageNumber"></span> / <span class="totalPages"></span>
+///                     </div>"#,
+///                 )
+///                 .page_ranges("1-5"),
+///         )
+///         .await?;
+///     # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PdfBuilder {
+    params: PrintToPdfParams,
+}
+
+impl PdfBuilder {
+    /// The margin in inches that leaves room for a header or footer if no
+    /// margin was set.
+    pub const HEADER_FOOTER_MARGIN: f64 = 0.5;
+
+    /// The HTML template of the header of every page.
+    ///
+    /// The template can contain elements with the classes `date`, `title`,
+    /// `url`, `pageNumber` and `totalPages` which are filled with the
+    /// printing values. Setting a header displays headers and footers, if
+    /// unset the top margin defaults to [`PdfBuilder::HEADER_FOOTER_MARGIN`].
+    pub fn header_html(mut self, html: impl Into<String>) -> Self {
+        self.params.header_template = Some(html.into());
+        self
+    }
+
+    /// The HTML template of the footer of every page, see
+    /// [`PdfBuilder::header_html`].
+    ///
+    /// If unset the bottom margin defaults to
+    /// [`PdfBuilder::HEADER_FOOTER_MARGIN`].
+    pub fn footer_html(mut self, html: impl Into<String>) -> Self {
+        self.params.footer_template = Some(html.into());
+        self
+    }
+
+    /// The page margins in inches, in the order of CSS.
+    pub fn margins(mut self, top: f64, right: f64, bottom: f64, left: f64) -> Self {
+        self.params.margin_top = Some(top);
+        self.params.margin_right = Some(right);
+        self.params.margin_bottom = Some(bottom);
+        self.params.margin_left = Some(left);
+        self
+    }
+
+    /// The one based pages to print, e.g. `1-5, 8, 11-13`.
+    pub fn page_ranges(mut self, page_ranges: impl Into<String>) -> Self {
+        self.params.page_ranges = Some(page_ranges.into());
+        self
+    }
+
+    /// The paper size in inches.
+    pub fn paper_size(mut self, width: f64, height: f64) -> Self {
+        self.params.paper_width = Some(width);
+        self.params.paper_height = Some(height);
+        self
+    }
+
+    /// Print in landscape orientation.
+    pub fn landscape(mut self, landscape: bool) -> Self {
+        self.params.landscape = Some(landscape);
+        self
+    }
+
+    /// Print background graphics.
+    pub fn print_background(mut self, print_background: bool) -> Self {
+        self.params.print_background = Some(print_background);
+        self
+    }
+
+    /// Scale of the webpage rendering.
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.params.scale = Some(scale);
+        self
+    }
+
+    /// Returns the [`PrintToPdfParams`] to print the page with.
+    ///
+    /// If a header or footer is set, headers and footers are displayed, the
+    /// missing template is left empty and the top or bottom margin defaults
+    /// to [`PdfBuilder::HEADER_FOOTER_MARGIN`] unless it was set.
+    pub fn build(self) -> PrintToPdfParams {
+        let mut params = self.params;
+        if params.header_template.is_some() || params.footer_template.is_some() {
+            params.display_header_footer = Some(true);
+            if params.header_template.is_some() {
+                params.margin_top.get_or_insert(Self::HEADER_FOOTER_MARGIN);
+            }
+            if params.footer_template.is_some() {
+                params
+                    .margin_bottom
+                    .get_or_insert(Self::HEADER_FOOTER_MARGIN);
+            }
+            // chromium prints its default template if only one is set
+            params
+                .header_template
+                .get_or_insert_with(|| "<span></span>".to_string());
+            params
+                .footer_template
+                .get_or_insert_with(|| "<span></span>".to_string());
+        }
+        params
+    }
+}
+
+impl From<PdfBuilder> for PrintToPdfParams {
+    fn from(builder: PdfBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Builds the [`SetUserAgentOverrideParams`] for [`Page::set_user_agent`]
+/// that override the user agent string consistently with the accept
+/// language, `navigator.platform` and the client hints of
+/// `navigator.userAgentData`.
+///
+/// Once any client hint is set, the brands that aren't set explicitly are
+/// derived from the `Chrome/<version>` of the user agent string.
+///
+/// # Example
+///
+/// ```no_run
+/// # use chromiumoxide::page::{Page, UserAgentOverride};
+/// # use chromiumoxide::error::Result;
+/// # async fn demo(page: Page) -> Result<()> {
+///     page.set_user_agent(
+///         UserAgentOverride::new(
+///             "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 \
+///              (KHTML, like Gecko) Chrome/120.0.6099.109 Safari/537.36",
+///         )
+///         .accept_language("en-US,en")
+///         .platform("Win32")
+///         .client_hints_platform("Windows", "15.0.0")
+///         .architecture("x86")
+///         .bitness("64"),
+///     )
+///     .await?;
+///     # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct UserAgentOverride {
+    params: SetUserAgentOverrideParams,
+}
+
+impl UserAgentOverride {
+    /// Overrides the user agent string of requests and `navigator.userAgent`.
+    pub fn new(user_agent: impl Into<String>) -> Self {
+        Self {
+            params: SetUserAgentOverrideParams::new(user_agent),
+        }
+    }
+
+    /// The `Accept-Language` header of requests and `navigator.languages`,
+    /// e.g. `en-US,en`.
+    pub fn accept_language(mut self, accept_language: impl Into<String>) -> Self {
+        self.params.accept_language = Some(accept_language.into());
+        self
+    }
+
+    /// The `navigator.platform`, e.g. `Win32` or `MacIntel`.
+    pub fn platform(mut self, platform: impl Into<String>) -> Self {
+        self.params.platform = Some(platform.into());
+        self
+    }
+
+    /// Replaces all client hints with the `metadata`.
+    pub fn metadata(mut self, metadata: UserAgentMetadata) -> Self {
+        self.params.user_agent_metadata = Some(metadata);
+        self
+    }
+
+    /// The platform and its version of the client hints, e.g. `Windows`
+    /// and `15.0.0`.
+    pub fn client_hints_platform(
+        mut self,
+        platform: impl Into<String>,
+        platform_version: impl Into<String>,
+    ) -> Self {
+        let metadata = self.metadata_mut();
+        metadata.platform = platform.into();
+        metadata.platform_version = platform_version.into();
+        self
+    }
+
+    /// The CPU architecture of the client hints, e.g. `x86` or `arm`.
+    pub fn architecture(mut self, architecture: impl Into<String>) -> Self {
+        self.metadata_mut().architecture = architecture.into();
+        self
+    }
+
+    /// The bitness of the CPU architecture of the client hints, e.g. `64`.
+    pub fn bitness(mut self, bitness: impl Into<String>) -> Self {
+        self.metadata_mut().bitness = Some(bitness.into());
+        self
+    }
+
+    /// The device model of the client hints, e.g. `Pixel 7`.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.metadata_mut().model = model.into();
+        self
+    }
+
+    /// Whether the client hints report a mobile device.
+    pub fn mobile(mut self, mobile: bool) -> Self {
+        self.metadata_mut().mobile = mobile;
+        self
+    }
+
+    /// Adds a brand with its full version to the client hints, e.g.
+    /// `Google Chrome` and `120.0.6099.109`.
+    pub fn brand(mut self, brand: impl Into<String>, full_version: impl Into<String>) -> Self {
+        let brand = brand.into();
+        let full_version = full_version.into();
+        let major_version = full_version
+            .split('.')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let metadata = self.metadata_mut();
+        metadata
+            .brands
+            .get_or_insert_with(Vec::new)
+            .push(UserAgentBrandVersion::new(brand.clone(), major_version));
+        metadata
+            .full_version_list
+            .get_or_insert_with(Vec::new)
+            .push(UserAgentBrandVersion::new(brand, full_version));
+        self
+    }
+
+    fn metadata_mut(&mut self) -> &mut UserAgentMetadata {
+        self.params
+            .user_agent_metadata
+            .get_or_insert_with(|| UserAgentMetadata {
+                brands: None,
+                full_version_list: None,
+                platform: String::new(),
+                platform_version: String::new(),
+                architecture: String::new(),
+                model: String::new(),
+                mobile: false,
+                bitness: None,
+                wow64: None,
+            })
+    }
+
+    /// Returns the [`SetUserAgentOverrideParams`] for
+    /// [`Page::set_user_agent`].
+    ///
+    /// If client hints are set without brands, the brands are derived from
+    /// the `Chrome/<version>` of the user agent string.
+    pub fn build(mut self) -> SetUserAgentOverrideParams {
+        let full_version = self
+            .params
+            .user_agent
+            .split_whitespace()
+            .find_map(|token| token.strip_prefix("Chrome/"))
+            .map(str::to_string);
+        if let (Some(metadata), Some(full_version)) =
+            (self.params.user_agent_metadata.as_ref(), full_version)
+        {
+            if metadata.brands.is_none() {
+                // the GREASE brand chromium adds to the brands list
+                self = self
+                    .brand("Not_A Brand", "8.0.0.0")
+                    .brand("Chromium", full_version.clone())
+                    .brand("Google Chrome", full_version);
+            }
+        }
+        self.params
+    }
+}
+
+impl From<UserAgentOverride> for SetUserAgentOverrideParams {
+    fn from(builder: UserAgentOverride) -> Self {
+        builder.build()
+    }
+}
+
+/// Run-time metrics of a page, see [`Page::performance_metrics`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PerformanceMetrics {
+    /// Current time in seconds (`Timestamp`).
+    pub timestamp: Option<f64>,
+    /// Number of documents in the page (`Documents`).
+    pub documents: Option<f64>,
+    /// Number of frames in the page (`Frames`).
+    pub frames: Option<f64>,
+    /// Number of events in the page (`JSEventListeners`).
+    pub js_event_listeners: Option<f64>,
+    /// Number of DOM nodes in the page (`Nodes`).
+    pub nodes: Option<f64>,
+    /// Number of layout objects in the page (`LayoutObjects`).
+    pub layout_objects: Option<f64>,
+    /// Total number of full or partial page layout (`LayoutCount`).
+    pub layout_count: Option<f64>,
+    /// Total number of page style recalculations (`RecalcStyleCount`).
+    pub recalc_style_count: Option<f64>,
+    /// Combined durations of all page layouts in seconds (`LayoutDuration`).
+    pub layout_duration: Option<f64>,
+    /// Combined duration of all page style recalculations in seconds (`RecalcStyleDuration`).
+    pub recalc_style_duration: Option<f64>,
+    /// Combined duration of JavaScript execution in seconds (`ScriptDuration`).
+    pub script_duration: Option<f64>,
+    /// Combined duration of JavaScript compilation in seconds (`V8CompileDuration`).
+    pub v8_compile_duration: Option<f64>,
+    /// Combined duration of all tasks performed by the browser in seconds (`TaskDuration`).
+    pub task_duration: Option<f64>,
+    /// Used JavaScript heap size in bytes (`JSHeapUsedSize`).
+    pub js_heap_used_size: Option<f64>,
+    /// Total JavaScript heap size in bytes (`JSHeapTotalSize`).
+    pub js_heap_total_size: Option<f64>,
+}
+
+impl From<&[Metric]> for PerformanceMetrics {
+    fn from(metrics: &[Metric]) -> Self {
+        let mut perf = Self::default();
+        for metric in metrics {
+            let field = match metric.name.as_str() {
+                "Timestamp" => &mut perf.timestamp,
+                "Documents" => &mut perf.documents,
+                "Frames" => &mut perf.frames,
+                "JSEventListeners" => &mut perf.js_event_listeners,
+                "Nodes" => &mut perf.nodes,
+                "LayoutObjects" => &mut perf.layout_objects,
+                "LayoutCount" => &mut perf.layout_count,
+                "RecalcStyleCount" => &mut perf.recalc_style_count,
+                "LayoutDuration" => &mut perf.layout_duration,
+                "RecalcStyleDuration" => &mut perf.recalc_style_duration,
+                "ScriptDuration" => &mut perf.script_duration,
+                "V8CompileDuration" => &mut perf.v8_compile_duration,
+                "TaskDuration" => &mut perf.task_duration,
+                "JSHeapUsedSize" => &mut perf.js_heap_used_size,
+                "JSHeapTotalSize" => &mut perf.js_heap_total_size,
+                _ => continue,
+            };
+            *field = Some(metric.value);
+        }
+        perf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_timezone_ids() {
+        for id in [
+            "",
+            "UTC",
+            "Asia/Tokyo",
+            "America/New_York",
+            "America/Argentina/Buenos_Aires",
+            "America/Port-au-Prince",
+            "Etc/GMT+9",
+            "Etc/GMT-14",
+        ] {
+            assert!(is_valid_timezone_id(id), "{id}");
+        }
+    }
+
+    #[test]
+    fn invalid_timezone_ids() {
+        for id in [
+            " ",
+            "Europe/ Berlin",
+            "New York",
+            "Asia/Tokyo ",
+            "/Asia/Tokyo",
+            "Asia//Tokyo",
+            "Asia/",
+            "../etc/passwd",
+            "Europe/Berlin;",
+            "Asia/Tōkyō",
+        ] {
+            assert!(!is_valid_timezone_id(id), "{id:?}");
+        }
+    }
+
+    #[test]
+    fn maps_invalid_timezone_error() {
+        let err = timezone_error(
+            CdpError::Chrome(chromiumoxide_types::Error {
+                code: -32000,
+                message: "Invalid timezone ID".to_string(),
+            }),
+            "Mars/Olympus_Mons".to_string(),
+        );
+        assert!(matches!(err, CdpError::InvalidTimezone(id) if id == "Mars/Olympus_Mons"));
+
+        let err = timezone_error(
+            CdpError::Chrome(chromiumoxide_types::Error {
+                code: -32000,
+                message: "Timezone override is already in effect".to_string(),
+            }),
+            "Asia/Tokyo".to_string(),
+        );
+        assert!(matches!(err, CdpError::Chrome(_)));
+        assert!(matches!(
+            timezone_error(CdpError::Timeout, "Asia/Tokyo".to_string()),
+            CdpError::Timeout
+        ));
+    }
+
+    #[test]
+    fn performance_metrics_from_metrics() {
+        let metric = |name: &str, value: f64| Metric {
+            name: name.to_string(),
+            value,
+        };
+        let metrics = PerformanceMetrics::from(
+            &[
+                metric("Timestamp", 1234.5),
+                metric("Nodes", 42.),
+                metric("JSEventListeners", 7.),
+                metric("JSHeapUsedSize", 1_048_576.),
+                metric("ScriptDuration", 0.25),
+                metric("UnknownMetric", 1.),
+            ][..],
+        );
+        assert_eq!(metrics.timestamp, Some(1234.5));
+        assert_eq!(metrics.nodes, Some(42.));
+        assert_eq!(metrics.js_event_listeners, Some(7.));
+        assert_eq!(metrics.js_heap_used_size, Some(1_048_576.));
+        assert_eq!(metrics.script_duration, Some(0.25));
+        assert_eq!(metrics.documents, None);
+        assert_eq!(metrics.js_heap_total_size, None);
+
+        assert_eq!(
+            PerformanceMetrics::from(&[][..]),
+            PerformanceMetrics::default()
+        );
+    }
+
+    #[test]
+    fn pdf_header_footer_margins() {
+        let params = PdfBuilder::default()
+            .header_html("<span class=title></span>")
+            .build();
+        assert_eq!(params.display_header_footer, Some(true));
+        assert_eq!(params.margin_top, Some(PdfBuilder::HEADER_FOOTER_MARGIN));
+        assert_eq!(params.margin_bottom, None);
+        assert_eq!(params.footer_template.as_deref(), Some("<span></span>"));
+
+        let params = PdfBuilder::default()
+            .footer_html("<span class=pageNumber></span>")
+            .build();
+        assert_eq!(params.margin_top, None);
+        assert_eq!(params.margin_bottom, Some(PdfBuilder::HEADER_FOOTER_MARGIN));
+        assert_eq!(params.header_template.as_deref(), Some("<span></span>"));
+
+        // explicit margins are kept
+        let params = PdfBuilder::default()
+            .margins(1., 0.25, 2., 0.25)
+            .header_html("<span class=title></span>")
+            .footer_html("<span class=pageNumber></span>")
+            .build();
+        assert_eq!(params.margin_top, Some(1.));
+        assert_eq!(params.margin_right, Some(0.25));
+        assert_eq!(params.margin_bottom, Some(2.));
+        assert_eq!(params.margin_left, Some(0.25));
+
+        let params = PdfBuilder::default().margins(1., 1., 1., 1.).build();
+        assert_eq!(params.display_header_footer, None);
+        assert_eq!(params.header_template, None);
+        assert_eq!(params.footer_template, None);
+    }
+}
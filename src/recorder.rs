@@ -0,0 +1,258 @@
+//! Records the responses of a page together with their bodies, see
+//! `Page::start_response_recording`.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use futures::future::{AbortHandle, Abortable};
+use futures::{stream, StreamExt};
+
+use chromiumoxide_cdp::cdp::browser_protocol::network::{
+    EventLoadingFailed, EventLoadingFinished, EventRequestWillBeSent, EventResponseReceived,
+    RequestId,
+};
+
+use crate::error::Result;
+use crate::page::Page;
+
+/// How many requests that have not finished loading are tracked at most,
+/// requests that never finish (e.g. long polling or event streams) are evicted
+/// oldest first beyond this.
+const MAX_PENDING: usize = 1_000;
+
+/// A finished response that was recorded by a [`ResponseRecorder`]
+#[derive(Debug, Clone)]
+pub struct RecordedResponse {
+    /// The event of the request as it was sent, `None` if it was sent before
+    /// the recording started
+    pub request: Option<Arc<EventRequestWillBeSent>>,
+    /// The event of the received response, including its headers and timing
+    pub response: Arc<EventResponseReceived>,
+    /// The event of the finished loading of the response
+    pub finished: Arc<EventLoadingFinished>,
+    /// The body of the response, `None` if the browser didn't retain it
+    pub body: Option<Vec<u8>>,
+}
+
+impl RecordedResponse {
+    /// The identifier of the recorded request
+    pub fn request_id(&self) -> &RequestId {
+        &self.response.request_id
+    }
+
+    /// The url of the response
+    pub fn url(&self) -> &str {
+        &self.response.response.url
+    }
+}
+
+/// The events of requests that have not finished loading yet, keyed by their
+/// request id and bounded to `MAX_PENDING` entries.
+#[derive(Debug)]
+struct Pending<T> {
+    entries: HashMap<RequestId, T>,
+    /// The request ids in insertion order, may contain ids that were
+    /// already removed
+    order: VecDeque<RequestId>,
+}
+
+impl<T> Pending<T> {
+    fn insert(&mut self, id: RequestId, value: T) {
+        if self.entries.insert(id.clone(), value).is_none() {
+            self.order.push_back(id);
+        }
+        while self.entries.len() > MAX_PENDING {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+        if self.order.len() > MAX_PENDING * 2 {
+            let entries = &self.entries;
+            self.order.retain(|id| entries.contains_key(id));
+        }
+    }
+
+    fn remove(&mut self, id: &RequestId) -> Option<T> {
+        self.entries.remove(id)
+    }
+}
+
+impl<T> Default for Pending<T> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Recording {
+    responses: Vec<RecordedResponse>,
+    /// How many responses were not recorded because `max_entries` was reached
+    dropped: usize,
+}
+
+/// A handle to a response recording that was started with
+/// `Page::start_response_recording`.
+///
+/// The recording runs on a background task until `ResponseRecorder::stop` is
+/// called or the page is closed.
+#[derive(Debug, Clone)]
+pub struct ResponseRecorder {
+    recording: Arc<Mutex<Recording>>,
+    abort: AbortHandle,
+}
+
+impl ResponseRecorder {
+    /// Starts recording the responses of the `page` that match the `filter`,
+    /// keeping at most `max_entries` responses until they are taken.
+    pub(crate) async fn start(
+        page: Page,
+        filter: impl Fn(&EventResponseReceived) -> bool + Send + 'static,
+        max_entries: usize,
+    ) -> Result<Self> {
+        enum Event {
+            Request(Arc<EventRequestWillBeSent>),
+            Response(Arc<EventResponseReceived>),
+            Finished(Arc<EventLoadingFinished>),
+            Failed(Arc<EventLoadingFailed>),
+        }
+
+        let events = stream::select(
+            stream::select(
+                page.event_listener::<EventRequestWillBeSent>()
+                    .await?
+                    .map(Event::Request),
+                page.event_listener::<EventResponseReceived>()
+                    .await?
+                    .map(Event::Response),
+            ),
+            stream::select(
+                page.event_listener::<EventLoadingFinished>()
+                    .await?
+                    .map(Event::Finished),
+                page.event_listener::<EventLoadingFailed>()
+                    .await?
+                    .map(Event::Failed),
+            ),
+        );
+
+        let recording = Arc::new(Mutex::new(Recording::default()));
+        let shared = Arc::clone(&recording);
+        let fut = async move {
+            futures::pin_mut!(events);
+            let mut requests = Pending::default();
+            let mut responses = Pending::default();
+            while let Some(event) = events.next().await {
+                match event {
+                    Event::Request(ev) => {
+                        requests.insert(ev.request_id.clone(), ev);
+                    }
+                    Event::Response(ev) => {
+                        if filter(&ev) {
+                            responses.insert(ev.request_id.clone(), ev);
+                        } else {
+                            requests.remove(&ev.request_id);
+                        }
+                    }
+                    Event::Finished(ev) => {
+                        let request = requests.remove(&ev.request_id);
+                        if let Some(response) = responses.remove(&ev.request_id) {
+                            {
+                                let mut recording = shared.lock().unwrap();
+                                if recording.responses.len() >= max_entries {
+                                    // don't fetch a body that is never recorded
+                                    recording.dropped += 1;
+                                    continue;
+                                }
+                            }
+                            let body = page.response_body(ev.request_id.clone()).await.ok();
+                            shared.lock().unwrap().responses.push(RecordedResponse {
+                                request,
+                                response,
+                                finished: ev,
+                                body,
+                            });
+                        }
+                    }
+                    Event::Failed(ev) => {
+                        requests.remove(&ev.request_id);
+                        responses.remove(&ev.request_id);
+                    }
+                }
+            }
+        };
+
+        let (abort, registration) = AbortHandle::new_pair();
+        let fut = Abortable::new(fut, registration);
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "async-std-runtime")] {
+                async_std::task::spawn(fut);
+            } else if #[cfg(feature = "tokio-runtime")] {
+                tokio::spawn(fut);
+            }
+        }
+
+        Ok(Self { recording, abort })
+    }
+
+    /// Returns all responses recorded so far without removing them.
+    pub fn recorded(&self) -> Vec<RecordedResponse> {
+        self.recording.lock().unwrap().responses.clone()
+    }
+
+    /// Removes and returns all responses recorded so far.
+    ///
+    /// This frees the space for `max_entries` new responses.
+    pub fn take_recorded(&self) -> Vec<RecordedResponse> {
+        let mut recording = self.recording.lock().unwrap();
+        recording.dropped = 0;
+        std::mem::take(&mut recording.responses)
+    }
+
+    /// How many responses matched the filter but were not recorded because
+    /// `max_entries` responses were already recorded and not taken.
+    pub fn dropped(&self) -> usize {
+        self.recording.lock().unwrap().dropped
+    }
+
+    /// Stops the recording, the responses recorded so far can still be
+    /// taken.
+    pub fn stop(&self) {
+        self.abort.abort();
+    }
+
+    /// Whether the recording was stopped
+    pub fn is_stopped(&self) -> bool {
+        self.abort.is_aborted()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_evicts_oldest() {
+        let mut pending = Pending::default();
+        for i in 0..MAX_PENDING + 10 {
+            pending.insert(RequestId::new(i.to_string()), i);
+        }
+        assert_eq!(pending.entries.len(), MAX_PENDING);
+        assert_eq!(pending.remove(&RequestId::new("9")), None);
+        assert_eq!(pending.remove(&RequestId::new("10")), Some(10));
+    }
+
+    #[test]
+    fn pending_order_stays_bounded() {
+        let mut pending = Pending::default();
+        for i in 0..MAX_PENDING * 5 {
+            let id = RequestId::new(i.to_string());
+            pending.insert(id.clone(), i);
+            pending.remove(&id);
+        }
+        assert!(pending.entries.is_empty());
+        assert!(pending.order.len() <= MAX_PENDING * 2);
+    }
+}
@@ -0,0 +1,125 @@
+//! Scripts that hide common traces of an automated browser.
+//!
+//! Every [`StealthPatch`] is a script that is evaluated in every new document
+//! before any of the page's scripts run. Patches can be applied individually
+//! to a page with `Page::apply_stealth_patches` or to all pages of a launched
+//! browser with `BrowserConfigBuilder::stealth_patch`.
+
+/// A single anti-detection patch, see [`StealthPatch::script`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StealthPatch {
+    /// Removes the `navigator.webdriver` property.
+    ///
+    /// When applied to a launched browser this also removes the
+    /// `--enable-automation` flag from the default arguments.
+    Webdriver,
+    /// Reports the `notifications` permission like a regular browser.
+    Permissions,
+    /// Reports a non-empty `navigator.plugins`.
+    Plugins,
+    /// Reports `navigator.language` and its base language, e.g.
+    /// `["de-DE", "de"]`, as `navigator.languages`.
+    ///
+    /// `navigator.language` follows the `--lang` argument of the browser and
+    /// `Page::set_locale`, so the reported languages match the configured
+    /// locale.
+    Languages,
+    /// Reports a common WebGL vendor and renderer.
+    WebglVendor,
+    /// Adds the `window.chrome` object of a regular Chrome browser.
+    Chrome,
+}
+
+impl StealthPatch {
+    /// All available patches.
+    pub const ALL: [StealthPatch; 6] = [
+        StealthPatch::Webdriver,
+        StealthPatch::Permissions,
+        StealthPatch::Plugins,
+        StealthPatch::Languages,
+        StealthPatch::WebglVendor,
+        StealthPatch::Chrome,
+    ];
+
+    /// The javascript source of this patch.
+    ///
+    /// Every patch is wrapped in a function so it doesn't declare any
+    /// variables in the global scope of the page.
+    pub fn script(&self) -> &'static str {
+        match self {
+            StealthPatch::Webdriver => {
+                "
+                    (() => {
+                        Object.defineProperty(
+                            navigator,
+                            'webdriver',
+                            { get: () => undefined }
+                        );
+                    })();
+                "
+            }
+            StealthPatch::Permissions => {
+                "
+                    (() => {
+                        const query = window.navigator.permissions.query;
+                        window.navigator.permissions.__proto__.query = function (parameters) {
+                            return parameters.name === 'notifications'
+                                ? Promise.resolve({ state: Notification.permission })
+                                : query.call(this, parameters);
+                        };
+                    })();
+                "
+            }
+            StealthPatch::Plugins => {
+                "
+                    (() => {
+                        Object.defineProperty(
+                            navigator,
+                            'plugins',
+                            {
+                                get: () => [
+                                    { filename: 'internal-pdf-viewer' },
+                                    { filename: 'adsfkjlkjhalkh' },
+                                    { filename: 'internal-nacl-plugin '}
+                                ],
+                            }
+                        );
+                    })();
+                "
+            }
+            StealthPatch::Languages => {
+                "
+                    (() => {
+                        const language = navigator.language;
+                        const base = language.split('-')[0];
+                        const languages = base === language ? [language] : [language, base];
+                        Object.defineProperty(
+                            navigator,
+                            'languages',
+                            { get: () => languages }
+                        );
+                    })();
+                "
+            }
+            StealthPatch::WebglVendor => {
+                "
+                    (() => {
+                        const getParameter = WebGLRenderingContext.prototype.getParameter;
+                        WebGLRenderingContext.prototype.getParameter = function (parameter) {
+                            if (parameter === 37445) {
+                                return 'Google Inc. (NVIDIA)';
+                            }
+
+                            if (parameter === 37446) {
+                                return 'ANGLE (NVIDIA, NVIDIA GeForce GTX 1050 Direct3D11 vs_5_0 ps_5_0, D3D11-27.21.14.5671)';
+                            }
+
+                            return getParameter.call(this, parameter);
+                        };
+                    })();
+                "
+            }
+            StealthPatch::Chrome => "(() => { window.chrome = { runtime: {} }; })();",
+        }
+    }
+}
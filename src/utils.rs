@@ -14,6 +14,17 @@ pub(crate) async fn write<P: AsRef<Path> + Unpin, C: AsRef<[u8]>>(
     }
 }
 
+/// Read a file to a string with configured runtime
+pub(crate) async fn read_to_string<P: AsRef<Path> + Unpin>(path: P) -> std::io::Result<String> {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "async-std-runtime")] {
+            async_std::fs::read_to_string(path.as_ref()).await
+        } else if #[cfg(feature = "tokio-runtime")] {
+            tokio::fs::read_to_string(path.as_ref()).await
+        }
+    }
+}
+
 /// Canonicalize path
 ///
 /// Chromium sandboxing does not support Window UNC paths which are used by Rust
@@ -37,6 +48,11 @@ pub(crate) mod base64 {
     pub fn decode<T: AsRef<[u8]>>(input: T) -> Result<Vec<u8>, DecodeError> {
         STANDARD.decode(input)
     }
+
+    /// Encode base64 using the standard alphabet and padding
+    pub fn encode<T: AsRef<[u8]>>(input: T) -> String {
+        STANDARD.encode(input)
+    }
 }
 
 /// Creates a javascript function string as `(<function>)("<param 1>", "<param
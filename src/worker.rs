@@ -0,0 +1,156 @@
+use futures::channel::mpsc::Sender;
+use futures::channel::oneshot::channel as oneshot_channel;
+use futures::SinkExt;
+
+use chromiumoxide_cdp::cdp::browser_protocol::target::{
+    DetachFromTargetParams, SessionId, TargetId,
+};
+use chromiumoxide_cdp::cdp::js_protocol::runtime::{
+    CallFunctionOnParams, EvaluateParams, RemoteObjectType,
+};
+use chromiumoxide_types::{Command, CommandResponse};
+
+use crate::cmd::{to_command_response, CommandMessage};
+use crate::error::Result;
+use crate::handler::HandlerMessage;
+use crate::js::{self, Evaluation, EvaluationResult};
+
+/// A handle to a worker target, like a service worker.
+///
+/// In contrast to a [`crate::Page`] a worker has no DOM, so this only
+/// supports executing commands and evaluating javascript in the worker's
+/// global scope.
+#[derive(Debug, Clone)]
+pub struct Worker {
+    target_id: TargetId,
+    session_id: SessionId,
+    sender: Sender<HandlerMessage>,
+}
+
+impl Worker {
+    pub(crate) fn new(
+        target_id: TargetId,
+        session_id: SessionId,
+        sender: Sender<HandlerMessage>,
+    ) -> Self {
+        Self {
+            target_id,
+            session_id,
+            sender,
+        }
+    }
+
+    /// The identifier of the worker's target
+    pub fn target_id(&self) -> &TargetId {
+        &self.target_id
+    }
+
+    /// The identifier of the session this worker is attached to
+    pub fn session_id(&self) -> &SessionId {
+        &self.session_id
+    }
+
+    /// Execute a command in the worker's session and return the result
+    pub async fn execute<T: Command>(&self, cmd: T) -> Result<CommandResponse<T::Response>> {
+        self.execute_in(cmd, Some(self.session_id.clone())).await
+    }
+
+    /// Detaches the session from the worker.
+    ///
+    /// The session is shared by all handles of this worker, which can't be
+    /// used afterwards. `Browser::service_workers` attaches a new session.
+    pub async fn detach(self) -> Result<()> {
+        let detach = DetachFromTargetParams::builder()
+            .session_id(self.session_id.clone())
+            .build();
+        self.execute_in(detach, None).await?;
+        Ok(())
+    }
+
+    async fn execute_in<T: Command>(
+        &self,
+        cmd: T,
+        session_id: Option<SessionId>,
+    ) -> Result<CommandResponse<T::Response>> {
+        let (tx, rx) = oneshot_channel();
+        let method = cmd.identifier();
+        let msg = CommandMessage::with_session(cmd, tx, session_id)?;
+
+        self.sender
+            .clone()
+            .send(HandlerMessage::Command(msg))
+            .await?;
+        let resp = rx.await??;
+        to_command_response::<T>(resp, method)
+    }
+
+    /// Evaluates an expression or function in the worker's global scope and
+    /// returns the result.
+    ///
+    /// See `Page::evaluate` for how the kind of evaluation is detected.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use chromiumoxide::browser::Browser;
+    /// # use chromiumoxide::error::Result;
+    /// # async fn demo(browser: Browser) -> Result<()> {
+    ///     for worker in browser.service_workers().await? {
+    ///         let scope: String = worker
+    ///             .evaluate("self.registration.scope")
+    ///             .await?
+    ///             .into_value()?;
+    ///     }
+    ///     # Ok(())
+    /// # }
+    /// ```
+    pub async fn evaluate(&self, evaluate: impl Into<Evaluation>) -> Result<EvaluationResult> {
+        match evaluate.into() {
+            Evaluation::Expression(expr) => {
+                let fallback = js::function_fallback(&expr);
+                let res = self.evaluate_expression(expr).await?;
+
+                if res.object().r#type == RemoteObjectType::Function {
+                    // expression was actually a function
+                    if let Some(fallback) = fallback {
+                        return self.evaluate_function(fallback).await;
+                    }
+                }
+                Ok(res)
+            }
+            Evaluation::Function(fun) => Ok(self.evaluate_function(fun).await?),
+        }
+    }
+
+    /// Evaluates an expression in the worker's global scope
+    pub async fn evaluate_expression(
+        &self,
+        evaluate: impl Into<EvaluateParams>,
+    ) -> Result<EvaluationResult> {
+        let resp = self
+            .execute(js::evaluate_defaults(evaluate.into()))
+            .await?
+            .result;
+        EvaluationResult::from_response(resp.result, resp.exception_details)
+    }
+
+    /// Calls a function with `this` bound to the worker's global scope
+    pub async fn evaluate_function(
+        &self,
+        evaluate: impl Into<CallFunctionOnParams>,
+    ) -> Result<EvaluationResult> {
+        let mut evaluate = js::call_function_defaults(evaluate.into());
+        if evaluate.execution_context_id.is_none() && evaluate.object_id.is_none() {
+            // workers have a single context, resolve its global object to call
+            // the function on
+            let global = self
+                .execute(EvaluateParams::new("globalThis"))
+                .await?
+                .result
+                .result;
+            evaluate.object_id = global.object_id;
+        }
+        let resp = self.execute(evaluate).await?.result;
+        EvaluationResult::from_response(resp.result, resp.exception_details)
+    }
+}